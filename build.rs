@@ -0,0 +1,57 @@
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn main() {
+    println!("cargo:rerun-if-changed=Cargo.lock");
+
+    // Cargo resolves dependencies before running build scripts, so the lock file
+    // already pins the exact scylla driver version being compiled in.
+    let lock_path = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("Cargo.lock");
+    let scylla_version = fs::read_to_string(lock_path)
+        .ok()
+        .and_then(|lock| locked_version(&lock, "scylla"))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=RSYLLA_SCYLLA_VERSION={}", scylla_version);
+    println!(
+        "cargo:rustc-env=RSYLLA_FEATURES={}",
+        enabled_features().join(",")
+    );
+    println!(
+        "cargo:rustc-env=RSYLLA_BUILD_TARGET={}",
+        env::var("TARGET").unwrap_or_default()
+    );
+    println!(
+        "cargo:rustc-env=RSYLLA_BUILD_PROFILE={}",
+        env::var("PROFILE").unwrap_or_default()
+    );
+}
+
+/// Cargo features this build was compiled with, as named in Cargo.toml.
+fn enabled_features() -> Vec<String> {
+    let mut features: Vec<String> = env::vars()
+        .filter_map(|(key, _)| {
+            key.strip_prefix("CARGO_FEATURE_")
+                .map(|feature| feature.to_lowercase().replace('_', "-"))
+        })
+        .filter(|feature| feature != "default")
+        .collect();
+    features.sort();
+    features
+}
+
+fn locked_version(lock: &str, package: &str) -> Option<String> {
+    let name_line = format!("name = \"{}\"", package);
+    let mut lines = lock.lines();
+    while let Some(line) = lines.next() {
+        if line.trim() == name_line {
+            let version = lines.next()?.trim();
+            return version
+                .strip_prefix("version = \"")
+                .and_then(|v| v.strip_suffix('"'))
+                .map(str::to_string);
+        }
+    }
+    None
+}
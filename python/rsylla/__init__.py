@@ -5,26 +5,117 @@ This package provides high-performance Python bindings for ScyllaDB/Cassandra
 using the official Rust driver.
 """
 
+import atexit
+
 from ._rsylla import (
     Batch,
+    BindError,
+    CDCReader,
+    ChangeEvent,
+    BoundStatement,
+    ClusterEvent,
+    ClusterEvents,
+    ClusterMetadata,
+    ColumnMetadata,
+    CqlType,
+    DeadlineExceededError,
+    ExecutionProfile,
+    ForkedProcessError,
+    FullScan,
+    IndexMetadata,
+    KeyspaceMetadata,
+    LoadBalancingPolicy,
+    Mapper,
+    MaterializedViewMetadata,
+    NodeHealth,
+    NodeInfo,
+    OrderedWriter,
+    PageIterator,
+    Pager,
+    PendingResult,
+    Pipeline,
     PreparedStatement,
+    PrometheusExporter,
     Query,
     QueryResult,
+    RateLimitedError,
+    RequestEvent,
+    ResultSnapshot,
     Row,
     ScyllaError,
+    ScyllaWarning,
+    ServerConnection,
     Session,
     SessionBuilder,
+    TableMetadata,
+    TracingEvent,
+    TracingInfo,
+    UserTypeMetadata,
+    ValueOutOfRange,
+    build_info,
+    configure_logging,
+    diff_results,
+    diff_rows,
+    event_loop_backend,
+    shutdown,
+    use_event_loop,
 )
 
 __version__ = "0.1.1"
 
+# Let requests still in flight finish before the interpreter tears down
+atexit.register(shutdown)
+
 __all__ = [
     "Session",
     "SessionBuilder",
     "Query",
     "PreparedStatement",
+    "BoundStatement",
     "QueryResult",
+    "ResultSnapshot",
     "Row",
     "Batch",
+    "ExecutionProfile",
+    "Pipeline",
+    "LoadBalancingPolicy",
+    "OrderedWriter",
+    "Pager",
+    "PageIterator",
+    "PendingResult",
+    "FullScan",
+    "CDCReader",
+    "ChangeEvent",
+    "Mapper",
+    "ClusterEvents",
+    "ClusterEvent",
+    "ClusterMetadata",
+    "NodeHealth",
+    "ServerConnection",
+    "NodeInfo",
+    "KeyspaceMetadata",
+    "TableMetadata",
+    "ColumnMetadata",
+    "CqlType",
+    "MaterializedViewMetadata",
+    "IndexMetadata",
+    "UserTypeMetadata",
+    "PrometheusExporter",
+    "RequestEvent",
+    "TracingInfo",
+    "TracingEvent",
     "ScyllaError",
+    "ScyllaWarning",
+    "RateLimitedError",
+    "BindError",
+    "ValueOutOfRange",
+    "ForkedProcessError",
+    "DeadlineExceededError",
+    "build_info",
+    "configure_logging",
+    "diff_rows",
+    "diff_results",
+    "use_event_loop",
+    "event_loop_backend",
+    "shutdown",
 ]
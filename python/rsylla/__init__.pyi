@@ -1,43 +1,313 @@
 """Type stubs for rsylla"""
 
-from collections.abc import Iterator
-from typing import Any
+import os
+from collections.abc import AsyncIterator, Callable, Generator, Iterable, Iterator
+from typing import Any, Generic, Literal, TypeVar, overload
+
+T = TypeVar("T")
+
+RowFactory = str | Callable[[list[str], tuple[Any, ...]], Any]
 
 class ScyllaError(Exception):
     """Exception raised for ScyllaDB errors"""
 
     pass
 
+class ScyllaWarning(UserWarning):
+    """Warning category for server-side warnings attached to responses"""
+
+    pass
+
+class BindError(ScyllaError):
+    """Raised when values don't match a prepared statement's bind markers"""
+
+    pass
+
+class ValueOutOfRange(BindError):
+    """Raised when an integer is too large for the column it is bound to"""
+
+    pass
+
+class ForkedProcessError(ScyllaError):
+    """Raised when rsylla is used in a process forked after it started its runtime"""
+
+    pass
+
+class DeadlineExceededError(ScyllaError):
+    """Raised when a request doesn't complete by the deadline it was given"""
+
+    pass
+
+class RateLimitedError(ScyllaError):
+    """Raised when Scylla rejects a request by its per-partition rate limit"""
+
+    partition_key: dict[str, Any] | None
+    operation: str
+    rejected_by_coordinator: bool
+
 class SessionBuilder:
     """Builder for creating ScyllaDB sessions with configuration"""
 
     def __init__(self) -> None: ...
+    @staticmethod
+    def from_config(
+        path: str | os.PathLike[str], environment: str | None = None
+    ) -> SessionBuilder: ...
     def known_node(self, hostname: str) -> SessionBuilder: ...
     def known_nodes(self, hostnames: list[str]) -> SessionBuilder: ...
     def use_keyspace(self, keyspace_name: str, case_sensitive: bool) -> SessionBuilder: ...
     def connection_timeout(self, duration_ms: int) -> SessionBuilder: ...
     def pool_size(self, size: int) -> SessionBuilder: ...
+    def connections_per_shard(self, connections: int) -> SessionBuilder: ...
     def user(self, username: str, password: str) -> SessionBuilder: ...
     def compression(self, compression: str | None) -> SessionBuilder: ...
     def tcp_nodelay(self, nodelay: bool) -> SessionBuilder: ...
     def tcp_keepalive(self, keepalive_ms: int | None) -> SessionBuilder: ...
+    def default_execution_profile(self, profile: ExecutionProfile) -> SessionBuilder: ...
+    def execution_profile(self, name: str, profile: ExecutionProfile) -> SessionBuilder: ...
+    def dns_refresh(self, interval_ms: int | None = 30000) -> SessionBuilder: ...
+    def service_level(self, name: str) -> SessionBuilder: ...
+    def track_node_requests(self, enabled: bool = True) -> SessionBuilder: ...
+    def load_balancing_policy(self, policy: LoadBalancingPolicy) -> SessionBuilder: ...
+    def emit_warnings(self, enabled: bool) -> SessionBuilder: ...
+    def collect_warnings(self, enabled: bool) -> SessionBuilder: ...
+    def fairness_class(self, name: str, max_in_flight: int | None = None) -> SessionBuilder: ...
+    def rate_limit(
+        self, requests_per_second: float | None = None, max_concurrent: int | None = None
+    ) -> SessionBuilder: ...
+    def hedged_startup(self, enabled: bool, probe_timeout_ms: int = 2000) -> SessionBuilder: ...
+    def opentelemetry(
+        self, enabled: bool = True, tracer_provider: Any | None = None
+    ) -> SessionBuilder: ...
+    def request_hook(
+        self,
+        before: Callable[[RequestEvent], None] | None = None,
+        after: Callable[[RequestEvent], None] | None = None,
+    ) -> SessionBuilder: ...
+    def row_factory(self, row_factory: RowFactory | None) -> SessionBuilder: ...
+    def default_page_size(self, page_size: int) -> SessionBuilder: ...
+    def strict_types(self, enabled: bool = True) -> SessionBuilder: ...
+    def timestamp_generator(
+        self, generator: str | None, clock_skew_warning_ms: int | None = 1000
+    ) -> SessionBuilder: ...
     def build(self) -> Session: ...
+    def build_with_retry(
+        self,
+        max_attempts: int = 5,
+        backoff_ms: int = 1000,
+        backoff: str = "exponential",
+        jitter: bool = True,
+    ) -> Session: ...
 
 class Session:
     """ScyllaDB session for executing queries"""
 
     @staticmethod
     def connect(nodes: list[str]) -> Session: ...
-    def execute(self, query: str, values: dict[str, Any] | None = None) -> QueryResult: ...
-    def query(self, query: Query, values: dict[str, Any] | None = None) -> QueryResult: ...
-    def prepare(self, query: str) -> PreparedStatement: ...
+    def execute(
+        self,
+        query: str,
+        values: dict[str, Any] | None = None,
+        row_factory: RowFactory | None = None,
+        consistency: str | None = None,
+        timeout_ms: int | None = None,
+        deadline: float | None = None,
+    ) -> QueryResult: ...
+    def query(
+        self,
+        query: Query,
+        values: dict[str, Any] | None = None,
+        row_factory: RowFactory | None = None,
+        deadline: float | None = None,
+    ) -> QueryResult: ...
+    def prepare(
+        self,
+        query: str,
+        timeout_ms: int | None = None,
+        retries: int = 0,
+        bypass_cache: bool = False,
+    ) -> PreparedStatement: ...
+    def prepare_from(
+        self,
+        definition: PreparedStatement | dict[str, Any],
+        timeout_ms: int | None = None,
+        retries: int = 0,
+    ) -> PreparedStatement: ...
+    def reprepare_all(self) -> int: ...
     def execute_prepared(
-        self, prepared: PreparedStatement, values: dict[str, Any] | None = None
+        self,
+        prepared: PreparedStatement,
+        values: dict[str, Any] | None = None,
+        row_factory: RowFactory | None = None,
+        consistency: str | None = None,
+        timeout_ms: int | None = None,
+        deadline: float | None = None,
+    ) -> QueryResult: ...
+    def execute_nowait(
+        self,
+        statement: str | Query | PreparedStatement,
+        values: dict[str, Any] | None = None,
+    ) -> PendingResult: ...
+    def execute_with_retry(
+        self,
+        statement: str | Query | PreparedStatement,
+        values: dict[str, Any] | None = None,
+        retries: int = 3,
+        backoff: str = "exponential",
+        jitter: bool = True,
+        base_delay_ms: int = 100,
+    ) -> QueryResult: ...
+    def execute_bound(
+        self,
+        bound: BoundStatement,
+        row_factory: RowFactory | None = None,
+        deadline: float | None = None,
+    ) -> QueryResult: ...
+    def batch(
+        self, batch: Batch, values: list[dict[str, Any]], deadline: float | None = None
     ) -> QueryResult: ...
-    def batch(self, batch: Batch, values: list[dict[str, Any]]) -> QueryResult: ...
+    def batch_chunked(
+        self, batch: Batch, values: list[dict[str, Any]], chunk_size: int = 100
+    ) -> dict[str, Any]: ...
+    def execute_pipeline(self, pipeline: Pipeline) -> list[dict[str, Any]]: ...
+    def insert_if_not_exists(
+        self, table: str, values: dict[str, Any], ttl: int | None = None
+    ) -> tuple[bool, Row | None]: ...
+    def compare_and_set(
+        self,
+        table: str,
+        key: dict[str, Any],
+        values: dict[str, Any],
+        expected: dict[str, Any] | None = None,
+    ) -> tuple[bool, Row | None]: ...
+    def delete_if(
+        self, table: str, key: dict[str, Any], expected: dict[str, Any] | None = None
+    ) -> tuple[bool, Row | None]: ...
+    def execute_many(
+        self,
+        prepared: PreparedStatement,
+        params: Iterable[dict[str, Any] | None],
+        window: int = 64,
+    ) -> dict[str, Any]: ...
+    def soft_delete(
+        self,
+        table: str,
+        primary_key: dict[str, Any],
+        ttl: int | None = None,
+        marker_column: str = "deleted_at",
+    ) -> None: ...
+    def cdc_reader(
+        self,
+        keyspace: str,
+        table: str,
+        start_ms: int | None = None,
+        end_ms: int | None = None,
+        poll_interval_ms: int = 1000,
+        confidence_window_ms: int = 30000,
+    ) -> CDCReader: ...
+    def mapper(
+        self,
+        cls: type[T],
+        table: str,
+        primary_key: list[str],
+        keyspace: str | None = None,
+        soft_delete_marker: str | None = None,
+    ) -> Mapper[T]: ...
+    def copy_from(
+        self,
+        table: str,
+        path: str | os.PathLike[str],
+        format: str = "csv",
+        concurrency: int = 16,
+        batch_size: int = 50,
+        on_progress: Callable[[int], Any] | None = None,
+    ) -> int: ...
+    def copy_to(
+        self,
+        query_or_table: str | Query | PreparedStatement,
+        path: str | os.PathLike[str],
+        format: str = "csv",
+        values: dict[str, Any] | None = None,
+        page_size: int | None = None,
+        on_progress: Callable[[int], Any] | None = None,
+    ) -> int: ...
+    def paginate(
+        self,
+        statement: str | Query | PreparedStatement,
+        values: dict[str, Any] | None = None,
+        page_size: int | None = None,
+        paging_state: bytes | None = None,
+        read_ahead: float | None = None,
+        prefetch: int = 0,
+    ) -> Pager: ...
+    def pages(
+        self,
+        statement: str | Query | PreparedStatement,
+        values: dict[str, Any] | None = None,
+        page_size: int | None = None,
+        paging_state: bytes | None = None,
+    ) -> PageIterator: ...
+    def execute_stream(
+        self,
+        statement: str | Query | PreparedStatement,
+        values: dict[str, Any] | None = None,
+        on_row: Callable[[Row], Any] | None = None,
+        on_page: Callable[[list[Row], list[str]], Any] | None = None,
+        page_size: int | None = None,
+        prefetch_pages: int = 2,
+    ) -> int: ...
+    def full_scan(
+        self,
+        table: str,
+        concurrency: int = 16,
+        ranges_per_task: int = 1,
+        columns: list[str] | None = None,
+        page_size: int | None = None,
+        bypass_cache: bool = False,
+    ) -> FullScan: ...
+    def ordered_writer(self, concurrency: int = 64) -> OrderedWriter: ...
     def use_keyspace(self, keyspace_name: str, case_sensitive: bool) -> None: ...
+    def create_keyspace(
+        self,
+        name: str,
+        replication: dict[str, str | int],
+        durable_writes: bool = True,
+        if_not_exists: bool = True,
+    ) -> None: ...
+    def drop_keyspace(self, name: str, if_exists: bool = True) -> None: ...
     def await_schema_agreement(self) -> bool: ...
+    def cluster_metadata(self) -> ClusterMetadata: ...
     def get_cluster_data(self) -> str: ...
+    def get_tracing_info(self, tracing_id: str) -> TracingInfo: ...
+    def cluster_events(self, poll_interval_ms: int = 1000) -> ClusterEvents: ...
+    def schema_events(self, poll_interval_ms: int = 1000) -> ClusterEvents: ...
+    def keyspaces(self) -> dict[str, KeyspaceMetadata]: ...
+    def keyspace(self, name: str) -> KeyspaceMetadata | None: ...
+    def token_ranges(
+        self, keyspace: str, table: str | None = None
+    ) -> list[tuple[int, int, list[str]]]: ...
+    def token_for(self, keyspace: str, table: str, partition_key: dict[str, Any]) -> int: ...
+    def replicas_for(
+        self, keyspace: str, table: str, partition_key: dict[str, Any]
+    ) -> list[tuple[str, int]]: ...
+    def metrics(self) -> dict[str, Any]: ...
+    def prometheus_metrics(self) -> str: ...
+    async def serve_prometheus(self, address: str = "127.0.0.1:9464") -> PrometheusExporter: ...
+    def node_latencies(self) -> dict[str, dict[str, Any]]: ...
+    def fairness_in_flight(self) -> dict[str, int]: ...
+    def orphaned_requests(self) -> int: ...
+    def inflight_requests(self) -> int: ...
+    def node_queue_depths(self) -> dict[str, int]: ...
+    async def supported_features(self, timeout_ms: int = 2000) -> dict[str, Any]: ...
+    async def list_server_connections(self, own_only: bool = True) -> list[ServerConnection]: ...
+    def execution_profile(self, name: str) -> ExecutionProfile: ...
+    def contact_points(self) -> list[str]: ...
+    def is_connected(self) -> bool: ...
+    def service_level(self) -> str | None: ...
+    def attach_service_level(self, service_level: str, role: str) -> None: ...
+    def describe(self) -> dict[str, Any]: ...
+    async def check_connectivity(self, timeout_ms: int = 2000) -> list[NodeHealth]: ...
+    def take_warnings(self) -> list[str]: ...
     def get_keyspace(self) -> str | None: ...
 
 class Query:
@@ -50,9 +320,18 @@ class Query:
     def with_timestamp(self, timestamp: int) -> Query: ...
     def with_timeout(self, timeout_ms: int) -> Query: ...
     def with_tracing(self, tracing: bool) -> Query: ...
+    def with_retry_policy(self, retry_policy: str) -> Query: ...
+    def with_execution_profile(self, profile: ExecutionProfile) -> Query: ...
+    def with_host(self, host: str, shard: int | None = None) -> Query: ...
+    def with_fairness_class(self, fairness_class: str) -> Query: ...
+    def with_db_timeout(self, timeout_ms: int | None) -> Query: ...
+    def with_bypass_cache(self, enabled: bool = True) -> Query: ...
     def is_idempotent(self) -> bool: ...
     def set_idempotent(self, idempotent: bool) -> None: ...
     def get_contents(self) -> str: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    @staticmethod
+    def from_dict(data: dict[str, Any]) -> Query: ...
 
 class PreparedStatement:
     """Prepared CQL statement for efficient repeated execution"""
@@ -60,37 +339,139 @@ class PreparedStatement:
     def with_consistency(self, consistency: str) -> PreparedStatement: ...
     def with_serial_consistency(self, serial_consistency: str) -> PreparedStatement: ...
     def with_page_size(self, page_size: int) -> PreparedStatement: ...
+    def get_page_size(self) -> int: ...
+    def get_keyspace(self) -> str | None: ...
     def with_timestamp(self, timestamp: int) -> PreparedStatement: ...
     def with_tracing(self, tracing: bool) -> PreparedStatement: ...
+    def with_retry_policy(self, retry_policy: str) -> PreparedStatement: ...
+    def with_execution_profile(self, profile: ExecutionProfile) -> PreparedStatement: ...
+    def with_host(self, host: str, shard: int | None = None) -> PreparedStatement: ...
+    def with_fairness_class(self, fairness_class: str) -> PreparedStatement: ...
     def is_idempotent(self) -> bool: ...
     def set_idempotent(self, idempotent: bool) -> PreparedStatement: ...
     def get_id(self) -> bytes: ...
     def get_statement(self) -> str: ...
+    def to_dict(self) -> dict[str, Any]: ...
+    def connected_at_prepare(self) -> list[str]: ...
+    def prepare_attempts(self) -> int: ...
+    def compute_token(self, values: dict[str, Any] | None = None) -> int | None: ...
+    def partition_key(self, values: dict[str, Any] | None = None) -> bytes: ...
+    def bind(self, values: dict[str, Any] | None = None) -> BoundStatement: ...
+    def variable_specs(self) -> list[dict[str, Any]]: ...
+    def result_specs(self) -> list[dict[str, Any]]: ...
+    def partition_key_indexes(self) -> list[int]: ...
+
+class BoundStatement:
+    """Prepared statement with values bound and validated, ready for repeated execution"""
+
+    def get_statement(self) -> str: ...
+
+class ChangeEvent:
+    """A change read from a table's CDC log"""
+
+    operation: str
+    stream_id: bytes
+    time: str
+    time_ms: int | None
+    batch_seq_no: int
+    ttl: int | None
+    columns: dict[str, Any]
+    deleted_columns: list[str]
+    pre_image: dict[str, Any] | None
+    post_image: dict[str, Any] | None
+
+class CDCReader:
+    """Async iterator over a table's CDC log, following generation switches"""
+
+    def __aiter__(self) -> AsyncIterator[ChangeEvent]: ...
+    async def __anext__(self) -> ChangeEvent: ...
+    def position_ms(self) -> int | None: ...
+    def close(self) -> None: ...
+
+class Mapper(Generic[T]):
+    """Dataclass mapped onto a table, with cached prepared statements"""
+
+    def save(self, obj: T) -> None: ...
+    def get(self, pk: Any) -> T | None: ...
+    def delete(self, obj: T) -> None: ...
+    def filter(self, allow_filtering: bool = False, **conditions: Any) -> list[T]: ...
+    def table(self) -> str: ...
+    def primary_key(self) -> list[str]: ...
 
 class QueryResult:
     """Result of a CQL query"""
 
-    def rows(self) -> list[Row]: ...
-    def first_row(self) -> Row | None: ...
-    def single_row(self) -> Row: ...
-    def first_row_typed(self) -> dict[str, Any] | None: ...
-    def rows_typed(self) -> list[dict[str, Any]]: ...
+    kind: Literal["rows", "void", "set_keyspace", "schema_change"]
+    rows_num: int | None
+
+    def rows(self) -> list[Any]: ...
+    def take_rows(self) -> list[Any]: ...
+    def first_row(self) -> Any | None: ...
+    def single_row(self) -> Any: ...
+    def first_row_typed(self, omit_nulls: bool = False) -> dict[str, Any] | None: ...
+    def maybe_first_row_typed(self, omit_nulls: bool = False) -> dict[str, Any] | None: ...
+    def scalar(self) -> Any: ...
+    def rows_typed(self, omit_nulls: bool = False) -> list[dict[str, Any]]: ...
+    def rows_named(self) -> list[tuple[Any, ...]]: ...
+    def rows_as(self, cls: type[T]) -> list[T]: ...
+    def snapshot(self) -> ResultSnapshot: ...
+    def to_json(self) -> str: ...
+    def to_arrow(self) -> Any: ...
+    def to_pandas(self) -> Any: ...
+    def to_polars(self) -> Any: ...
+    def column_as_numpy(self, name: str) -> Any: ...
     def col_specs(self) -> list[dict[str, Any]]: ...
+    def is_rows(self) -> bool: ...
     def tracing_id(self) -> str | None: ...
+    async def trace(self) -> TracingInfo | None: ...
+    def stats(self) -> dict[str, Any] | None: ...
     def warnings(self) -> list[str]: ...
-    def __iter__(self) -> Iterator[Row]: ...
-    def __next__(self) -> Row: ...
+    def paging_state(self) -> bytes | None: ...
+    def __iter__(self) -> Iterator[Any]: ...
+    def __next__(self) -> Any: ...
     def __len__(self) -> int: ...
     def __bool__(self) -> bool: ...
 
+class ResultSnapshot:
+    """Column names and converted rows of a result, detached from the driver and picklable"""
+
+    def __init__(self, columns: list[str], rows: list[list[Any]]) -> None: ...
+    def columns(self) -> list[str]: ...
+    def rows(self) -> list[Row]: ...
+    def first_row(self) -> Row | None: ...
+    def __iter__(self) -> Iterator[Row]: ...
+    def __len__(self) -> int: ...
+    def __getitem__(self, index: int) -> Row: ...
+    def __repr__(self) -> str: ...
+
+class PendingResult:
+    """A request started under trio (directly or through anyio) or by execute_nowait()"""
+
+    def done(self) -> bool: ...
+    def result(self) -> Any: ...
+    def cancel(self) -> bool: ...
+    def add_done_callback(self, callback: Callable[[], None]) -> None: ...
+    def __await__(self) -> Generator[Any, None, Any]: ...
+    def __repr__(self) -> str: ...
+
 class Row:
     """A row from a query result"""
 
+    def __init__(self, values: list[Any], names: list[str] | None = None) -> None: ...
     def columns(self) -> list[Any]: ...
-    def as_dict(self) -> dict[str, Any]: ...
+    def as_dict(self, omit_nulls: bool = False) -> dict[str, Any]: ...
     def get(self, index: int) -> Any: ...
+    def keys(self) -> list[str]: ...
+    def values(self) -> list[Any]: ...
+    def items(self) -> list[tuple[str, Any]]: ...
+    def to_json(self) -> str: ...
+    def index(self, name: str) -> int: ...
+    def __getattr__(self, name: str) -> Any: ...
     def __len__(self) -> int: ...
-    def __getitem__(self, index: int) -> Any: ...
+    @overload
+    def __getitem__(self, key: int | str) -> Any: ...
+    @overload
+    def __getitem__(self, key: slice) -> tuple[Any, ...]: ...
     def __repr__(self) -> str: ...
 
 class Batch:
@@ -105,7 +486,274 @@ class Batch:
     def with_timestamp(self, timestamp: int) -> Batch: ...
     def with_timeout(self, timeout_ms: int) -> Batch: ...
     def with_tracing(self, tracing: bool) -> Batch: ...
+    def with_retry_policy(self, retry_policy: str) -> Batch: ...
+    def with_execution_profile(self, profile: ExecutionProfile) -> Batch: ...
+    def with_fairness_class(self, fairness_class: str) -> Batch: ...
     def is_idempotent(self) -> bool: ...
     def set_idempotent(self, idempotent: bool) -> None: ...
     def statements_count(self) -> int: ...
     def __repr__(self) -> str: ...
+
+class ExecutionProfile:
+    """Reusable set of execution options shared by sessions and statements"""
+
+    def __init__(self) -> None: ...
+    def with_consistency(self, consistency: str) -> ExecutionProfile: ...
+    def with_serial_consistency(self, serial_consistency: str) -> ExecutionProfile: ...
+    def with_timeout(self, timeout_ms: int | None = None) -> ExecutionProfile: ...
+    def with_retry_policy(self, retry_policy: str) -> ExecutionProfile: ...
+    def with_load_balancing_policy(self, policy: LoadBalancingPolicy) -> ExecutionProfile: ...
+
+class LoadBalancingPolicy:
+    """Builder for the token-aware, DC-aware default load balancing policy"""
+
+    def __init__(self) -> None: ...
+    def prefer_datacenter(self, datacenter: str) -> LoadBalancingPolicy: ...
+    def prefer_datacenter_and_rack(self, datacenter: str, rack: str) -> LoadBalancingPolicy: ...
+    def token_aware(self, enabled: bool) -> LoadBalancingPolicy: ...
+    def permit_dc_failover(self, permit: bool) -> LoadBalancingPolicy: ...
+    def enable_shuffling_replicas(self, enabled: bool) -> LoadBalancingPolicy: ...
+    def latency_awareness(
+        self,
+        exclusion_threshold: float = 2.0,
+        retry_period_ms: int = 10000,
+        update_rate_ms: int = 100,
+        minimum_measurements: int = 50,
+        scale_ms: int = 100,
+    ) -> LoadBalancingPolicy: ...
+
+class Pipeline:
+    """Batches executed with bounded concurrency and per-batch outcome reporting"""
+
+    def __init__(
+        self, concurrency: int = 16, retries: int = 0, retry_delay_ms: int = 100
+    ) -> None: ...
+    def add(self, batch: Batch, values: list[dict[str, Any]]) -> None: ...
+    def __len__(self) -> int: ...
+    def __repr__(self) -> str: ...
+
+class OrderedWriter:
+    """Applies writes in submission order per key, in parallel across keys"""
+
+    def submit(
+        self,
+        key: Any,
+        statement: str | Query | PreparedStatement,
+        values: dict[str, Any] | None = None,
+    ) -> QueryResult: ...
+    def pending_keys(self) -> int: ...
+
+class Pager:
+    """Async row iterator over a paged statement with optional read-ahead"""
+
+    def __aiter__(self) -> AsyncIterator[Row]: ...
+    async def __anext__(self) -> Row: ...
+    def paging_state(self) -> bytes | None: ...
+    def is_prefetching(self) -> bool: ...
+    def pages_fetched(self) -> int: ...
+    def pages_consumed(self) -> int: ...
+
+class PageIterator:
+    """Async iterator yielding one QueryResult per page of a paged statement"""
+
+    def __aiter__(self) -> AsyncIterator[QueryResult]: ...
+    async def __anext__(self) -> QueryResult: ...
+    def paging_state(self) -> bytes | None: ...
+
+class FullScan:
+    """Async row iterator over a whole table, scanning token ranges concurrently"""
+
+    def __aiter__(self) -> AsyncIterator[Row]: ...
+    async def __anext__(self) -> Row: ...
+    def cancel(self) -> None: ...
+
+class PrometheusExporter:
+    """HTTP endpoint serving the session's metrics to Prometheus scrapes"""
+
+    address: str
+
+    def is_running(self) -> bool: ...
+    def stop(self) -> None: ...
+
+class RequestEvent:
+    """A request seen by a request hook; outcome fields are set once it completes"""
+
+    operation: str
+    statement: str
+    elapsed_ms: float | None
+    coordinator: str | None
+    error: BaseException | None
+
+class TracingEvent:
+    """A single event recorded while executing a traced request"""
+
+    event_id: str
+    activity: str | None
+    source: str | None
+    source_elapsed: int | None
+    thread: str | None
+
+class TracingInfo:
+    """Trace of a request, from system_traces"""
+
+    client: str | None
+    command: str | None
+    coordinator: str | None
+    duration: int | None
+    parameters: dict[str, str]
+    request: str | None
+    started_at: int | None
+    events: list[TracingEvent]
+
+    def nodes(self) -> list[str]: ...
+
+class ClusterEvent:
+    """A topology, node status or schema change"""
+
+    kind: str
+    address: str | None
+    host_id: str | None
+    change: str | None
+    target: str | None
+    keyspace: str | None
+    name: str | None
+
+class ClusterEvents:
+    """Async iterator of cluster events"""
+
+    def __aiter__(self) -> AsyncIterator[ClusterEvent]: ...
+    async def __anext__(self) -> ClusterEvent: ...
+    def close(self) -> None: ...
+
+class NodeInfo:
+    """A node of the cluster as seen by the driver"""
+
+    address: str
+    datacenter: str | None
+    rack: str | None
+    host_id: str
+    scylla_version: str | None
+    shard_count: int | None
+    is_up: bool
+
+class NodeHealth:
+    """Outcome of probing a node with Session.check_connectivity()"""
+
+    address: str
+    datacenter: str | None
+    rack: str | None
+    host_id: str
+    connected: bool
+    healthy: bool
+    latency_ms: float | None
+    error: str | None
+
+class ServerConnection:
+    """A client connection as a node lists it in system.clients"""
+
+    node: str
+    address: str
+    port: int
+    client_type: str | None
+    shard_id: int | None
+    connection_stage: str | None
+    driver_name: str | None
+    driver_version: str | None
+    hostname: str | None
+    protocol_version: int | None
+    username: str | None
+    ssl_enabled: bool | None
+
+class ClusterMetadata:
+    """Snapshot of the cluster topology"""
+
+    cluster_name: str
+    nodes: list[NodeInfo]
+
+    def datacenters(self) -> list[str]: ...
+
+class ColumnMetadata:
+    """A column of a table"""
+
+    name: str
+    cql_type: str
+    kind: str
+
+class CqlType:
+    """A CQL type broken down into its parts"""
+
+    kind: str
+    name: str
+    frozen: bool
+    element_types: list[CqlType]
+    udt_name: str | None
+    udt_keyspace: str | None
+    fields: list[tuple[str, CqlType]]
+    dimensions: int | None
+
+class TableMetadata:
+    """Schema of a table"""
+
+    name: str
+    partition_key: list[str]
+    clustering_key: list[str]
+    columns: list[ColumnMetadata]
+    partitioner: str | None
+    indexes: list[IndexMetadata]
+
+    def column(self, name: str) -> ColumnMetadata | None: ...
+
+class IndexMetadata:
+    """A secondary index on a table"""
+
+    name: str
+    table: str
+    kind: str
+    target: str | None
+    options: dict[str, str]
+
+class MaterializedViewMetadata:
+    """Schema of a materialized view"""
+
+    name: str
+    base_table: str
+    partition_key: list[str]
+    clustering_key: list[str]
+    columns: list[ColumnMetadata]
+
+    def included_columns(self) -> list[str]: ...
+
+class UserTypeMetadata:
+    """Definition of a user-defined type"""
+
+    name: str
+    fields: list[tuple[str, str]]
+
+class KeyspaceMetadata:
+    """Schema of a keyspace"""
+
+    name: str
+    strategy: str
+    replication: dict[str, str]
+    durable_writes: bool
+    tables: dict[str, TableMetadata]
+    views: dict[str, MaterializedViewMetadata]
+    user_types: dict[str, UserTypeMetadata]
+
+    def table(self, name: str) -> TableMetadata | None: ...
+    def view(self, name: str) -> MaterializedViewMetadata | None: ...
+    def views_of(self, table: str) -> list[MaterializedViewMetadata]: ...
+
+def build_info() -> dict[str, Any]: ...
+def configure_logging(level: str = "warning") -> None: ...
+def use_event_loop(
+    backend: Literal["auto", "asyncio", "uvloop"] = "auto",
+) -> Literal["asyncio", "uvloop"]: ...
+def event_loop_backend() -> str: ...
+def shutdown(timeout_ms: int = 5000) -> int: ...
+def diff_rows(
+    row_a: Row, row_b: Row, columns: list[str] | None = None
+) -> dict[str, tuple[Any, Any]]: ...
+def diff_results(
+    res_a: QueryResult, res_b: QueryResult, key_columns: list[str]
+) -> list[dict[str, Any]]: ...
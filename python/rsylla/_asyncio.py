@@ -0,0 +1,33 @@
+"""
+Awaiting a PendingResult, such as the handle from Session.execute_nowait(), under asyncio
+"""
+
+import asyncio
+
+
+async def wait(pending):
+    """Wait on the running loop until `pending` completes and return its result.
+
+    Cancelling the task cancels the request.
+    """
+    loop = asyncio.get_running_loop()
+    completed = loop.create_future()
+
+    def wake():
+        if not completed.done():
+            completed.set_result(None)
+
+    def on_done():
+        try:
+            loop.call_soon_threadsafe(wake)
+        except RuntimeError:
+            # The loop has closed; nobody is waiting anymore
+            pass
+
+    pending.add_done_callback(on_done)
+    try:
+        await completed
+    except asyncio.CancelledError:
+        pending.cancel()
+        raise
+    return pending.result()
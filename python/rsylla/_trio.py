@@ -0,0 +1,38 @@
+"""
+Awaiting rsylla requests under trio, directly or through anyio's trio backend
+"""
+
+import trio
+
+
+async def wait(pending):
+    """Park the current trio task until `pending` completes and return its result.
+
+    Cancelling the task cancels the request.
+    """
+    token = trio.lowlevel.current_trio_token()
+    task = trio.lowlevel.current_task()
+    waiting = True
+
+    def wake():
+        # Runs on the trio thread, so it can't interleave with abort()
+        nonlocal waiting
+        if waiting:
+            waiting = False
+            trio.lowlevel.reschedule(task)
+
+    def on_done():
+        try:
+            token.run_sync_soon(wake)
+        except trio.RunFinishedError:
+            pass
+
+    def abort(raise_cancel):
+        nonlocal waiting
+        waiting = False
+        pending.cancel()
+        return trio.lowlevel.Abort.SUCCEEDED
+
+    pending.add_done_callback(on_done)
+    await trio.lowlevel.wait_task_rescheduled(abort)
+    return pending.result()
@@ -0,0 +1,19 @@
+"""
+rsylla.qb - CQL query builder
+
+Builders generate CQL with named bind markers, so values always travel as bound
+parameters rather than being spliced into the statement text.
+"""
+
+from ._rsylla import Delete, Insert, Select, Update, delete, insert_into, select, update
+
+__all__ = [
+    "select",
+    "insert_into",
+    "update",
+    "delete",
+    "Select",
+    "Insert",
+    "Update",
+    "Delete",
+]
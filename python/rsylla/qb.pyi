@@ -0,0 +1,43 @@
+"""Type stubs for rsylla.qb"""
+
+class Select:
+    """SELECT statement builder"""
+
+    def where(self, column: str, op: str = "=", name: str | None = None) -> Select: ...
+    def order_by(self, column: str, descending: bool = False) -> Select: ...
+    def limit(self, limit: int) -> Select: ...
+    def allow_filtering(self) -> Select: ...
+    def bypass_cache(self) -> Select: ...
+    def build(self) -> str: ...
+
+class Insert:
+    """INSERT statement builder"""
+
+    def values(self, columns: list[str]) -> Insert: ...
+    def if_not_exists(self) -> Insert: ...
+    def using_ttl(self, ttl: int) -> Insert: ...
+    def using_timestamp(self, timestamp: int) -> Insert: ...
+    def build(self) -> str: ...
+
+class Update:
+    """UPDATE statement builder"""
+
+    def set(self, column: str, name: str | None = None) -> Update: ...
+    def where(self, column: str, op: str = "=", name: str | None = None) -> Update: ...
+    def if_exists(self) -> Update: ...
+    def using_ttl(self, ttl: int) -> Update: ...
+    def using_timestamp(self, timestamp: int) -> Update: ...
+    def build(self) -> str: ...
+
+class Delete:
+    """DELETE statement builder"""
+
+    def where(self, column: str, op: str = "=", name: str | None = None) -> Delete: ...
+    def if_exists(self) -> Delete: ...
+    def using_timestamp(self, timestamp: int) -> Delete: ...
+    def build(self) -> str: ...
+
+def select(table: str, columns: list[str] | None = None) -> Select: ...
+def insert_into(table: str) -> Insert: ...
+def update(table: str) -> Update: ...
+def delete(table: str, columns: list[str] | None = None) -> Delete: ...
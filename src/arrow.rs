@@ -0,0 +1,107 @@
+use arrow_array::types::{
+    ArrowPrimitiveType, Date32Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type,
+    Int8Type, Time64NanosecondType, TimestampMillisecondType,
+};
+use arrow_array::{
+    Array, ArrayRef, BinaryArray, BooleanArray, PrimitiveArray, RecordBatch, RecordBatchOptions,
+    StringArray, StructArray,
+};
+use arrow_buffer::{BooleanBuffer, NullBuffer, ScalarBuffer};
+use arrow_data::ffi::FFI_ArrowArray;
+use arrow_schema::ffi::FFI_ArrowSchema;
+use arrow_schema::{Field, Schema};
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+use crate::columnar::{Column, ColumnData, Primitive};
+use crate::json::cql_value_to_json;
+
+fn null_buffer(valid: Vec<bool>) -> Option<NullBuffer> {
+    let nulls = NullBuffer::from(valid);
+    (nulls.null_count() > 0).then_some(nulls)
+}
+
+fn primitive_array<T: ArrowPrimitiveType>(column: Primitive<T::Native>) -> PrimitiveArray<T> {
+    PrimitiveArray::new(ScalarBuffer::from(column.values), null_buffer(column.valid))
+}
+
+fn column_array(data: ColumnData) -> ArrayRef {
+    match data {
+        ColumnData::Boolean(column) => Arc::new(BooleanArray::new(
+            BooleanBuffer::from(column.values),
+            null_buffer(column.valid),
+        )),
+        ColumnData::TinyInt(column) => Arc::new(primitive_array::<Int8Type>(column)),
+        ColumnData::SmallInt(column) => Arc::new(primitive_array::<Int16Type>(column)),
+        ColumnData::Int(column) => Arc::new(primitive_array::<Int32Type>(column)),
+        ColumnData::BigInt(column) => Arc::new(primitive_array::<Int64Type>(column)),
+        ColumnData::Float(column) => Arc::new(primitive_array::<Float32Type>(column)),
+        ColumnData::Double(column) => Arc::new(primitive_array::<Float64Type>(column)),
+        ColumnData::Timestamp(column) => {
+            Arc::new(primitive_array::<TimestampMillisecondType>(column).with_timezone_utc())
+        }
+        ColumnData::Date(column) => Arc::new(primitive_array::<Date32Type>(column)),
+        ColumnData::Time(column) => Arc::new(primitive_array::<Time64NanosecondType>(column)),
+        ColumnData::Text(values) => Arc::new(StringArray::from(values)),
+        ColumnData::Blob(values) => Arc::new(BinaryArray::from_iter(values)),
+        // Nested values travel as JSON text rather than nested Arrow types.
+        ColumnData::Other(values) => {
+            Arc::new(StringArray::from_iter(values.iter().map(|value| {
+                value.as_ref().map(|v| cql_value_to_json(v).to_string())
+            })))
+        }
+    }
+}
+
+/// Assemble decoded columns into a record batch with one nullable field per column.
+pub fn record_batch(columns: Vec<Column>, num_rows: usize) -> PyResult<RecordBatch> {
+    let (fields, arrays): (Vec<Field>, Vec<ArrayRef>) = columns
+        .into_iter()
+        .map(|column| {
+            let array = column_array(column.data);
+            (
+                Field::new(column.name, array.data_type().clone(), true),
+                array,
+            )
+        })
+        .unzip();
+
+    let options = RecordBatchOptions::new().with_row_count(Some(num_rows));
+    RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), arrays, &options).map_err(
+        |e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to build Arrow batch: {}",
+                e
+            ))
+        },
+    )
+}
+
+/// Hand a batch to pyarrow through the Arrow C data interface, without copying buffers.
+pub fn to_pyarrow<'py>(py: Python<'py>, batch: RecordBatch) -> PyResult<Bound<'py, PyAny>> {
+    let pyarrow = py.import("pyarrow").map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyImportError, _>(
+            "Arrow export requires the 'pyarrow' package",
+        )
+    })?;
+
+    let data = StructArray::from(batch).into_data();
+    let schema = FFI_ArrowSchema::try_from(data.data_type()).map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+            "Failed to export Arrow schema: {}",
+            e
+        ))
+    })?;
+    let mut schema = Box::new(schema);
+    let mut array = Box::new(FFI_ArrowArray::new(&data));
+
+    // pyarrow moves both structs out and takes over releasing them; if the import fails
+    // they are still ours and are released when the boxes drop.
+    pyarrow.getattr("RecordBatch")?.call_method1(
+        "_import_from_c",
+        (
+            &mut *array as *mut FFI_ArrowArray as usize,
+            &mut *schema as *mut FFI_ArrowSchema as usize,
+        ),
+    )
+}
@@ -1,13 +1,22 @@
 use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla::client::session::Session as ScyllaSession;
 use scylla::statement::batch::Batch as ScyllaBatch;
 use scylla::statement::Consistency;
+use std::collections::HashMap;
+use std::ops::Range;
 
+use crate::execution_profile::ExecutionProfile;
+use crate::policies::parse_retry_policy;
 use crate::query::{PreparedStatement, Query};
+use crate::rate_limit::RequestLimiter;
+use crate::types::SerializableValue;
 
 #[pyclass]
 #[derive(Clone)]
 pub struct Batch {
     pub(crate) inner: ScyllaBatch,
+    pub(crate) fairness_class: Option<String>,
 }
 
 #[pymethods]
@@ -28,6 +37,7 @@ impl Batch {
 
         Ok(Batch {
             inner: ScyllaBatch::new(btype),
+            fairness_class: None,
         })
     }
 
@@ -71,6 +81,23 @@ impl Batch {
         Ok(self.clone())
     }
 
+    pub fn with_retry_policy(&mut self, retry_policy: &str) -> PyResult<Self> {
+        let policy = parse_retry_policy(retry_policy)?;
+        self.inner.set_retry_policy(Some(policy));
+        Ok(self.clone())
+    }
+
+    pub fn with_execution_profile(&mut self, profile: &ExecutionProfile) -> PyResult<Self> {
+        self.inner
+            .set_execution_profile_handle(Some(profile.handle()));
+        Ok(self.clone())
+    }
+
+    pub fn with_fairness_class(&mut self, fairness_class: &str) -> PyResult<Self> {
+        self.fairness_class = Some(fairness_class.to_string());
+        Ok(self.clone())
+    }
+
     pub fn is_idempotent(&self) -> bool {
         self.inner.get_is_idempotent()
     }
@@ -88,6 +115,78 @@ impl Batch {
     }
 }
 
+impl Batch {
+    /// The statements split into consecutive batches of at most `size`, sharing this
+    /// batch's settings, each with the statement indexes it covers.
+    pub(crate) fn chunks(&self, size: usize) -> Vec<(ScyllaBatch, Range<usize>)> {
+        let statements = &self.inner.statements;
+        (0..statements.len())
+            .step_by(size)
+            .map(|start| {
+                let range = start..(start + size).min(statements.len());
+                let mut chunk = self.inner.clone();
+                chunk.statements = statements[range.clone()].to_vec();
+                (chunk, range)
+            })
+            .collect()
+    }
+}
+
+/// How one server batch of a chunked batch went.
+pub struct ChunkOutcome {
+    index: usize,
+    statements: Range<usize>,
+    error: Option<String>,
+    warnings: Vec<String>,
+}
+
+impl ChunkOutcome {
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn to_py(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let dict = PyDict::new(py);
+        dict.set_item("index", self.index)?;
+        dict.set_item("statements", (self.statements.start, self.statements.end))?;
+        dict.set_item("success", self.succeeded())?;
+        dict.set_item("error", self.error.clone())?;
+        dict.set_item("warnings", self.warnings.clone())?;
+        Ok(dict.into())
+    }
+}
+
+/// Execute the chunks one after another, carrying on past failures so every chunk is
+/// reported.
+pub async fn execute_chunks(
+    session: &ScyllaSession,
+    limiter: &RequestLimiter,
+    chunks: Vec<(ScyllaBatch, Range<usize>)>,
+    values: &[HashMap<String, SerializableValue>],
+) -> Vec<ChunkOutcome> {
+    let mut outcomes = Vec::with_capacity(chunks.len());
+    for (index, (chunk, statements)) in chunks.into_iter().enumerate() {
+        let slot = limiter.acquire().await;
+        let result = session.batch(&chunk, &values[statements.clone()]).await;
+        drop(slot);
+        outcomes.push(match result {
+            Ok(result) => ChunkOutcome {
+                index,
+                statements,
+                error: None,
+                warnings: result.warnings().map(|w| w.to_string()).collect(),
+            },
+            Err(err) => ChunkOutcome {
+                index,
+                statements,
+                error: Some(err.to_string()),
+                warnings: Vec::new(),
+            },
+        });
+    }
+    outcomes
+}
+
 fn parse_consistency(consistency: &str) -> PyResult<Consistency> {
     match consistency.to_uppercase().as_str() {
         "ANY" => Ok(Consistency::Any),
@@ -0,0 +1,506 @@
+use futures::stream::{self, StreamExt, TryStreamExt};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::response::{PagingState, PagingStateResponse};
+use scylla::statement::prepared::PreparedStatement as ScyllaPreparedStatement;
+use scylla::value::{CqlTimestamp, CqlTimeuuid, CqlValue, Row as ScyllaRow};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+use crate::error::{deserialization_error_to_py, prepare_error_to_py, query_error_to_py};
+use crate::runtime::future_into_py;
+use crate::types::cql_value_to_py;
+
+/// Scylla rejects `IN` restrictions longer than this by default.
+const STREAMS_PER_QUERY: usize = 100;
+/// Stream queries of one window run this many at a time.
+const CONCURRENT_QUERIES: usize = 8;
+/// Catching up on old changes reads the log in windows of at most this long.
+const MAX_WINDOW_MS: i64 = 60_000;
+/// Log queries are paged, so a busy window doesn't come back as one huge response.
+const LOG_PAGE_SIZE: i32 = 1000;
+
+const OP_PRE_IMAGE: i8 = 0;
+const OP_POST_IMAGE: i8 = 9;
+
+fn operation_name(operation: i8) -> &'static str {
+    match operation {
+        0 => "pre_image",
+        1 => "update",
+        2 => "insert",
+        3 => "row_delete",
+        4 => "partition_delete",
+        5 => "range_delete_start_inclusive",
+        6 => "range_delete_start_exclusive",
+        7 => "range_delete_end_inclusive",
+        8 => "range_delete_end_exclusive",
+        9 => "post_image",
+        _ => "unknown",
+    }
+}
+
+fn rows_error(err: impl std::fmt::Display) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to get rows result: {}", err))
+}
+
+fn now_ms() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+type Columns = Vec<(String, Option<CqlValue>)>;
+
+fn columns_to_py<'py>(py: Python<'py>, columns: &Columns) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new(py);
+    for (name, value) in columns {
+        match value {
+            Some(value) => dict.set_item(name, cql_value_to_py(py, value)?)?,
+            None => dict.set_item(name, py.None())?,
+        }
+    }
+    Ok(dict)
+}
+
+/// One change from a table's CDC log, with the pre- and post-image recorded in the
+/// same batch when the table has images enabled.
+#[pyclass]
+pub struct ChangeEvent {
+    stream_id: Vec<u8>,
+    time: CqlTimeuuid,
+    batch_seq_no: i32,
+    operation: i8,
+    ttl: Option<i64>,
+    columns: Columns,
+    deleted_columns: Vec<String>,
+    pre_image: Option<Columns>,
+    post_image: Option<Columns>,
+}
+
+#[pymethods]
+impl ChangeEvent {
+    /// `insert`, `update`, `row_delete`, `partition_delete` or one of the `range_delete_*`
+    /// bounds.
+    #[getter]
+    pub fn operation(&self) -> &'static str {
+        operation_name(self.operation)
+    }
+
+    #[getter]
+    pub fn stream_id<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.stream_id)
+    }
+
+    /// The change's `cdc$time` timeuuid.
+    #[getter]
+    pub fn time(&self) -> String {
+        self.time.to_string()
+    }
+
+    /// Milliseconds since the Unix epoch at which the change was written.
+    #[getter]
+    pub fn time_ms(&self) -> Option<i64> {
+        let (secs, nanos) = uuid::Uuid::from(self.time).get_timestamp()?.to_unix();
+        Some(secs as i64 * 1000 + nanos as i64 / 1_000_000)
+    }
+
+    #[getter]
+    pub fn batch_seq_no(&self) -> i32 {
+        self.batch_seq_no
+    }
+
+    #[getter]
+    pub fn ttl(&self) -> Option<i64> {
+        self.ttl
+    }
+
+    /// Base table columns as written by the change; unchanged columns are `None`.
+    #[getter]
+    pub fn columns<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        columns_to_py(py, &self.columns)
+    }
+
+    /// Columns the change set to null.
+    #[getter]
+    pub fn deleted_columns(&self) -> Vec<String> {
+        self.deleted_columns.clone()
+    }
+
+    #[getter]
+    pub fn pre_image<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        self.pre_image
+            .as_ref()
+            .map(|columns| columns_to_py(py, columns))
+            .transpose()
+    }
+
+    #[getter]
+    pub fn post_image<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        self.post_image
+            .as_ref()
+            .map(|columns| columns_to_py(py, columns))
+            .transpose()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ChangeEvent(operation={}, time={}, batch_seq_no={})",
+            self.operation(),
+            self.time,
+            self.batch_seq_no
+        )
+    }
+}
+
+/// A log row split into its `cdc$` metadata and the base table columns.
+struct LogRow {
+    stream_id: Vec<u8>,
+    time: CqlTimeuuid,
+    batch_seq_no: i32,
+    operation: i8,
+    ttl: Option<i64>,
+    columns: Columns,
+    deleted_columns: Vec<String>,
+}
+
+impl LogRow {
+    fn new(names: &[String], row: ScyllaRow) -> Option<Self> {
+        let mut log_row = LogRow {
+            stream_id: Vec::new(),
+            time: CqlTimeuuid::nil(),
+            batch_seq_no: 0,
+            operation: -1,
+            ttl: None,
+            columns: Vec::new(),
+            deleted_columns: Vec::new(),
+        };
+        for (name, value) in names.iter().zip(row.columns) {
+            match (name.as_str(), value) {
+                ("cdc$stream_id", Some(CqlValue::Blob(id))) => log_row.stream_id = id,
+                ("cdc$time", Some(CqlValue::Timeuuid(time))) => log_row.time = time,
+                ("cdc$batch_seq_no", Some(CqlValue::Int(seq))) => log_row.batch_seq_no = seq,
+                ("cdc$operation", Some(CqlValue::TinyInt(op))) => log_row.operation = op,
+                ("cdc$ttl", Some(CqlValue::BigInt(ttl))) => log_row.ttl = Some(ttl),
+                (name, Some(CqlValue::Boolean(true))) if name.starts_with("cdc$deleted_") => {
+                    if let Some(column) = name.strip_prefix("cdc$deleted_") {
+                        if !column.starts_with("elements_") {
+                            log_row.deleted_columns.push(column.to_string());
+                        }
+                    }
+                }
+                (name, _) if name.starts_with("cdc$") => {}
+                (name, value) => log_row.columns.push((name.to_string(), value)),
+            }
+        }
+        (log_row.operation >= 0).then_some(log_row)
+    }
+}
+
+/// Order log rows by time, then stream and position in batch, attaching each batch's
+/// pre- and post-images to its changes.
+fn change_events(mut rows: Vec<LogRow>) -> Vec<ChangeEvent> {
+    rows.sort_by(|a, b| {
+        (a.time, &a.stream_id, a.batch_seq_no).cmp(&(b.time, &b.stream_id, b.batch_seq_no))
+    });
+
+    let mut events = Vec::new();
+    let mut rows = rows.into_iter().peekable();
+    while let Some(first) = rows.next() {
+        let mut batch = vec![first];
+        while let Some(row) =
+            rows.next_if(|row| row.time == batch[0].time && row.stream_id == batch[0].stream_id)
+        {
+            batch.push(row);
+        }
+
+        let mut pre_image = None;
+        let mut post_image = None;
+        let mut changes = Vec::new();
+        for row in batch {
+            match row.operation {
+                OP_PRE_IMAGE => pre_image = Some(row.columns),
+                OP_POST_IMAGE => post_image = Some(row.columns),
+                _ => changes.push(row),
+            }
+        }
+        events.extend(changes.into_iter().map(|row| ChangeEvent {
+            stream_id: row.stream_id,
+            time: row.time,
+            batch_seq_no: row.batch_seq_no,
+            operation: row.operation,
+            ttl: row.ttl,
+            columns: row.columns,
+            deleted_columns: row.deleted_columns,
+            pre_image: pre_image.clone(),
+            post_image: post_image.clone(),
+        }));
+    }
+    events
+}
+
+/// A CDC generation: the set of streams changes are written to from `start_ms` on.
+struct Generation {
+    start_ms: i64,
+    streams: Vec<Vec<u8>>,
+}
+
+struct ReaderState {
+    session: Arc<ScyllaSession>,
+    log_table: String,
+    log_query: Option<ScyllaPreparedStatement>,
+    /// Changes up to and including this time have been read.
+    cursor_ms: i64,
+    end_ms: Option<i64>,
+    poll_interval: Duration,
+    confidence_window_ms: i64,
+    generation: Option<Generation>,
+    pending: VecDeque<ChangeEvent>,
+}
+
+impl ReaderState {
+    async fn generation_starts(&self) -> PyResult<Vec<i64>> {
+        let result = self
+            .session
+            .query_unpaged(
+                "SELECT time FROM system_distributed.cdc_generation_timestamps \
+                 WHERE key = 'timestamps'",
+                (),
+            )
+            .await
+            .map_err(query_error_to_py)?;
+        let rows = result.into_rows_result().map_err(rows_error)?;
+        let mut starts = rows
+            .rows::<(CqlTimestamp,)>()
+            .map_err(rows_error)?
+            .map(|row| row.map(|(start,)| start.0))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(deserialization_error_to_py)?;
+        starts.sort_unstable();
+        Ok(starts)
+    }
+
+    async fn generation_streams(&self, start_ms: i64) -> PyResult<Vec<Vec<u8>>> {
+        let result = self
+            .session
+            .query_unpaged(
+                "SELECT streams FROM system_distributed.cdc_streams_descriptions_v2 \
+                 WHERE time = ?",
+                (CqlTimestamp(start_ms),),
+            )
+            .await
+            .map_err(query_error_to_py)?;
+        let rows = result.into_rows_result().map_err(rows_error)?;
+        let mut streams = Vec::new();
+        for row in rows.rows::<(Vec<Vec<u8>>,)>().map_err(rows_error)? {
+            streams.extend(row.map_err(deserialization_error_to_py)?.0);
+        }
+        Ok(streams)
+    }
+
+    async fn log_query(&mut self) -> PyResult<ScyllaPreparedStatement> {
+        if let Some(prepared) = &self.log_query {
+            return Ok(prepared.clone());
+        }
+        let mut prepared = self
+            .session
+            .prepare(format!(
+                "SELECT * FROM {} WHERE \"cdc$stream_id\" IN ? \
+                 AND \"cdc$time\" > maxTimeuuid(?) AND \"cdc$time\" <= maxTimeuuid(?)",
+                self.log_table
+            ))
+            .await
+            .map_err(prepare_error_to_py)?;
+        prepared.set_page_size(LOG_PAGE_SIZE);
+        self.log_query = Some(prepared.clone());
+        Ok(prepared)
+    }
+
+    /// Read every stream of the current generation over `(cursor_ms, to_ms]`.
+    async fn read_window(&mut self, to_ms: i64) -> PyResult<Vec<ChangeEvent>> {
+        let prepared = self.log_query().await?;
+        let Some(generation) = &self.generation else {
+            return Ok(Vec::new());
+        };
+        let from = CqlTimestamp(self.cursor_ms);
+        let to = CqlTimestamp(to_ms);
+        let chunks: Vec<Vec<Vec<u8>>> = generation
+            .streams
+            .chunks(STREAMS_PER_QUERY)
+            .map(<[_]>::to_vec)
+            .collect();
+
+        let batches: Vec<Vec<LogRow>> = stream::iter(chunks)
+            .map(|streams| {
+                let session = self.session.clone();
+                let prepared = prepared.clone();
+                async move {
+                    let values = (streams, from, to);
+                    let mut paging_state = PagingState::start();
+                    let mut log_rows = Vec::new();
+                    loop {
+                        let (result, paging_state_response) = session
+                            .execute_single_page(&prepared, &values, paging_state)
+                            .await
+                            .map_err(query_error_to_py)?;
+                        let rows = result.into_rows_result().map_err(rows_error)?;
+                        let names: Vec<String> = rows
+                            .column_specs()
+                            .iter()
+                            .map(|spec| spec.name().to_string())
+                            .collect();
+                        for row in rows.rows::<ScyllaRow>().map_err(rows_error)? {
+                            let row = row.map_err(deserialization_error_to_py)?;
+                            log_rows.extend(LogRow::new(&names, row));
+                        }
+                        match paging_state_response {
+                            PagingStateResponse::HasMorePages { state } => paging_state = state,
+                            PagingStateResponse::NoMorePages => break,
+                        }
+                    }
+                    Ok::<_, PyErr>(log_rows)
+                }
+            })
+            .buffer_unordered(CONCURRENT_QUERIES)
+            .try_collect()
+            .await?;
+
+        Ok(change_events(batches.into_iter().flatten().collect()))
+    }
+
+    /// Move on to the generation in effect right after the cursor. Returns the end of
+    /// that generation, if a later one is already known.
+    async fn advance_generation(&mut self) -> PyResult<Option<i64>> {
+        let starts = self.generation_starts().await?;
+        let current = starts
+            .iter()
+            .rev()
+            .find(|start| **start <= self.cursor_ms + 1)
+            .or(starts.first())
+            .copied();
+        let Some(current) = current else {
+            self.generation = None;
+            return Ok(None);
+        };
+        // Nothing can have been written before the first generation started.
+        self.cursor_ms = self.cursor_ms.max(current - 1);
+
+        if self.generation.as_ref().map(|g| g.start_ms) != Some(current) {
+            self.generation = Some(Generation {
+                start_ms: current,
+                streams: self.generation_streams(current).await?,
+            });
+        }
+        Ok(starts.into_iter().find(|start| *start > current))
+    }
+
+    /// Read windows until one holds changes; `false` once closed or past `end_ms`.
+    async fn poll(&mut self, closed: &AtomicBool) -> PyResult<bool> {
+        loop {
+            if closed.load(Ordering::Relaxed)
+                || self.end_ms.is_some_and(|end_ms| self.cursor_ms >= end_ms)
+            {
+                return Ok(false);
+            }
+
+            let next_generation = self.advance_generation().await?;
+            let live_ms = now_ms() - self.confidence_window_ms;
+            let mut to_ms = live_ms.min(self.cursor_ms + MAX_WINDOW_MS);
+            if let Some(end_ms) = self.end_ms {
+                to_ms = to_ms.min(end_ms);
+            }
+            if let Some(next_generation) = next_generation {
+                to_ms = to_ms.min(next_generation - 1);
+            }
+
+            if self.generation.is_none() || to_ms <= self.cursor_ms {
+                tokio::time::sleep(self.poll_interval).await;
+                continue;
+            }
+
+            let events = self.read_window(to_ms).await?;
+            self.cursor_ms = to_ms;
+            if !events.is_empty() {
+                self.pending.extend(events);
+                return Ok(true);
+            }
+            if to_ms >= live_ms {
+                tokio::time::sleep(self.poll_interval).await;
+            }
+        }
+    }
+}
+
+/// Async iterator over the changes recorded in a table's CDC log, in write order.
+/// Follows generation switches and stays `confidence_window_ms` behind the present so
+/// late writes are not skipped.
+#[pyclass]
+pub struct CDCReader {
+    state: Arc<Mutex<ReaderState>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl CDCReader {
+    pub fn new(
+        session: Arc<ScyllaSession>,
+        log_table: String,
+        start_ms: Option<i64>,
+        end_ms: Option<i64>,
+        poll_interval_ms: u64,
+        confidence_window_ms: i64,
+    ) -> Self {
+        CDCReader {
+            state: Arc::new(Mutex::new(ReaderState {
+                session,
+                log_table,
+                log_query: None,
+                cursor_ms: start_ms.unwrap_or_else(now_ms),
+                end_ms,
+                poll_interval: Duration::from_millis(poll_interval_ms),
+                confidence_window_ms,
+                generation: None,
+                pending: VecDeque::new(),
+            })),
+            closed: Arc::new(AtomicBool::new(false)),
+        }
+    }
+}
+
+#[pymethods]
+impl CDCReader {
+    pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let state = self.state.clone();
+        let closed = self.closed.clone();
+
+        future_into_py(py, async move {
+            let mut state = state.lock().await;
+            loop {
+                if let Some(event) = state.pending.pop_front() {
+                    return Ok(event);
+                }
+                if !state.poll(&closed).await? {
+                    return Err(PyErr::new::<pyo3::exceptions::PyStopAsyncIteration, _>(()));
+                }
+            }
+        })
+    }
+
+    /// Time up to which the log has been read, in milliseconds since the Unix epoch.
+    /// Changes already read but not yet consumed are at or before it.
+    pub fn position_ms(&self) -> Option<i64> {
+        self.state.try_lock().ok().map(|state| state.cursor_ms)
+    }
+
+    /// Stop reading; iteration ends once already read changes are consumed.
+    pub fn close(&self) {
+        self.closed.store(true, Ordering::Relaxed);
+    }
+}
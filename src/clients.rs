@@ -0,0 +1,149 @@
+use futures::future::try_join_all;
+use pyo3::prelude::*;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::cluster::Node;
+use scylla::policies::load_balancing::{NodeIdentifier, SingleTargetLoadBalancingPolicy};
+use scylla::statement::unprepared::Statement as ScyllaStatement;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use crate::error::{deserialization_error_to_py, query_error_to_py};
+
+/// The name the Rust driver, and so rsylla, reports when it opens a connection.
+const DRIVER_NAME: &str = "ScyllaDB Rust Driver";
+
+const CLIENTS_QUERY: &str = "SELECT address, port, client_type, shard_id, connection_stage, \
+    driver_name, driver_version, hostname, protocol_version, username, ssl_enabled \
+    FROM system.clients";
+
+type ClientRow = (
+    IpAddr,
+    i32,
+    Option<String>,
+    Option<i32>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    Option<i32>,
+    Option<String>,
+    Option<bool>,
+);
+
+fn rows_error(err: impl std::fmt::Display) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Failed to get rows result: {}", err))
+}
+
+/// One client connection as a node lists it in `system.clients`.
+#[pyclass]
+#[derive(Clone)]
+pub struct ServerConnection {
+    /// Address of the node holding the connection.
+    #[pyo3(get)]
+    pub node: String,
+    /// Client address and port as the node sees them, after any NAT.
+    #[pyo3(get)]
+    pub address: String,
+    #[pyo3(get)]
+    pub port: i32,
+    #[pyo3(get)]
+    pub client_type: Option<String>,
+    /// Shard of the node serving the connection.
+    #[pyo3(get)]
+    pub shard_id: Option<i32>,
+    #[pyo3(get)]
+    pub connection_stage: Option<String>,
+    #[pyo3(get)]
+    pub driver_name: Option<String>,
+    #[pyo3(get)]
+    pub driver_version: Option<String>,
+    #[pyo3(get)]
+    pub hostname: Option<String>,
+    #[pyo3(get)]
+    pub protocol_version: Option<i32>,
+    #[pyo3(get)]
+    pub username: Option<String>,
+    #[pyo3(get)]
+    pub ssl_enabled: Option<bool>,
+}
+
+#[pymethods]
+impl ServerConnection {
+    fn __repr__(&self) -> String {
+        format!(
+            "ServerConnection(node={}, address={}:{}, shard_id={:?}, driver_name={:?})",
+            self.node, self.address, self.port, self.shard_id, self.driver_name
+        )
+    }
+}
+
+/// List the client connections `node` holds; `system.clients` only lists the local ones.
+async fn node_connections(
+    session: &ScyllaSession,
+    node: &Arc<Node>,
+) -> PyResult<Vec<ServerConnection>> {
+    let mut statement = ScyllaStatement::new(CLIENTS_QUERY);
+    statement.set_load_balancing_policy(Some(SingleTargetLoadBalancingPolicy::new(
+        NodeIdentifier::Node(node.clone()),
+        None,
+    )));
+    let result = session
+        .query_unpaged(statement, &[])
+        .await
+        .map_err(query_error_to_py)?;
+    let rows = result.into_rows_result().map_err(rows_error)?;
+
+    let mut connections = Vec::new();
+    for row in rows.rows::<ClientRow>().map_err(rows_error)? {
+        let (
+            address,
+            port,
+            client_type,
+            shard_id,
+            connection_stage,
+            driver_name,
+            driver_version,
+            hostname,
+            protocol_version,
+            username,
+            ssl_enabled,
+        ) = row.map_err(deserialization_error_to_py)?;
+        connections.push(ServerConnection {
+            node: node.address.to_string(),
+            address: address.to_string(),
+            port,
+            client_type,
+            shard_id,
+            connection_stage,
+            driver_name,
+            driver_version,
+            hostname,
+            protocol_version,
+            username,
+            ssl_enabled,
+        });
+    }
+    Ok(connections)
+}
+
+/// Client connections of every connected node, narrowed with `own_only` to those opened by
+/// the Rust driver rsylla is built on.
+pub async fn list_server_connections(
+    session: &ScyllaSession,
+    own_only: bool,
+) -> PyResult<Vec<ServerConnection>> {
+    let cluster_state = session.get_cluster_state();
+    let per_node = try_join_all(
+        cluster_state
+            .get_nodes_info()
+            .iter()
+            .filter(|node| node.is_connected())
+            .map(|node| node_connections(session, node)),
+    )
+    .await?;
+    Ok(per_node
+        .into_iter()
+        .flatten()
+        .filter(|connection| !own_only || connection.driver_name.as_deref() == Some(DRIVER_NAME))
+        .collect())
+}
@@ -0,0 +1,174 @@
+use scylla::frame::response::result::{ColumnSpec, ColumnType, NativeType};
+use scylla::value::{CqlValue, Row as ScyllaRow};
+
+/// Days between 1970-01-01 and the origin of CQL `date` values, which count from 2^31.
+pub const CQL_DATE_EPOCH: i64 = 1 << 31;
+
+/// Primitive values of one column in a single contiguous buffer; null slots hold
+/// `T::default()` and are flagged in `valid`.
+pub struct Primitive<T> {
+    pub values: Vec<T>,
+    pub valid: Vec<bool>,
+}
+
+impl<T: Default> Primitive<T> {
+    fn with_capacity(capacity: usize) -> Self {
+        Primitive {
+            values: Vec::with_capacity(capacity),
+            valid: Vec::with_capacity(capacity),
+        }
+    }
+
+    fn push(&mut self, value: Option<T>) {
+        self.valid.push(value.is_some());
+        self.values.push(value.unwrap_or_default());
+    }
+}
+
+/// A decoded column, typed from the result metadata rather than from its values.
+pub enum ColumnData {
+    Boolean(Primitive<bool>),
+    TinyInt(Primitive<i8>),
+    SmallInt(Primitive<i16>),
+    Int(Primitive<i32>),
+    /// `bigint` and `counter`.
+    BigInt(Primitive<i64>),
+    Float(Primitive<f32>),
+    Double(Primitive<f64>),
+    /// Milliseconds since the Unix epoch.
+    Timestamp(Primitive<i64>),
+    /// Days since the Unix epoch.
+    Date(Primitive<i32>),
+    /// Nanoseconds since midnight.
+    Time(Primitive<i64>),
+    /// Text types, plus the types rows render as strings (uuid, inet, varint, decimal).
+    Text(Vec<Option<String>>),
+    Blob(Vec<Option<Vec<u8>>>),
+    /// Collections, tuples, UDTs and durations, left as CQL values.
+    Other(Vec<Option<CqlValue>>),
+}
+
+impl ColumnData {
+    fn for_type(typ: &ColumnType, capacity: usize) -> Self {
+        let ColumnType::Native(native) = typ else {
+            return ColumnData::Other(Vec::with_capacity(capacity));
+        };
+        match native {
+            NativeType::Boolean => ColumnData::Boolean(Primitive::with_capacity(capacity)),
+            NativeType::TinyInt => ColumnData::TinyInt(Primitive::with_capacity(capacity)),
+            NativeType::SmallInt => ColumnData::SmallInt(Primitive::with_capacity(capacity)),
+            NativeType::Int => ColumnData::Int(Primitive::with_capacity(capacity)),
+            NativeType::BigInt | NativeType::Counter => {
+                ColumnData::BigInt(Primitive::with_capacity(capacity))
+            }
+            NativeType::Float => ColumnData::Float(Primitive::with_capacity(capacity)),
+            NativeType::Double => ColumnData::Double(Primitive::with_capacity(capacity)),
+            NativeType::Timestamp => ColumnData::Timestamp(Primitive::with_capacity(capacity)),
+            NativeType::Date => ColumnData::Date(Primitive::with_capacity(capacity)),
+            NativeType::Time => ColumnData::Time(Primitive::with_capacity(capacity)),
+            NativeType::Blob => ColumnData::Blob(Vec::with_capacity(capacity)),
+            NativeType::Ascii
+            | NativeType::Text
+            | NativeType::Uuid
+            | NativeType::Timeuuid
+            | NativeType::Inet
+            | NativeType::Varint
+            | NativeType::Decimal => ColumnData::Text(Vec::with_capacity(capacity)),
+            _ => ColumnData::Other(Vec::with_capacity(capacity)),
+        }
+    }
+
+    /// Append one value; a value that doesn't match the column type is stored as null.
+    fn push(&mut self, value: Option<CqlValue>) {
+        let value = value.filter(|value| !matches!(value, CqlValue::Empty));
+        match self {
+            ColumnData::Boolean(column) => column.push(match value {
+                Some(CqlValue::Boolean(b)) => Some(b),
+                _ => None,
+            }),
+            ColumnData::TinyInt(column) => column.push(match value {
+                Some(CqlValue::TinyInt(i)) => Some(i),
+                _ => None,
+            }),
+            ColumnData::SmallInt(column) => column.push(match value {
+                Some(CqlValue::SmallInt(i)) => Some(i),
+                _ => None,
+            }),
+            ColumnData::Int(column) => column.push(match value {
+                Some(CqlValue::Int(i)) => Some(i),
+                _ => None,
+            }),
+            ColumnData::BigInt(column) => column.push(match value {
+                Some(CqlValue::BigInt(i)) => Some(i),
+                Some(CqlValue::Counter(c)) => Some(c.0),
+                _ => None,
+            }),
+            ColumnData::Float(column) => column.push(match value {
+                Some(CqlValue::Float(f)) => Some(f),
+                _ => None,
+            }),
+            ColumnData::Double(column) => column.push(match value {
+                Some(CqlValue::Double(d)) => Some(d),
+                _ => None,
+            }),
+            ColumnData::Timestamp(column) => column.push(match value {
+                Some(CqlValue::Timestamp(ts)) => Some(ts.0),
+                _ => None,
+            }),
+            ColumnData::Date(column) => column.push(match value {
+                Some(CqlValue::Date(d)) => i32::try_from(d.0 as i64 - CQL_DATE_EPOCH).ok(),
+                _ => None,
+            }),
+            ColumnData::Time(column) => column.push(match value {
+                Some(CqlValue::Time(t)) => Some(t.0),
+                _ => None,
+            }),
+            ColumnData::Text(column) => column.push(value.map(|value| match value {
+                CqlValue::Ascii(s) | CqlValue::Text(s) => s,
+                CqlValue::Uuid(u) => u.to_string(),
+                CqlValue::Timeuuid(t) => t.to_string(),
+                CqlValue::Inet(addr) => addr.to_string(),
+                CqlValue::Varint(v) => format!("{:?}", v),
+                CqlValue::Decimal(d) => format!("{:?}", d),
+                other => format!("{:?}", other),
+            })),
+            ColumnData::Blob(column) => column.push(match value {
+                Some(CqlValue::Blob(b)) => Some(b),
+                _ => None,
+            }),
+            ColumnData::Other(column) => column.push(value),
+        }
+    }
+}
+
+pub struct Column {
+    pub name: String,
+    pub data: ColumnData,
+}
+
+/// Transpose rows into one typed column per result column.
+pub fn decode_columns(specs: &[ColumnSpec<'_>], rows: Vec<ScyllaRow>) -> Vec<Column> {
+    let mut columns: Vec<Column> = specs
+        .iter()
+        .map(|spec| Column {
+            name: spec.name().to_string(),
+            data: ColumnData::for_type(spec.typ(), rows.len()),
+        })
+        .collect();
+
+    for row in rows {
+        for (column, value) in columns.iter_mut().zip(row.columns) {
+            column.data.push(value);
+        }
+    }
+    columns
+}
+
+/// Decode a single column, skipping the others.
+pub fn decode_column(spec: &ColumnSpec<'_>, index: usize, rows: Vec<ScyllaRow>) -> ColumnData {
+    let mut data = ColumnData::for_type(spec.typ(), rows.len());
+    for mut row in rows {
+        data.push(row.columns.get_mut(index).and_then(Option::take));
+    }
+    data
+}
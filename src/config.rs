@@ -0,0 +1,268 @@
+use pyo3::conversion::FromPyObjectOwned;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::path::Path;
+
+use crate::execution_profile::ExecutionProfile;
+use crate::session::SessionBuilder;
+
+/// Settings of an execution profile, accepted at the top level of a config file for the
+/// default profile and in each `[profiles.<name>]` table.
+const PROFILE_KEYS: &[&str] = &[
+    "consistency",
+    "serial_consistency",
+    "request_timeout_ms",
+    "retry_policy",
+];
+
+/// Top-level settings besides the profile ones.
+const SESSION_KEYS: &[&str] = &[
+    "nodes",
+    "keyspace",
+    "username",
+    "password",
+    "connection_timeout_ms",
+    "pool_size",
+    "connections_per_shard",
+    "compression",
+    "page_size",
+    "strict_types",
+    "emit_warnings",
+    "collect_warnings",
+    "dns_refresh_ms",
+    "service_level",
+    "profiles",
+    "environments",
+];
+
+fn config_error(message: String) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(message)
+}
+
+/// Parse `path` as TOML, or as YAML for `.yaml`/`.yml` files, which needs PyYAML.
+fn read_config<'py>(py: Python<'py>, path: &Path) -> PyResult<Bound<'py, PyDict>> {
+    let text = std::fs::read_to_string(path)?;
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .unwrap_or_default()
+        .to_lowercase();
+
+    let parsed = match extension.as_str() {
+        "toml" => py.import("tomllib")?.call_method1("loads", (text,))?,
+        "yaml" | "yml" => py
+            .import("yaml")
+            .map_err(|_| {
+                PyErr::new::<pyo3::exceptions::PyImportError, _>(
+                    "PyYAML is required for YAML config files; install it with 'pip install pyyaml'",
+                )
+            })?
+            .call_method1("safe_load", (text,))?,
+        _ => {
+            return Err(config_error(format!(
+                "Unsupported config file {}: expected a .toml, .yaml or .yml file",
+                path.display()
+            )))
+        }
+    };
+
+    if parsed.is_none() {
+        return Ok(PyDict::new(py));
+    }
+    parsed.cast_into::<PyDict>().map_err(|_| {
+        config_error(format!(
+            "{} must contain a table of settings",
+            path.display()
+        ))
+    })
+}
+
+/// The value of `key`, if set, converted to `T`; the error names the offending key.
+fn setting<'py, T: FromPyObjectOwned<'py>>(
+    table: &Bound<'py, PyDict>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    match table.get_item(key)? {
+        Some(value) if !value.is_none() => value.extract::<T>().map(Some).map_err(|err| {
+            let err: PyErr = err.into();
+            config_error(format!("Invalid config value for '{}': {}", key, err))
+        }),
+        _ => Ok(None),
+    }
+}
+
+fn table<'py>(table: &Bound<'py, PyDict>, key: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+    match table.get_item(key)? {
+        Some(value) if !value.is_none() => value
+            .cast_into::<PyDict>()
+            .map(Some)
+            .map_err(|_| config_error(format!("Config key '{}' must be a table", key))),
+        _ => Ok(None),
+    }
+}
+
+/// Reject keys outside `allowed`, so a typo doesn't silently fall back to a default.
+fn check_keys(table: &Bound<'_, PyDict>, allowed: &[&[&str]], context: &str) -> PyResult<()> {
+    for key in table.keys() {
+        let key: String = key.extract()?;
+        if !allowed.iter().any(|keys| keys.contains(&key.as_str())) {
+            return Err(config_error(format!(
+                "Unknown config key '{}'{}",
+                key, context
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// An execution profile from the profile settings of `table`, or `None` if it has none.
+fn profile_from(table: &Bound<'_, PyDict>) -> PyResult<Option<ExecutionProfile>> {
+    let mut profile = ExecutionProfile::new();
+    let mut configured = false;
+
+    if let Some(consistency) = setting::<String>(table, "consistency")? {
+        profile.with_consistency(&consistency)?;
+        configured = true;
+    }
+    if let Some(serial_consistency) = setting::<String>(table, "serial_consistency")? {
+        profile.with_serial_consistency(&serial_consistency)?;
+        configured = true;
+    }
+    if let Some(timeout_ms) = setting::<u64>(table, "request_timeout_ms")? {
+        profile.with_timeout(Some(timeout_ms))?;
+        configured = true;
+    }
+    if let Some(retry_policy) = setting::<String>(table, "retry_policy")? {
+        profile.with_retry_policy(&retry_policy)?;
+        configured = true;
+    }
+
+    Ok(configured.then_some(profile))
+}
+
+/// Top-level settings with the `[environments.<environment>]` table laid over them.
+/// Named profiles are merged by name rather than replaced wholesale.
+fn merged_settings<'py>(
+    config: &Bound<'py, PyDict>,
+    environment: Option<&str>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = config.py();
+    let settings = config.copy()?;
+    let environments = table(config, "environments")?;
+    settings.del_item("environments").ok();
+
+    let Some(environment) = environment else {
+        return Ok(settings);
+    };
+    let overrides = environments
+        .as_ref()
+        .map(|environments| table(environments, environment))
+        .transpose()?
+        .flatten()
+        .ok_or_else(|| {
+            config_error(format!(
+                "Config has no [environments.{}] table",
+                environment
+            ))
+        })?;
+    check_keys(
+        &overrides,
+        &[SESSION_KEYS, PROFILE_KEYS],
+        &format!(" in [environments.{}]", environment),
+    )?;
+
+    for (key, value) in overrides.iter() {
+        let key: String = key.extract()?;
+        if key == "profiles" {
+            let profiles = table(&settings, "profiles")?
+                .map(|profiles| profiles.copy())
+                .transpose()?
+                .unwrap_or_else(|| PyDict::new(py));
+            profiles.update(value.cast::<PyDict>()?.as_mapping())?;
+            settings.set_item("profiles", profiles)?;
+        } else {
+            settings.set_item(key, value)?;
+        }
+    }
+    Ok(settings)
+}
+
+/// Configure `builder` from the file at `path`, using the overrides of `environment`.
+pub fn apply_config(
+    py: Python,
+    builder: &mut SessionBuilder,
+    path: &Path,
+    environment: Option<&str>,
+) -> PyResult<()> {
+    let config = read_config(py, path)?;
+    check_keys(&config, &[SESSION_KEYS, PROFILE_KEYS], "")?;
+    let settings = merged_settings(&config, environment)?;
+
+    if let Some(nodes) = setting::<Vec<String>>(&settings, "nodes")? {
+        builder.known_nodes(nodes)?;
+    }
+    if let Some(keyspace) = setting::<String>(&settings, "keyspace")? {
+        builder.use_keyspace(&keyspace, false)?;
+    }
+    match (
+        setting::<String>(&settings, "username")?,
+        setting::<String>(&settings, "password")?,
+    ) {
+        (Some(username), Some(password)) => {
+            builder.user(&username, &password)?;
+        }
+        (None, None) => {}
+        _ => {
+            return Err(config_error(
+                "Config must set both 'username' and 'password' or neither".to_string(),
+            ))
+        }
+    }
+    if let Some(timeout_ms) = setting::<u64>(&settings, "connection_timeout_ms")? {
+        builder.connection_timeout(timeout_ms)?;
+    }
+    if let Some(size) = setting::<usize>(&settings, "pool_size")? {
+        builder.pool_size(size)?;
+    }
+    if let Some(connections) = setting::<usize>(&settings, "connections_per_shard")? {
+        builder.connections_per_shard(connections)?;
+    }
+    if let Some(compression) = setting::<String>(&settings, "compression")? {
+        builder.compression(Some(&compression))?;
+    }
+    if let Some(page_size) = setting::<i32>(&settings, "page_size")? {
+        builder.default_page_size(page_size)?;
+    }
+    if let Some(enabled) = setting::<bool>(&settings, "strict_types")? {
+        builder.strict_types(enabled)?;
+    }
+    if let Some(enabled) = setting::<bool>(&settings, "emit_warnings")? {
+        builder.emit_warnings(enabled)?;
+    }
+    if let Some(enabled) = setting::<bool>(&settings, "collect_warnings")? {
+        builder.collect_warnings(enabled)?;
+    }
+    if let Some(interval_ms) = setting::<u64>(&settings, "dns_refresh_ms")? {
+        builder.dns_refresh(Some(interval_ms))?;
+    }
+    if let Some(service_level) = setting::<String>(&settings, "service_level")? {
+        builder.service_level(&service_level)?;
+    }
+    if let Some(default_profile) = profile_from(&settings)? {
+        builder.default_execution_profile(&default_profile)?;
+    }
+
+    if let Some(profiles) = table(&settings, "profiles")? {
+        for (name, _) in profiles.iter() {
+            let name: String = name.extract()?;
+            let context = format!(" in [profiles.{}]", name);
+            let Some(profile_table) = table(&profiles, &name)? else {
+                continue;
+            };
+            check_keys(&profile_table, &[PROFILE_KEYS], &context)?;
+            let profile = profile_from(&profile_table)?.unwrap_or_default();
+            builder.execution_profile(name, &profile)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use pyo3_async_runtimes::tokio::get_runtime;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::cluster::KnownNode;
+use std::collections::{BTreeSet, HashSet};
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::Duration;
+
+use crate::policies::DEFAULT_CQL_PORT;
+
+/// `hostname`, with the default CQL port appended unless it already names one. A bare
+/// IPv6 address is full of colons, so it only carries a port as `[addr]:port`.
+pub fn with_default_port(hostname: &str) -> String {
+    let bare = hostname
+        .strip_prefix('[')
+        .and_then(|address| address.strip_suffix(']'))
+        .unwrap_or(hostname);
+    if let Ok(ip) = bare.parse::<IpAddr>() {
+        return SocketAddr::new(ip, DEFAULT_CQL_PORT).to_string();
+    }
+    if hostname.parse::<SocketAddr>().is_ok() {
+        return hostname.to_string();
+    }
+
+    let has_port = hostname
+        .rsplit_once(':')
+        .is_some_and(|(host, port)| !host.contains(':') && port.parse::<u16>().is_ok());
+    if has_port {
+        hostname.to_string()
+    } else {
+        format!("{}:{}", hostname, DEFAULT_CQL_PORT)
+    }
+}
+
+/// Contact points given as hostnames and the addresses they resolved to last. The driver
+/// resolves them once at startup, and again only after losing every node it knows, so
+/// behind a DNS name whose addresses rotate (a Kubernetes headless service) it would
+/// otherwise never notice new pods it wasn't told about through the cluster itself.
+#[derive(Default)]
+pub struct ContactPoints {
+    /// Every contact point as given to the builder, addresses included.
+    configured: Vec<String>,
+    hostnames: Vec<String>,
+    resolved: Mutex<BTreeSet<SocketAddr>>,
+}
+
+impl ContactPoints {
+    pub fn new(nodes: &[KnownNode]) -> Self {
+        let hostnames = nodes
+            .iter()
+            .filter_map(|node| match node {
+                KnownNode::Hostname(hostname) => Some(with_default_port(hostname)),
+                _ => None,
+            })
+            .collect();
+        let configured = nodes
+            .iter()
+            .map(|node| match node {
+                KnownNode::Hostname(hostname) => with_default_port(hostname),
+                KnownNode::Address(address) => address.to_string(),
+                _ => format!("{:?}", node),
+            })
+            .collect();
+        ContactPoints {
+            configured,
+            hostnames,
+            resolved: Mutex::default(),
+        }
+    }
+
+    pub fn configured(&self) -> Vec<String> {
+        self.configured.clone()
+    }
+
+    /// Addresses the hostnames resolved to on the last successful lookup.
+    pub fn resolved(&self) -> Vec<String> {
+        let resolved = self.resolved.lock().unwrap();
+        resolved.iter().map(SocketAddr::to_string).collect()
+    }
+
+    /// Re-resolve the hostnames every `interval` for as long as `session` is alive.
+    pub fn watch(self: &Arc<Self>, session: &Arc<ScyllaSession>, interval: Duration) {
+        if self.hostnames.is_empty() {
+            return;
+        }
+        get_runtime().spawn(refresh(self.clone(), Arc::downgrade(session), interval));
+    }
+
+    /// Current addresses of every hostname; hostnames that fail to resolve are skipped.
+    async fn resolve(&self) -> BTreeSet<SocketAddr> {
+        let mut addresses = BTreeSet::new();
+        for hostname in &self.hostnames {
+            if let Ok(resolved) = tokio::net::lookup_host(hostname.as_str()).await {
+                addresses.extend(resolved);
+            }
+        }
+        addresses
+    }
+}
+
+/// Resolve the contact points periodically, and refresh the cluster metadata when they
+/// point at an address the driver doesn't know, so it connects to the new node.
+async fn refresh(
+    contact_points: Arc<ContactPoints>,
+    session: Weak<ScyllaSession>,
+    interval: Duration,
+) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        ticker.tick().await;
+        let resolved = contact_points.resolve().await;
+        let Some(session) = session.upgrade() else {
+            return;
+        };
+        if resolved.is_empty() {
+            // DNS is unavailable; keep the last answer rather than forgetting every node
+            continue;
+        }
+
+        let known: HashSet<SocketAddr> = session
+            .get_cluster_state()
+            .get_nodes_info()
+            .iter()
+            .map(|node| SocketAddr::new(node.address.ip(), node.address.port()))
+            .collect();
+        let appeared = {
+            let previous = contact_points.resolved.lock().unwrap();
+            resolved
+                .iter()
+                .any(|address| !previous.contains(address) && !known.contains(address))
+        };
+        *contact_points.resolved.lock().unwrap() = resolved;
+
+        if appeared {
+            // Failures are retried on the next tick and by the driver's own refreshes
+            let _ = session.refresh_metadata().await;
+        }
+    }
+}
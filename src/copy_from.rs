@@ -0,0 +1,462 @@
+use arrow_array::cast::AsArray;
+use arrow_array::types::{
+    Date32Type, Float32Type, Float64Type, Int16Type, Int32Type, Int64Type, Int8Type,
+    TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType,
+    TimestampSecondType, UInt16Type, UInt32Type, UInt8Type,
+};
+use arrow_array::{Array, RecordBatch};
+use arrow_schema::{DataType, TimeUnit};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use parquet::arrow::arrow_reader::{ParquetRecordBatchReader, ParquetRecordBatchReaderBuilder};
+use pyo3::prelude::*;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::frame::response::result::{ColumnType, NativeType};
+use scylla::statement::batch::{Batch as ScyllaBatch, BatchType};
+use scylla::statement::prepared::PreparedStatement as ScyllaPreparedStatement;
+use scylla::value::{CqlDate, CqlTimestamp, CqlValue};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use crate::columnar::CQL_DATE_EPOCH;
+use crate::error::{partition_key_error_to_py, prepare_error_to_py, query_error_to_py};
+use crate::rate_limit::RequestLimiter;
+
+/// Rows read from the file per round of batches.
+const CHUNK_ROWS: usize = 10_000;
+/// Parsed chunks buffered ahead of the writers.
+const READ_AHEAD_CHUNKS: usize = 2;
+
+type CqlRow = Vec<Option<CqlValue>>;
+
+#[derive(Clone, Copy)]
+pub enum Format {
+    Csv { delimiter: u8 },
+    Parquet,
+}
+
+impl Format {
+    pub fn parse(format: &str) -> PyResult<Self> {
+        match format.to_lowercase().as_str() {
+            "csv" => Ok(Format::Csv { delimiter: b',' }),
+            "tsv" => Ok(Format::Csv { delimiter: b'\t' }),
+            "parquet" => Ok(Format::Parquet),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Unsupported format: {} (expected 'csv', 'tsv' or 'parquet')",
+                format
+            ))),
+        }
+    }
+}
+
+pub struct CopyOptions {
+    pub table: String,
+    pub path: PathBuf,
+    pub format: Format,
+    pub concurrency: usize,
+    pub batch_size: usize,
+    pub on_progress: Option<Py<PyAny>>,
+}
+
+fn load_error(message: String) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(message)
+}
+
+/// A value as read from the file, before conversion to its column's CQL type.
+enum Field {
+    Null,
+    Text(String),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Bytes(Vec<u8>),
+    TimestampMs(i64),
+    /// Days since the Unix epoch.
+    Date(i64),
+}
+
+fn parse_text(text: &str, typ: &NativeType) -> Result<CqlValue, String> {
+    fn number<T: std::str::FromStr>(text: &str) -> Result<T, String> {
+        text.trim()
+            .parse()
+            .map_err(|_| format!("invalid number {:?}", text))
+    }
+
+    Ok(match typ {
+        NativeType::Ascii => CqlValue::Ascii(text.to_string()),
+        NativeType::Text => CqlValue::Text(text.to_string()),
+        NativeType::Boolean => match text.trim().to_lowercase().as_str() {
+            "true" | "1" | "yes" => CqlValue::Boolean(true),
+            "false" | "0" | "no" => CqlValue::Boolean(false),
+            _ => return Err(format!("invalid boolean {:?}", text)),
+        },
+        NativeType::TinyInt => CqlValue::TinyInt(number(text)?),
+        NativeType::SmallInt => CqlValue::SmallInt(number(text)?),
+        NativeType::Int => CqlValue::Int(number(text)?),
+        NativeType::BigInt => CqlValue::BigInt(number(text)?),
+        NativeType::Float => CqlValue::Float(number(text)?),
+        NativeType::Double => CqlValue::Double(number(text)?),
+        NativeType::Uuid => CqlValue::Uuid(
+            uuid::Uuid::parse_str(text.trim()).map_err(|_| format!("invalid uuid {:?}", text))?,
+        ),
+        NativeType::Timeuuid => CqlValue::Timeuuid(
+            uuid::Uuid::parse_str(text.trim())
+                .map_err(|_| format!("invalid timeuuid {:?}", text))?
+                .into(),
+        ),
+        NativeType::Inet => CqlValue::Inet(
+            text.trim()
+                .parse()
+                .map_err(|_| format!("invalid address {:?}", text))?,
+        ),
+        NativeType::Timestamp => {
+            let text = text.trim();
+            let ms = match text.parse::<i64>() {
+                Ok(ms) => ms,
+                Err(_) => chrono::DateTime::parse_from_rfc3339(text)
+                    .map_err(|_| format!("invalid timestamp {:?}", text))?
+                    .timestamp_millis(),
+            };
+            CqlValue::Timestamp(CqlTimestamp(ms))
+        }
+        NativeType::Date => {
+            let date = chrono::NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d")
+                .map_err(|_| format!("invalid date {:?}", text))?;
+            let days = (date - chrono::NaiveDate::default()).num_days();
+            cql_date(days)?
+        }
+        NativeType::Blob => {
+            let hex = text.trim();
+            let hex = hex.strip_prefix("0x").unwrap_or(hex);
+            if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+                return Err(format!("invalid hex blob {:?}", text));
+            }
+            let bytes = (0..hex.len())
+                .step_by(2)
+                .map(|i| u8::from_str_radix(&hex[i..i + 2], 16))
+                .collect::<Result<Vec<u8>, _>>()
+                .map_err(|_| format!("invalid hex blob {:?}", text))?;
+            CqlValue::Blob(bytes)
+        }
+        other => return Err(format!("loading {:?} columns is not supported", other)),
+    })
+}
+
+fn cql_date(days: i64) -> Result<CqlValue, String> {
+    u32::try_from(days + CQL_DATE_EPOCH)
+        .map(|date| CqlValue::Date(CqlDate(date)))
+        .map_err(|_| format!("date out of range: {} days", days))
+}
+
+/// Convert a file value to the column's type; text is parsed, numbers may be narrowed
+/// as long as they fit.
+fn to_cql(field: Field, typ: &ColumnType) -> Result<Option<CqlValue>, String> {
+    let ColumnType::Native(native) = typ else {
+        return Err("only native column types can be loaded".to_string());
+    };
+    fn fit<T: TryFrom<i64>>(value: i64) -> Result<T, String> {
+        T::try_from(value).map_err(|_| format!("{} is out of range", value))
+    }
+
+    let value = match (field, native) {
+        (Field::Null, _) => return Ok(None),
+        (Field::Text(text), _) if text.is_empty() => return Ok(None),
+        (Field::Text(text), native) => parse_text(&text, native)?,
+        (Field::Int(i), NativeType::TinyInt) => CqlValue::TinyInt(fit(i)?),
+        (Field::Int(i), NativeType::SmallInt) => CqlValue::SmallInt(fit(i)?),
+        (Field::Int(i), NativeType::Int) => CqlValue::Int(fit(i)?),
+        (Field::Int(i), NativeType::BigInt) => CqlValue::BigInt(i),
+        (Field::Int(i), NativeType::Float) => CqlValue::Float(i as f32),
+        (Field::Int(i), NativeType::Double) => CqlValue::Double(i as f64),
+        (Field::Int(i), NativeType::Timestamp) => CqlValue::Timestamp(CqlTimestamp(i)),
+        (Field::Float(f), NativeType::Float) => CqlValue::Float(f as f32),
+        (Field::Float(f), NativeType::Double) => CqlValue::Double(f),
+        (Field::Bool(b), NativeType::Boolean) => CqlValue::Boolean(b),
+        (Field::Bytes(b), NativeType::Blob) => CqlValue::Blob(b),
+        (Field::TimestampMs(ms), NativeType::Timestamp) => CqlValue::Timestamp(CqlTimestamp(ms)),
+        (Field::Date(days), NativeType::Date) => cql_date(days)?,
+        (_, native) => return Err(format!("value doesn't fit a {:?} column", native)),
+    };
+    Ok(Some(value))
+}
+
+/// The file being loaded, positioned after its header.
+enum Source {
+    Csv(csv::Reader<File>),
+    Parquet(ParquetRecordBatchReader),
+}
+
+impl Source {
+    /// Open `path` and return it with its column names: the CSV header row or the
+    /// Parquet schema fields.
+    fn open(path: &Path, format: &Format) -> Result<(Self, Vec<String>), String> {
+        let file =
+            File::open(path).map_err(|e| format!("Cannot open {}: {}", path.display(), e))?;
+        match format {
+            Format::Csv { delimiter } => {
+                let mut reader = csv::ReaderBuilder::new()
+                    .delimiter(*delimiter)
+                    .from_reader(file);
+                let names = reader
+                    .headers()
+                    .map_err(|e| format!("Cannot read CSV header: {}", e))?
+                    .iter()
+                    .map(|name| name.trim().to_string())
+                    .collect();
+                Ok((Source::Csv(reader), names))
+            }
+            Format::Parquet => {
+                let builder = ParquetRecordBatchReaderBuilder::try_new(file)
+                    .map_err(|e| format!("Cannot read Parquet file: {}", e))?;
+                let names = builder
+                    .schema()
+                    .fields()
+                    .iter()
+                    .map(|field| field.name().clone())
+                    .collect();
+                let reader = builder
+                    .with_batch_size(CHUNK_ROWS)
+                    .build()
+                    .map_err(|e| format!("Cannot read Parquet file: {}", e))?;
+                Ok((Source::Parquet(reader), names))
+            }
+        }
+    }
+
+    /// The next rows of raw fields, or `None` at the end of the file.
+    fn next_chunk(&mut self) -> Result<Option<Vec<Vec<Field>>>, String> {
+        match self {
+            Source::Csv(reader) => {
+                let mut rows = Vec::new();
+                for record in reader.records().take(CHUNK_ROWS) {
+                    let record = record.map_err(|e| format!("Invalid CSV: {}", e))?;
+                    rows.push(
+                        record
+                            .iter()
+                            .map(|text| Field::Text(text.to_string()))
+                            .collect(),
+                    );
+                }
+                Ok((!rows.is_empty()).then_some(rows))
+            }
+            Source::Parquet(reader) => match reader.next() {
+                Some(batch) => {
+                    let batch = batch.map_err(|e| format!("Invalid Parquet data: {}", e))?;
+                    batch_fields(&batch).map(Some)
+                }
+                None => Ok(None),
+            },
+        }
+    }
+}
+
+fn array_field(array: &dyn Array, row: usize) -> Result<Field, String> {
+    if array.is_null(row) {
+        return Ok(Field::Null);
+    }
+    Ok(match array.data_type() {
+        DataType::Boolean => Field::Bool(array.as_boolean().value(row)),
+        DataType::Int8 => Field::Int(array.as_primitive::<Int8Type>().value(row) as i64),
+        DataType::Int16 => Field::Int(array.as_primitive::<Int16Type>().value(row) as i64),
+        DataType::Int32 => Field::Int(array.as_primitive::<Int32Type>().value(row) as i64),
+        DataType::Int64 => Field::Int(array.as_primitive::<Int64Type>().value(row)),
+        DataType::UInt8 => Field::Int(array.as_primitive::<UInt8Type>().value(row) as i64),
+        DataType::UInt16 => Field::Int(array.as_primitive::<UInt16Type>().value(row) as i64),
+        DataType::UInt32 => Field::Int(array.as_primitive::<UInt32Type>().value(row) as i64),
+        DataType::Float32 => Field::Float(array.as_primitive::<Float32Type>().value(row) as f64),
+        DataType::Float64 => Field::Float(array.as_primitive::<Float64Type>().value(row)),
+        DataType::Utf8 => Field::Text(array.as_string::<i32>().value(row).to_string()),
+        DataType::LargeUtf8 => Field::Text(array.as_string::<i64>().value(row).to_string()),
+        DataType::Binary => Field::Bytes(array.as_binary::<i32>().value(row).to_vec()),
+        DataType::LargeBinary => Field::Bytes(array.as_binary::<i64>().value(row).to_vec()),
+        DataType::Date32 => Field::Date(array.as_primitive::<Date32Type>().value(row) as i64),
+        DataType::Timestamp(unit, _) => Field::TimestampMs(match unit {
+            TimeUnit::Second => array.as_primitive::<TimestampSecondType>().value(row) * 1000,
+            TimeUnit::Millisecond => array.as_primitive::<TimestampMillisecondType>().value(row),
+            TimeUnit::Microsecond => {
+                array.as_primitive::<TimestampMicrosecondType>().value(row) / 1000
+            }
+            TimeUnit::Nanosecond => {
+                array.as_primitive::<TimestampNanosecondType>().value(row) / 1_000_000
+            }
+        }),
+        other => return Err(format!("Unsupported Parquet column type: {}", other)),
+    })
+}
+
+fn batch_fields(batch: &RecordBatch) -> Result<Vec<Vec<Field>>, String> {
+    (0..batch.num_rows())
+        .map(|row| {
+            batch
+                .columns()
+                .iter()
+                .map(|array| array_field(array.as_ref(), row))
+                .collect()
+        })
+        .collect()
+}
+
+fn convert_row(
+    fields: Vec<Field>,
+    line: usize,
+    names: &[String],
+    types: &[ColumnType<'static>],
+) -> Result<CqlRow, String> {
+    if fields.len() != types.len() {
+        return Err(format!(
+            "Row {}: expected {} values, got {}",
+            line,
+            types.len(),
+            fields.len()
+        ));
+    }
+    fields
+        .into_iter()
+        .zip(types)
+        .zip(names)
+        .map(|((field, typ), name)| {
+            to_cql(field, typ).map_err(|e| format!("Row {}, column '{}': {}", line, name, e))
+        })
+        .collect()
+}
+
+/// Read the file on a blocking thread, converting each chunk to the insert's column types.
+/// Stops after the first error or once the receiver is gone.
+fn read_rows(
+    mut source: Source,
+    names: Vec<String>,
+    types: Vec<ColumnType<'static>>,
+    chunks: mpsc::Sender<Result<Vec<CqlRow>, String>>,
+) {
+    let mut line = 0;
+    loop {
+        let chunk = match source.next_chunk() {
+            Ok(Some(rows)) => rows
+                .into_iter()
+                .map(|fields| {
+                    line += 1;
+                    convert_row(fields, line, &names, &types)
+                })
+                .collect(),
+            Ok(None) => return,
+            Err(err) => Err(err),
+        };
+        let failed = chunk.is_err();
+        if chunks.blocking_send(chunk).is_err() || failed {
+            return;
+        }
+    }
+}
+
+/// Group rows by partition and split each group into unlogged batches of at most
+/// `batch_size` rows, so every batch is applied by a single replica set.
+fn partition_batches(
+    prepared: &ScyllaPreparedStatement,
+    rows: Vec<CqlRow>,
+    batch_size: usize,
+) -> PyResult<Vec<Vec<CqlRow>>> {
+    let mut order: Vec<Vec<u8>> = Vec::new();
+    let mut partitions: HashMap<Vec<u8>, Vec<CqlRow>> = HashMap::new();
+    for row in rows {
+        let key = prepared
+            .compute_partition_key(&row)
+            .map_err(partition_key_error_to_py)?
+            .to_vec();
+        partitions
+            .entry(key.clone())
+            .or_insert_with(|| {
+                order.push(key);
+                Vec::new()
+            })
+            .push(row);
+    }
+
+    let mut batches = Vec::new();
+    for key in order {
+        let mut rows = partitions.remove(&key).unwrap_or_default();
+        while !rows.is_empty() {
+            let rest = rows.split_off(rows.len().min(batch_size));
+            batches.push(std::mem::replace(&mut rows, rest));
+        }
+    }
+    Ok(batches)
+}
+
+async fn write_batch(
+    session: &ScyllaSession,
+    limiter: &RequestLimiter,
+    prepared: &ScyllaPreparedStatement,
+    rows: Vec<CqlRow>,
+) -> PyResult<usize> {
+    let count = rows.len();
+    let mut batch = ScyllaBatch::new(BatchType::Unlogged);
+    for _ in 0..count {
+        batch.append_statement(prepared.clone());
+    }
+    let _slot = limiter.acquire().await;
+    session
+        .batch(&batch, rows)
+        .await
+        .map_err(query_error_to_py)?;
+    Ok(count)
+}
+
+/// Load every row of the file into the table. Returns the number of rows written.
+pub async fn copy_from(
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    options: CopyOptions,
+) -> PyResult<u64> {
+    let path = options.path.clone();
+    let (source, names) = tokio::task::spawn_blocking(move || Source::open(&path, &options.format))
+        .await
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?
+        .map_err(load_error)?;
+
+    for name in &names {
+        crate::cql::validate_identifier(name)?;
+    }
+    let prepared = session
+        .prepare(format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            options.table,
+            names.join(", "),
+            vec!["?"; names.len()].join(", ")
+        ))
+        .await
+        .map_err(prepare_error_to_py)?;
+    let types: Vec<ColumnType<'static>> = prepared
+        .get_variable_col_specs()
+        .iter()
+        .map(|spec| spec.typ().clone())
+        .collect();
+
+    let (sender, mut chunks) = mpsc::channel(READ_AHEAD_CHUNKS);
+    let reader = tokio::task::spawn_blocking(move || read_rows(source, names, types, sender));
+
+    let outcome = async {
+        let mut loaded = 0u64;
+        while let Some(chunk) = chunks.recv().await {
+            let batches =
+                partition_batches(&prepared, chunk.map_err(load_error)?, options.batch_size)?;
+            loaded += stream::iter(batches)
+                .map(|rows| write_batch(&session, &limiter, &prepared, rows))
+                .buffer_unordered(options.concurrency)
+                .try_fold(0u64, |total, count| async move { Ok(total + count as u64) })
+                .await?;
+
+            if let Some(on_progress) = &options.on_progress {
+                Python::attach(|py| on_progress.call1(py, (loaded,)))?;
+            }
+        }
+        Ok(loaded)
+    }
+    .await;
+
+    // Dropping the receiver stops the reader at its next chunk.
+    drop(chunks);
+    let _ = reader.await;
+    outcome
+}
@@ -0,0 +1,237 @@
+use arrow_array::RecordBatch;
+use parquet::arrow::ArrowWriter;
+use pyo3::prelude::*;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::response::{PagingState, PagingStateResponse};
+use scylla::value::{CqlValue, Row as ScyllaRow};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use crate::arrow::record_batch;
+use crate::columnar::{decode_columns, CQL_DATE_EPOCH};
+use crate::copy_from::Format;
+use crate::error::query_error_to_py;
+use crate::json::cql_value_to_json;
+use crate::query::Statement;
+use crate::rate_limit::RequestLimiter;
+use crate::types::SerializableValue;
+
+/// Converted pages buffered ahead of the file writer.
+const WRITE_AHEAD_PAGES: usize = 2;
+
+pub struct ExportOptions {
+    pub path: PathBuf,
+    pub format: Format,
+    pub on_progress: Option<Py<PyAny>>,
+}
+
+fn export_error(message: String) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyIOError, _>(message)
+}
+
+fn rows_error(e: impl std::fmt::Display) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!("Row deserialization error: {}", e))
+}
+
+/// CSV text of a value, in the forms `copy_from` reads back. Nested values are written
+/// as JSON.
+fn csv_field(value: Option<CqlValue>) -> String {
+    match value {
+        None | Some(CqlValue::Empty) => String::new(),
+        Some(CqlValue::Ascii(s) | CqlValue::Text(s)) => s,
+        Some(CqlValue::Timestamp(ts)) => chrono::DateTime::from_timestamp_millis(ts.0)
+            .map(|dt| dt.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+            .unwrap_or_else(|| ts.0.to_string()),
+        Some(CqlValue::Date(d)) => chrono::NaiveDate::default()
+            .checked_add_signed(chrono::TimeDelta::days(d.0 as i64 - CQL_DATE_EPOCH))
+            .map(|date| date.to_string())
+            .unwrap_or_else(|| d.0.to_string()),
+        Some(CqlValue::Time(t)) => chrono::NaiveTime::from_num_seconds_from_midnight_opt(
+            (t.0 / 1_000_000_000) as u32,
+            (t.0 % 1_000_000_000) as u32,
+        )
+        .map(|time| time.to_string())
+        .unwrap_or_else(|| t.0.to_string()),
+        Some(value) => match cql_value_to_json(&value) {
+            serde_json::Value::String(s) => s,
+            other => other.to_string(),
+        },
+    }
+}
+
+/// One page, converted for the writer.
+enum Chunk {
+    Csv {
+        names: Vec<String>,
+        records: Vec<Vec<String>>,
+    },
+    Parquet(RecordBatch),
+}
+
+impl Chunk {
+    fn len(&self) -> usize {
+        match self {
+            Chunk::Csv { records, .. } => records.len(),
+            Chunk::Parquet(batch) => batch.num_rows(),
+        }
+    }
+}
+
+/// The output file, opened once the first page shows the result's columns.
+enum Sink {
+    Csv(csv::Writer<File>),
+    Parquet(ArrowWriter<File>),
+}
+
+impl Sink {
+    fn open(path: &Path, format: Format, chunk: &Chunk) -> Result<Self, String> {
+        let file =
+            File::create(path).map_err(|e| format!("Cannot create {}: {}", path.display(), e))?;
+        match (format, chunk) {
+            (Format::Csv { delimiter }, Chunk::Csv { names, .. }) => {
+                let mut writer = csv::WriterBuilder::new()
+                    .delimiter(delimiter)
+                    .from_writer(file);
+                writer.write_record(names).map_err(|e| e.to_string())?;
+                Ok(Sink::Csv(writer))
+            }
+            (_, Chunk::Parquet(batch)) => ArrowWriter::try_new(file, batch.schema(), None)
+                .map(Sink::Parquet)
+                .map_err(|e| e.to_string()),
+            _ => unreachable!("chunks are converted for the export format"),
+        }
+    }
+
+    fn write(&mut self, chunk: Chunk) -> Result<(), String> {
+        match (self, chunk) {
+            (Sink::Csv(writer), Chunk::Csv { records, .. }) => records
+                .iter()
+                .try_for_each(|record| writer.write_record(record))
+                .map_err(|e| e.to_string()),
+            (Sink::Parquet(writer), Chunk::Parquet(batch)) => {
+                writer.write(&batch).map_err(|e| e.to_string())
+            }
+            _ => unreachable!("chunks are converted for the export format"),
+        }
+    }
+
+    fn finish(self) -> Result<(), String> {
+        match self {
+            Sink::Csv(mut writer) => writer.flush().map_err(|e| e.to_string()),
+            Sink::Parquet(writer) => writer.close().map(|_| ()).map_err(|e| e.to_string()),
+        }
+    }
+}
+
+/// Write chunks to the file on a blocking thread, reporting progress after each one.
+fn write_chunks(options: ExportOptions, mut chunks: mpsc::Receiver<Chunk>) -> PyResult<u64> {
+    let mut sink: Option<Sink> = None;
+    let mut written = 0u64;
+    while let Some(chunk) = chunks.blocking_recv() {
+        let rows = chunk.len() as u64;
+        let sink = match &mut sink {
+            Some(sink) => sink,
+            None => sink
+                .insert(Sink::open(&options.path, options.format, &chunk).map_err(export_error)?),
+        };
+        sink.write(chunk).map_err(export_error)?;
+        written += rows;
+
+        if let Some(on_progress) = &options.on_progress {
+            Python::attach(|py| on_progress.call1(py, (written,)))?;
+        }
+    }
+
+    match sink {
+        Some(sink) => sink.finish().map_err(export_error)?,
+        None => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Statement doesn't return rows",
+            ))
+        }
+    }
+    Ok(written)
+}
+
+/// Convert one page for `format`; `None` when the statement doesn't return rows.
+fn convert_page(
+    result: scylla::response::query_result::QueryResult,
+    format: Format,
+) -> PyResult<Option<Chunk>> {
+    let Ok(rows_result) = result.into_rows_result() else {
+        return Ok(None);
+    };
+    let rows = rows_result
+        .rows::<ScyllaRow>()
+        .map_err(rows_error)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(rows_error)?;
+
+    Ok(Some(match format {
+        Format::Csv { .. } => Chunk::Csv {
+            names: rows_result
+                .column_specs()
+                .iter()
+                .map(|spec| spec.name().to_string())
+                .collect(),
+            records: rows
+                .into_iter()
+                .map(|row| row.columns.into_iter().map(csv_field).collect())
+                .collect(),
+        },
+        Format::Parquet => {
+            let num_rows = rows.len();
+            let columns = decode_columns(rows_result.column_specs().as_slice(), rows);
+            Chunk::Parquet(record_batch(columns, num_rows)?)
+        }
+    }))
+}
+
+/// Page through `statement` within the session's rate limit and write every row to the
+/// file. Returns the number of rows written.
+pub async fn copy_to(
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    statement: Statement,
+    values: HashMap<String, SerializableValue>,
+    options: ExportOptions,
+) -> PyResult<u64> {
+    let format = options.format;
+
+    let (sender, chunks) = mpsc::channel(WRITE_AHEAD_PAGES);
+    let writer = tokio::task::spawn_blocking(move || write_chunks(options, chunks));
+
+    let fetched: PyResult<()> = async {
+        let mut paging_state = PagingState::start();
+        loop {
+            let slot = limiter.acquire().await;
+            let (result, paging_state_response) = statement
+                .execute_single_page(&session, &values, paging_state)
+                .await
+                .map_err(query_error_to_py)?;
+            drop(slot);
+            let Some(chunk) = convert_page(result, format)? else {
+                return Ok(());
+            };
+            // The writer only hangs up after failing; its error is reported below.
+            if sender.send(chunk).await.is_err() {
+                return Ok(());
+            }
+            match paging_state_response {
+                PagingStateResponse::HasMorePages { state } => paging_state = state,
+                _ => return Ok(()),
+            }
+        }
+    }
+    .await;
+
+    drop(sender);
+    let written = writer
+        .await
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?;
+    fetched?;
+    written
+}
@@ -0,0 +1,213 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Ensure a keyspace, table or column name is a plain CQL identifier before it is
+/// interpolated into generated statements.
+pub fn validate_identifier(name: &str) -> PyResult<&str> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if valid {
+        Ok(name)
+    } else {
+        Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid CQL identifier: {:?}",
+            name
+        )))
+    }
+}
+
+/// Validate a table name, optionally qualified with a keyspace (`ks.table`).
+pub fn validate_table_name(name: &str) -> PyResult<&str> {
+    match name.split_once('.') {
+        Some((keyspace, table)) => {
+            validate_identifier(keyspace)?;
+            validate_identifier(table)?;
+        }
+        None => {
+            validate_identifier(name)?;
+        }
+    }
+    Ok(name)
+}
+
+/// Byte offset of the first occurrence of `keyword` as a whole word outside string
+/// literals and quoted identifiers, ignoring case.
+fn find_keyword(cql: &str, keyword: &str) -> Option<usize> {
+    let bytes = cql.as_bytes();
+    let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+    let mut quote = None;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        match quote {
+            Some(q) if b == q => quote = None,
+            Some(_) => {}
+            None if b == b'\'' || b == b'"' => quote = Some(b),
+            None => {
+                let end = i + keyword.len();
+                if end <= bytes.len()
+                    && bytes[i..end].eq_ignore_ascii_case(keyword.as_bytes())
+                    && (i == 0 || !is_word(bytes[i - 1]))
+                    && (end == bytes.len() || !is_word(bytes[end]))
+                {
+                    return Some(i);
+                }
+            }
+        }
+        i += 1;
+    }
+    None
+}
+
+/// SELECT `cql` with Scylla's `BYPASS CACHE` clause, which reads past the row cache and
+/// leaves it as it was, e.g. for scans touching data nothing else reads.
+pub fn with_bypass_cache(cql: &str) -> PyResult<String> {
+    let cql = cql.trim_end().trim_end_matches(';').trim_end();
+    let is_select = cql
+        .split_whitespace()
+        .next()
+        .is_some_and(|verb| verb.eq_ignore_ascii_case("SELECT"));
+    if !is_select {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "BYPASS CACHE only applies to SELECT statements",
+        ));
+    }
+    Ok(format!("{} BYPASS CACHE", cql))
+}
+
+/// `cql` with Scylla's `USING TIMEOUT` clause set to `timeout_ms`, merged into an
+/// existing `USING` clause or placed where the statement's grammar expects it.
+pub fn with_using_timeout(cql: &str, timeout_ms: u64) -> PyResult<String> {
+    let cql = cql.trim_end().trim_end_matches(';').trim_end();
+    let timeout = format!("TIMEOUT {}ms", timeout_ms);
+
+    let verb = cql
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_ascii_uppercase();
+    // UPDATE and DELETE take USING before SET/WHERE; SELECT and INSERT end with it
+    let before = match verb.as_str() {
+        "SELECT" | "INSERT" => None,
+        "UPDATE" => Some("SET"),
+        "DELETE" => Some("WHERE"),
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "USING TIMEOUT only applies to SELECT, INSERT, UPDATE and DELETE statements, \
+                 not {:?}",
+                verb
+            )))
+        }
+    };
+
+    if verb != "SELECT" {
+        if let Some(using) = find_keyword(cql, "USING") {
+            let (head, tail) = cql.split_at(using + "USING".len());
+            return Ok(format!("{} {} AND{}", head, timeout, tail));
+        }
+    }
+    match before.map(|keyword| find_keyword(cql, keyword)) {
+        Some(Some(at)) => {
+            let (head, tail) = cql.split_at(at);
+            Ok(format!("{} USING {} {}", head.trim_end(), timeout, tail))
+        }
+        Some(None) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Could not find where to place USING TIMEOUT in {:?}",
+            cql
+        ))),
+        None => Ok(format!("{} USING {}", cql, timeout)),
+    }
+}
+
+/// `value` as a CQL string literal.
+fn string_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+/// The `replication` map of a `CREATE KEYSPACE` statement from `replication`, holding the
+/// strategy `class` and its options: `replication_factor` for `SimpleStrategy`, and
+/// replication factors per datacenter for `NetworkTopologyStrategy`.
+fn replication_map(replication: &Bound<'_, PyDict>) -> PyResult<String> {
+    let invalid = |message: String| PyErr::new::<pyo3::exceptions::PyValueError, _>(message);
+
+    let class: String = replication
+        .get_item("class")?
+        .ok_or_else(|| invalid("Replication needs a 'class' strategy".to_string()))?
+        .extract()?;
+    let strategy = class.rsplit('.').next().unwrap_or_default();
+
+    let mut options = Vec::new();
+    for (key, value) in replication.iter() {
+        let key: String = key.extract()?;
+        if key == "class" {
+            continue;
+        }
+        let value = match value.extract::<i64>() {
+            Ok(factor) if factor < 0 => {
+                return Err(invalid(format!(
+                    "Replication factor for '{}' must not be negative",
+                    key
+                )))
+            }
+            Ok(factor) => factor.to_string(),
+            Err(_) => value.extract::<String>().map_err(|_| {
+                invalid(format!(
+                    "Replication option '{}' must be an int or a str",
+                    key
+                ))
+            })?,
+        };
+        options.push((key, value));
+    }
+
+    match strategy {
+        "SimpleStrategy" if options.len() != 1 || options[0].0 != "replication_factor" => {
+            return Err(invalid(
+                "SimpleStrategy takes a 'replication_factor' and nothing else".to_string(),
+            ));
+        }
+        "NetworkTopologyStrategy" if options.is_empty() => {
+            return Err(invalid(
+                "NetworkTopologyStrategy needs a replication factor per datacenter".to_string(),
+            ));
+        }
+        _ => {}
+    }
+
+    let entries: Vec<String> = std::iter::once(("class".to_string(), class))
+        .chain(options)
+        .map(|(key, value)| format!("{}: {}", string_literal(&key), string_literal(&value)))
+        .collect();
+    Ok(format!("{{{}}}", entries.join(", ")))
+}
+
+/// `CREATE KEYSPACE` for `name`, with its replication map built from `replication`.
+pub fn create_keyspace_statement(
+    name: &str,
+    replication: &Bound<'_, PyDict>,
+    durable_writes: bool,
+    if_not_exists: bool,
+) -> PyResult<String> {
+    validate_identifier(name)?;
+    Ok(format!(
+        "CREATE KEYSPACE {}{} WITH replication = {} AND durable_writes = {}",
+        if if_not_exists { "IF NOT EXISTS " } else { "" },
+        name,
+        replication_map(replication)?,
+        durable_writes
+    ))
+}
+
+/// `DROP KEYSPACE` for `name`.
+pub fn drop_keyspace_statement(name: &str, if_exists: bool) -> PyResult<String> {
+    validate_identifier(name)?;
+    Ok(format!(
+        "DROP KEYSPACE {}{}",
+        if if_exists { "IF EXISTS " } else { "" },
+        name
+    ))
+}
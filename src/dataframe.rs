@@ -0,0 +1,204 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList};
+
+use crate::columnar::{Column, ColumnData, Primitive};
+use crate::types::cql_value_to_py;
+
+/// Plain numeric types whose buffers numpy can adopt as-is.
+pub trait NumpyDtype: Copy {
+    const DTYPE: &'static str;
+}
+
+macro_rules! numpy_dtype {
+    ($($ty:ty => $dtype:literal),* $(,)?) => {
+        $(impl NumpyDtype for $ty {
+            const DTYPE: &'static str = $dtype;
+        })*
+    };
+}
+
+numpy_dtype!(
+    bool => "bool",
+    i8 => "int8",
+    i16 => "int16",
+    i32 => "int32",
+    i64 => "int64",
+    f32 => "float32",
+    f64 => "float64",
+);
+
+fn import<'py>(py: Python<'py>, module: &str, package: &str) -> PyResult<Bound<'py, PyModule>> {
+    py.import(module).map_err(|_| {
+        PyErr::new::<pyo3::exceptions::PyImportError, _>(format!(
+            "This conversion requires the '{}' package",
+            package
+        ))
+    })
+}
+
+/// Copy a buffer into a writable numpy array in a single pass.
+pub fn numpy_array<'py, T: NumpyDtype>(
+    py: Python<'py>,
+    values: &[T],
+) -> PyResult<Bound<'py, PyAny>> {
+    // SAFETY: every `NumpyDtype` is a primitive without padding or invalid byte patterns
+    // (bool is always 0 or 1), so its buffer can be read as bytes.
+    let bytes = unsafe {
+        std::slice::from_raw_parts(values.as_ptr().cast::<u8>(), std::mem::size_of_val(values))
+    };
+    import(py, "numpy", "numpy")?
+        .call_method1("frombuffer", (PyByteArray::new(py, bytes), T::DTYPE))
+}
+
+/// A numpy array, or a `numpy.ma.MaskedArray` when the column has nulls.
+fn numpy_masked<'py, T: NumpyDtype>(
+    py: Python<'py>,
+    column: &Primitive<T>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let values = numpy_array(py, &column.values)?;
+    if column.valid.iter().all(|valid| *valid) {
+        return Ok(values);
+    }
+
+    let mask: Vec<bool> = column.valid.iter().map(|valid| !valid).collect();
+    import(py, "numpy", "numpy")?
+        .getattr("ma")?
+        .call_method1("masked_array", (values, numpy_array(py, &mask)?))
+}
+
+/// Integers and booleans as numpy arrays, masked when they hold nulls; floats use NaN.
+pub fn numpy_column<'py>(
+    py: Python<'py>,
+    name: &str,
+    data: ColumnData,
+) -> PyResult<Bound<'py, PyAny>> {
+    match data {
+        ColumnData::Boolean(column) => numpy_masked(py, &column),
+        ColumnData::TinyInt(column) => numpy_masked(py, &column),
+        ColumnData::SmallInt(column) => numpy_masked(py, &column),
+        ColumnData::Int(column) => numpy_masked(py, &column),
+        ColumnData::BigInt(column) => numpy_masked(py, &column),
+        ColumnData::Float(column) => float_column(py, &column, f32::NAN),
+        ColumnData::Double(column) => float_column(py, &column, f64::NAN),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+            "Column '{}' is not an integer, float or boolean column",
+            name
+        ))),
+    }
+}
+
+/// Integers and booleans: a plain numpy array, or a pandas masked array when there are nulls.
+fn masked_column<'py, T: NumpyDtype>(
+    py: Python<'py>,
+    pandas: &Bound<'py, PyModule>,
+    array_type: &str,
+    column: &Primitive<T>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let values = numpy_array(py, &column.values)?;
+    if column.valid.iter().all(|valid| *valid) {
+        return Ok(values);
+    }
+
+    let mask: Vec<bool> = column.valid.iter().map(|valid| !valid).collect();
+    pandas
+        .getattr("arrays")?
+        .getattr(array_type)?
+        .call1((values, numpy_array(py, &mask)?))
+}
+
+/// Floats keep numpy dtypes, with nulls as NaN.
+fn float_column<'py, T: NumpyDtype>(
+    py: Python<'py>,
+    column: &Primitive<T>,
+    nan: T,
+) -> PyResult<Bound<'py, PyAny>> {
+    let values: Vec<T> = column
+        .values
+        .iter()
+        .zip(&column.valid)
+        .map(|(value, valid)| if *valid { *value } else { nan })
+        .collect();
+    numpy_array(py, &values)
+}
+
+/// Int64 ticks viewed as a numpy datetime or timedelta dtype, with nulls as NaT.
+fn temporal_column<'py>(
+    py: Python<'py>,
+    ticks: impl Iterator<Item = i64>,
+    valid: &[bool],
+    dtype: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    let values: Vec<i64> = ticks
+        .zip(valid)
+        .map(|(tick, valid)| if *valid { tick } else { i64::MIN })
+        .collect();
+    numpy_array(py, &values)?.call_method1("view", (dtype,))
+}
+
+fn pandas_column<'py>(
+    py: Python<'py>,
+    pandas: &Bound<'py, PyModule>,
+    data: ColumnData,
+) -> PyResult<Bound<'py, PyAny>> {
+    match data {
+        ColumnData::Boolean(column) => masked_column(py, pandas, "BooleanArray", &column),
+        ColumnData::TinyInt(column) => masked_column(py, pandas, "IntegerArray", &column),
+        ColumnData::SmallInt(column) => masked_column(py, pandas, "IntegerArray", &column),
+        ColumnData::Int(column) => masked_column(py, pandas, "IntegerArray", &column),
+        ColumnData::BigInt(column) => masked_column(py, pandas, "IntegerArray", &column),
+        ColumnData::Float(column) => float_column(py, &column, f32::NAN),
+        ColumnData::Double(column) => float_column(py, &column, f64::NAN),
+        ColumnData::Timestamp(column) => {
+            let values = temporal_column(
+                py,
+                column.values.iter().copied(),
+                &column.valid,
+                "datetime64[ms]",
+            )?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("utc", true)?;
+            pandas.call_method("to_datetime", (values,), Some(&kwargs))
+        }
+        ColumnData::Date(column) => temporal_column(
+            py,
+            column.values.iter().map(|days| *days as i64),
+            &column.valid,
+            "datetime64[D]",
+        ),
+        ColumnData::Time(column) => temporal_column(
+            py,
+            column.values.iter().copied(),
+            &column.valid,
+            "timedelta64[ns]",
+        ),
+        ColumnData::Text(values) => Ok(PyList::new(py, values)?.into_any()),
+        ColumnData::Blob(values) => Ok(PyList::new(
+            py,
+            values
+                .into_iter()
+                .map(|value| value.map(|bytes| PyBytes::new(py, &bytes))),
+        )?
+        .into_any()),
+        ColumnData::Other(values) => {
+            let list = PyList::empty(py);
+            for value in values {
+                match value {
+                    Some(value) => list.append(cql_value_to_py(py, &value)?)?,
+                    None => list.append(py.None())?,
+                }
+            }
+            Ok(list.into_any())
+        }
+    }
+}
+
+/// Build a DataFrame from decoded columns, one numpy-backed column per numeric column.
+pub fn to_pandas<'py>(py: Python<'py>, columns: Vec<Column>) -> PyResult<Bound<'py, PyAny>> {
+    let pandas = import(py, "pandas", "pandas")?;
+
+    let data = PyDict::new(py);
+    for column in columns {
+        data.set_item(column.name, pandas_column(py, &pandas, column.data)?)?;
+    }
+    pandas.call_method1("DataFrame", (data,))
+}
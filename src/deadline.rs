@@ -0,0 +1,66 @@
+use pyo3::prelude::*;
+use std::future::Future;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::time::Instant;
+
+use crate::error::DeadlineExceededError;
+
+/// Absolute time by which a call must complete, bounding everything it waits for: the
+/// rate limiter and fairness class, every attempt, retries and speculative executions.
+#[derive(Clone, Copy)]
+pub struct Deadline {
+    at: Instant,
+    budget: Duration,
+}
+
+impl Deadline {
+    /// Read a deadline given as a Unix timestamp in seconds, as `time.time()` returns.
+    /// A deadline already in the past leaves a budget of zero.
+    pub fn from_timestamp(timestamp: Option<f64>) -> PyResult<Option<Self>> {
+        let Some(timestamp) = timestamp else {
+            return Ok(None);
+        };
+        let invalid = || {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid deadline: {}",
+                timestamp
+            ))
+        };
+        if !timestamp.is_finite() {
+            return Err(invalid());
+        }
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs_f64();
+        // Too far in the future to represent as a Duration or an Instant
+        let budget =
+            Duration::try_from_secs_f64((timestamp - now).max(0.0)).map_err(|_| invalid())?;
+        let at = Instant::now().checked_add(budget).ok_or_else(invalid)?;
+        Ok(Some(Deadline { at, budget }))
+    }
+
+    fn exceeded(&self) -> PyErr {
+        PyErr::new::<DeadlineExceededError, _>(format!(
+            "Deadline exceeded: the request didn't complete within its {} ms budget",
+            self.budget.as_millis()
+        ))
+    }
+}
+
+/// Run `request` to completion or until `deadline`, dropping it then. A request whose
+/// deadline has already passed isn't started.
+pub async fn within<T>(
+    deadline: Option<Deadline>,
+    request: impl Future<Output = PyResult<T>>,
+) -> PyResult<T> {
+    let Some(deadline) = deadline else {
+        return request.await;
+    };
+    if Instant::now() >= deadline.at {
+        return Err(deadline.exceeded());
+    }
+    tokio::time::timeout_at(deadline.at, request)
+        .await
+        .unwrap_or_else(|_| Err(deadline.exceeded()))
+}
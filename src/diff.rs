@@ -0,0 +1,182 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+use scylla::value::CqlValue;
+use std::collections::HashMap;
+
+use crate::result::{QueryResult, Row};
+use crate::types::cql_value_to_py;
+
+fn value_to_py(py: Python<'_>, value: Option<&CqlValue>) -> PyResult<Py<PyAny>> {
+    match value {
+        Some(value) => cql_value_to_py(py, value),
+        None => Ok(py.None()),
+    }
+}
+
+/// `{column: (old, new)}` for every column whose value differs. A column present on
+/// only one side is reported against `None`.
+fn changed_columns<'py>(
+    py: Python<'py>,
+    names: &[String],
+    row_a: &[Option<CqlValue>],
+    row_b: &[Option<CqlValue>],
+) -> PyResult<Bound<'py, PyDict>> {
+    let changes = PyDict::new(py);
+    for (i, name) in names.iter().enumerate() {
+        let a = row_a.get(i).and_then(Option::as_ref);
+        let b = row_b.get(i).and_then(Option::as_ref);
+        if a != b {
+            let pair = PyTuple::new(py, [value_to_py(py, a)?, value_to_py(py, b)?])?;
+            changes.set_item(name, pair)?;
+        }
+    }
+    Ok(changes)
+}
+
+/// Compare two rows column by column.
+///
+/// Columns are named `col_{i}` unless `columns` gives the names.
+#[pyfunction]
+#[pyo3(signature = (row_a, row_b, columns=None))]
+pub fn diff_rows<'py>(
+    py: Python<'py>,
+    row_a: &Row,
+    row_b: &Row,
+    columns: Option<Vec<String>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let width = row_a.cql_values().len().max(row_b.cql_values().len());
+    let names = match columns {
+        Some(columns) if columns.len() != width => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Expected {} column names, got {}",
+                width,
+                columns.len()
+            )))
+        }
+        Some(columns) => columns,
+        None => (0..width).map(|i| format!("col_{}", i)).collect(),
+    };
+
+    changed_columns(py, &names, row_a.cql_values(), row_b.cql_values())
+}
+
+/// Rows of a result keyed by the values of `key_columns`, with the remaining values
+/// reordered to follow `names`.
+#[allow(clippy::type_complexity)]
+fn keyed_rows(
+    result: &QueryResult,
+    key_columns: &[String],
+    names: &[String],
+) -> PyResult<Vec<(Vec<Option<CqlValue>>, Vec<Option<CqlValue>>)>> {
+    let columns = result.column_names();
+    let position = |name: &String| -> PyResult<Option<usize>> {
+        Ok(columns.iter().position(|column| column == name))
+    };
+
+    let mut key_positions = Vec::with_capacity(key_columns.len());
+    for key in key_columns {
+        key_positions.push(position(key)?.ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Key column '{}' is missing from the result",
+                key
+            ))
+        })?);
+    }
+    let value_positions = names.iter().map(position).collect::<PyResult<Vec<_>>>()?;
+
+    Ok(result
+        .scylla_rows()?
+        .into_iter()
+        .map(|row| {
+            let key = key_positions
+                .iter()
+                .map(|&i| row.columns[i].clone())
+                .collect();
+            let values = value_positions
+                .iter()
+                .map(|position| position.and_then(|i| row.columns[i].clone()))
+                .collect();
+            (key, values)
+        })
+        .collect())
+}
+
+/// Match the rows of two results by `key_columns` and report what changed.
+///
+/// Returns one entry per differing key: `{"key": {...}, "status": "changed" | "added" |
+/// "removed", "changes": {column: (old, new)}}`, in the order keys appear in `res_a`
+/// followed by keys only present in `res_b`.
+#[pyfunction]
+pub fn diff_results<'py>(
+    py: Python<'py>,
+    res_a: &QueryResult,
+    res_b: &QueryResult,
+    key_columns: Vec<String>,
+) -> PyResult<Bound<'py, PyList>> {
+    if key_columns.is_empty() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+            "At least one key column is required",
+        ));
+    }
+
+    // Union of non-key columns, in the order they first appear
+    let mut names: Vec<String> = Vec::new();
+    for name in res_a.column_names().into_iter().chain(res_b.column_names()) {
+        if !key_columns.contains(&name) && !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let rows_a = keyed_rows(res_a, &key_columns, &names)?;
+    let rows_b = keyed_rows(res_b, &key_columns, &names)?;
+
+    let mut index_b: Vec<Option<usize>> = (0..rows_b.len()).map(Some).collect();
+    // CqlValue isn't hashable, so keys are matched on their debug representation
+    let mut lookup_b: HashMap<String, usize> = HashMap::with_capacity(rows_b.len());
+    for (i, (key, _)) in rows_b.iter().enumerate() {
+        lookup_b.insert(format!("{:?}", key), i);
+    }
+
+    let key_dict = |key: &[Option<CqlValue>]| -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        for (name, value) in key_columns.iter().zip(key) {
+            dict.set_item(name, value_to_py(py, value.as_ref())?)?;
+        }
+        Ok(dict)
+    };
+    let entry = |key: &[Option<CqlValue>],
+                 status: &str,
+                 changes: Bound<'py, PyDict>|
+     -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("key", key_dict(key)?)?;
+        dict.set_item("status", status)?;
+        dict.set_item("changes", changes)?;
+        Ok(dict)
+    };
+
+    let empty = vec![None; names.len()];
+    let diffs = PyList::empty(py);
+    for (key, values_a) in &rows_a {
+        match lookup_b.get(&format!("{:?}", key)) {
+            Some(&i) => {
+                index_b[i] = None;
+                let changes = changed_columns(py, &names, values_a, &rows_b[i].1)?;
+                if !changes.is_empty() {
+                    diffs.append(entry(key, "changed", changes)?)?;
+                }
+            }
+            None => {
+                let changes = changed_columns(py, &names, values_a, &empty)?;
+                diffs.append(entry(key, "removed", changes)?)?;
+            }
+        }
+    }
+    for i in index_b.into_iter().flatten() {
+        let (key, values_b) = &rows_b[i];
+        let changes = changed_columns(py, &names, &empty, values_b)?;
+        diffs.append(entry(key, "added", changes)?)?;
+    }
+
+    Ok(diffs)
+}
@@ -1,12 +1,81 @@
 use pyo3::create_exception;
 use pyo3::prelude::*;
+use scylla::errors::{BadQuery, DbError, ExecutionError, OperationType, RequestAttemptError};
+use scylla::serialize::row::{
+    BuiltinSerializationError, BuiltinSerializationErrorKind, BuiltinTypeCheckError,
+    BuiltinTypeCheckErrorKind,
+};
+
+use crate::types::BindValueError;
 
 create_exception!(rsylla, ScyllaError, pyo3::exceptions::PyException);
+create_exception!(rsylla, ScyllaWarning, pyo3::exceptions::PyUserWarning);
+create_exception!(rsylla, RateLimitedError, ScyllaError);
+create_exception!(rsylla, BindError, ScyllaError);
+create_exception!(rsylla, ValueOutOfRange, BindError);
+create_exception!(rsylla, ForkedProcessError, ScyllaError);
+create_exception!(rsylla, DeadlineExceededError, ScyllaError);
 
 // Helper functions to convert scylla errors to PyErr
 // We can't implement From directly due to orphan rules
 pub fn query_error_to_py(err: scylla::errors::ExecutionError) -> PyErr {
-    PyErr::new::<ScyllaError, _>(format!("Query error: {}", err))
+    execution_error_to_py(err, |_| None)
+}
+
+/// Like `query_error_to_py`, but lets the caller attach the partition key of the
+/// statement when Scylla rejects it with a per-partition rate limit.
+pub fn execution_error_to_py(
+    err: scylla::errors::ExecutionError,
+    partition_key: impl FnOnce(Python<'_>) -> Option<Py<PyAny>>,
+) -> PyErr {
+    let ExecutionError::LastAttemptError(RequestAttemptError::DbError(
+        DbError::RateLimitReached {
+            op_type,
+            rejected_by_coordinator,
+        },
+        _,
+    )) = &err
+    else {
+        if let ExecutionError::BadQuery(BadQuery::SerializationError(err)) = err {
+            return bind_error_to_py(err);
+        }
+        return PyErr::new::<ScyllaError, _>(format!("Query error: {}", err));
+    };
+
+    let operation = match op_type {
+        OperationType::Read => "read",
+        OperationType::Write => "write",
+        _ => "other",
+    };
+    let rejected_by_coordinator = *rejected_by_coordinator;
+    let py_err = PyErr::new::<RateLimitedError, _>(format!("Query error: {}", err));
+
+    Python::attach(|py| {
+        let value = py_err.value(py);
+        let attrs = [
+            ("partition_key", partition_key(py)),
+            (
+                "operation",
+                Some(operation.into_pyobject(py)?.into_any().unbind()),
+            ),
+            (
+                "rejected_by_coordinator",
+                Some(
+                    rejected_by_coordinator
+                        .into_pyobject(py)?
+                        .to_owned()
+                        .into_any()
+                        .unbind(),
+                ),
+            ),
+        ];
+        for (name, attr) in attrs {
+            value.setattr(name, attr)?;
+        }
+        Ok::<_, PyErr>(())
+    })
+    .err()
+    .unwrap_or(py_err)
 }
 
 pub fn session_error_to_py(err: scylla::errors::NewSessionError) -> PyErr {
@@ -17,10 +86,26 @@ pub fn prepare_error_to_py(err: scylla::errors::PrepareError) -> PyErr {
     PyErr::new::<ScyllaError, _>(format!("Prepare error: {}", err))
 }
 
+pub fn prepare_timeout_to_py(timeout_ms: u64) -> PyErr {
+    PyErr::new::<ScyllaError, _>(format!("Prepare error: timed out after {} ms", timeout_ms))
+}
+
 pub fn use_keyspace_error_to_py(err: scylla::errors::UseKeyspaceError) -> PyErr {
     PyErr::new::<ScyllaError, _>(format!("Use keyspace error: {}", err))
 }
 
+pub fn metadata_error_to_py(err: scylla::errors::MetadataError) -> PyErr {
+    PyErr::new::<ScyllaError, _>(format!("Metadata error: {}", err))
+}
+
+pub fn token_error_to_py(err: scylla::errors::ClusterStateTokenError) -> PyErr {
+    PyErr::new::<ScyllaError, _>(format!("Token error: {}", err))
+}
+
+pub fn tracing_error_to_py(err: scylla::errors::TracingError) -> PyErr {
+    PyErr::new::<ScyllaError, _>(format!("Tracing error: {}", err))
+}
+
 pub fn schema_agreement_error_to_py(err: scylla::errors::SchemaAgreementError) -> PyErr {
     PyErr::new::<ScyllaError, _>(format!("Schema agreement error: {}", err))
 }
@@ -30,7 +115,60 @@ pub fn serialization_error_to_py(err: scylla::serialize::SerializationError) ->
     PyErr::new::<ScyllaError, _>(format!("Serialization error: {}", err))
 }
 
-#[allow(dead_code)]
+/// A value that can't be written to the column of the bind marker `name`. Integers too
+/// large for their column raise `ValueOutOfRange`, a subclass of `BindError`.
+pub fn bind_value_error_to_py(name: &str, err: &BindValueError) -> PyErr {
+    match err {
+        BindValueError::OutOfRange { .. } => PyErr::new::<ValueOutOfRange, _>(format!(
+            "Value out of range: parameter '{}' {}",
+            name, err
+        )),
+        BindValueError::TypeMismatch { .. } => {
+            PyErr::new::<BindError, _>(format!("Bind error: parameter '{}' {}", name, err))
+        }
+    }
+}
+
+/// Values that don't fit a prepared statement's bind markers, caught before execution.
+/// Missing, unexpected and mistyped named values are reported by marker name.
+pub fn bind_error_to_py(err: scylla::serialize::SerializationError) -> PyErr {
+    if let Some(BuiltinSerializationErrorKind::ColumnSerializationFailed { name, err }) = err
+        .downcast_ref::<BuiltinSerializationError>()
+        .map(|err| &err.kind)
+    {
+        return match err.downcast_ref::<BindValueError>() {
+            Some(err) => bind_value_error_to_py(name, err),
+            None => {
+                PyErr::new::<BindError, _>(format!("Bind error: parameter '{}': {}", name, err))
+            }
+        };
+    }
+
+    let message = match err
+        .downcast_ref::<BuiltinTypeCheckError>()
+        .map(|err| &err.kind)
+    {
+        Some(BuiltinTypeCheckErrorKind::ValueMissingForColumn { name }) => {
+            format!("Bind error: missing value for bind marker '{}'", name)
+        }
+        Some(BuiltinTypeCheckErrorKind::NoColumnWithName { name }) => {
+            format!(
+                "Bind error: no bind marker named '{}' in the statement",
+                name
+            )
+        }
+        _ => format!("Bind error: {}", err),
+    };
+    PyErr::new::<BindError, _>(message)
+}
+
+pub fn partition_key_error_to_py(err: scylla::statement::prepared::PartitionKeyError) -> PyErr {
+    match err {
+        scylla::statement::prepared::PartitionKeyError::Serialization(err) => bind_error_to_py(err),
+        err => PyErr::new::<ScyllaError, _>(format!("Partition key error: {}", err)),
+    }
+}
+
 pub fn deserialization_error_to_py(err: scylla::deserialize::DeserializationError) -> PyErr {
     PyErr::new::<ScyllaError, _>(format!("Deserialization error: {}", err))
 }
@@ -0,0 +1,71 @@
+use pyo3::prelude::*;
+
+/// Loop methods the awaitables returned by rsylla rely on: results are delivered to a
+/// future created on the loop from a Tokio worker thread.
+const REQUIRED_LOOP_METHODS: &[&str] = &["create_future", "call_soon_threadsafe"];
+
+/// Top-level module of the class of `obj`, e.g. `asyncio` or `uvloop`.
+fn backend_of(obj: &Bound<'_, PyAny>) -> PyResult<String> {
+    let module: String = obj.get_type().getattr("__module__")?.extract()?;
+    Ok(module.split('.').next().unwrap_or_default().to_string())
+}
+
+/// Select the event loop implementation for loops created from now on: `"asyncio"`
+/// restores the standard loop, `"uvloop"` installs uvloop's policy, and `"auto"` uses
+/// uvloop when it is installed. Returns the backend selected.
+#[pyfunction]
+#[pyo3(signature = (backend="auto"))]
+pub fn use_event_loop(py: Python, backend: &str) -> PyResult<&'static str> {
+    let asyncio = py.import("asyncio")?;
+    let selected = match backend.to_lowercase().as_str() {
+        "asyncio" => None,
+        "uvloop" => Some(py.import("uvloop").map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyImportError, _>(
+                "uvloop is not installed; install it with 'pip install uvloop'",
+            )
+        })?),
+        "auto" => py.import("uvloop").ok(),
+        _ => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid event loop backend: {}. Must be 'auto', 'asyncio', or 'uvloop'",
+                backend
+            )))
+        }
+    };
+
+    match selected {
+        Some(uvloop) => {
+            let policy = uvloop.getattr("EventLoopPolicy")?.call0()?;
+            asyncio.call_method1("set_event_loop_policy", (policy,))?;
+            Ok("uvloop")
+        }
+        None => {
+            asyncio.call_method1("set_event_loop_policy", (py.None(),))?;
+            Ok("asyncio")
+        }
+    }
+}
+
+/// Backend of the running event loop, or of the loops the current policy creates when no
+/// loop is running. Raises `RuntimeError` when the running loop lacks what rsylla's
+/// awaitables need.
+#[pyfunction]
+pub fn event_loop_backend(py: Python) -> PyResult<String> {
+    let asyncio = py.import("asyncio")?;
+    let Ok(event_loop) = asyncio.call_method0("get_running_loop") else {
+        let policy = asyncio.call_method0("get_event_loop_policy")?;
+        return backend_of(&policy);
+    };
+
+    let backend = backend_of(&event_loop)?;
+    for method in REQUIRED_LOOP_METHODS {
+        if !event_loop.hasattr(*method)? {
+            return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Event loop {} does not support {}(), which rsylla requires",
+                event_loop.get_type().name()?,
+                method
+            )));
+        }
+    }
+    Ok(backend)
+}
@@ -0,0 +1,274 @@
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::get_runtime;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::cluster::metadata::Keyspace;
+use scylla::cluster::ClusterState;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::runtime::future_into_py;
+
+/// Bound on undelivered events; the watcher waits for the consumer beyond this.
+const EVENT_QUEUE_SIZE: usize = 1024;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ClusterEvent {
+    /// `node_added`, `node_removed`, `node_up`, `node_down` or `schema_change`.
+    #[pyo3(get)]
+    pub kind: String,
+    #[pyo3(get)]
+    pub address: Option<String>,
+    #[pyo3(get)]
+    pub host_id: Option<String>,
+    /// For schema changes: `created`, `updated` or `dropped`.
+    #[pyo3(get)]
+    pub change: Option<String>,
+    /// For schema changes: `keyspace`, `table`, `view` or `type`.
+    #[pyo3(get)]
+    pub target: Option<String>,
+    #[pyo3(get)]
+    pub keyspace: Option<String>,
+    /// Name of the changed table, view or type; `None` for keyspace changes.
+    #[pyo3(get)]
+    pub name: Option<String>,
+}
+
+#[pymethods]
+impl ClusterEvent {
+    pub fn __repr__(&self) -> String {
+        match self.kind.as_str() {
+            "schema_change" => format!(
+                "ClusterEvent(kind=schema_change, change={:?}, target={:?}, keyspace={:?}, name={:?})",
+                self.change, self.target, self.keyspace, self.name
+            ),
+            kind => format!(
+                "ClusterEvent(kind={}, address={:?}, host_id={:?})",
+                kind, self.address, self.host_id
+            ),
+        }
+    }
+}
+
+impl ClusterEvent {
+    fn node(kind: &str, host_id: &str, address: &str) -> Self {
+        ClusterEvent {
+            kind: kind.to_string(),
+            address: Some(address.to_string()),
+            host_id: Some(host_id.to_string()),
+            change: None,
+            target: None,
+            keyspace: None,
+            name: None,
+        }
+    }
+
+    fn schema(change: &str, target: &str, keyspace: &str, name: Option<&str>) -> Self {
+        ClusterEvent {
+            kind: "schema_change".to_string(),
+            address: None,
+            host_id: None,
+            change: Some(change.to_string()),
+            target: Some(target.to_string()),
+            keyspace: Some(keyspace.to_string()),
+            name: name.map(str::to_string),
+        }
+    }
+}
+
+/// What the watcher last saw: nodes by host id with their address and connectivity.
+struct Snapshot {
+    cluster_state: Arc<ClusterState>,
+    nodes: HashMap<String, (String, bool)>,
+}
+
+impl Snapshot {
+    fn take(session: &ScyllaSession) -> Self {
+        let cluster_state = session.get_cluster_state();
+        let nodes = cluster_state
+            .get_nodes_info()
+            .iter()
+            .map(|node| {
+                (
+                    node.host_id.to_string(),
+                    (node.address.to_string(), node.is_connected()),
+                )
+            })
+            .collect();
+        Snapshot {
+            cluster_state,
+            nodes,
+        }
+    }
+
+    fn keyspaces(&self) -> HashMap<&str, &Keyspace> {
+        self.cluster_state.keyspaces_iter().collect()
+    }
+}
+
+fn diff_nodes(old: &Snapshot, new: &Snapshot, events: &mut Vec<ClusterEvent>) {
+    for (host_id, (address, up)) in &new.nodes {
+        match old.nodes.get(host_id) {
+            None => events.push(ClusterEvent::node("node_added", host_id, address)),
+            Some((_, was_up)) if was_up != up => {
+                let kind = if *up { "node_up" } else { "node_down" };
+                events.push(ClusterEvent::node(kind, host_id, address));
+            }
+            Some(_) => {}
+        }
+    }
+    for (host_id, (address, _)) in &old.nodes {
+        if !new.nodes.contains_key(host_id) {
+            events.push(ClusterEvent::node("node_removed", host_id, address));
+        }
+    }
+}
+
+/// Created/updated/dropped events for the entries of two same-kind schema maps.
+fn diff_entries<T: PartialEq>(
+    target: &str,
+    keyspace: &str,
+    old: &HashMap<String, T>,
+    new: &HashMap<String, T>,
+    events: &mut Vec<ClusterEvent>,
+) {
+    for (name, entry) in new {
+        let change = match old.get(name) {
+            None => "created",
+            Some(previous) if previous != entry => "updated",
+            Some(_) => continue,
+        };
+        events.push(ClusterEvent::schema(change, target, keyspace, Some(name)));
+    }
+    for name in old.keys() {
+        if !new.contains_key(name) {
+            events.push(ClusterEvent::schema(
+                "dropped",
+                target,
+                keyspace,
+                Some(name),
+            ));
+        }
+    }
+}
+
+fn diff_schema(old: &Snapshot, new: &Snapshot, events: &mut Vec<ClusterEvent>) {
+    // The driver swaps in a new cluster state on every metadata refresh
+    if Arc::ptr_eq(&old.cluster_state, &new.cluster_state) {
+        return;
+    }
+
+    let old_keyspaces = old.keyspaces();
+    let new_keyspaces = new.keyspaces();
+    for (name, keyspace) in &new_keyspaces {
+        let Some(previous) = old_keyspaces.get(name) else {
+            events.push(ClusterEvent::schema("created", "keyspace", name, None));
+            continue;
+        };
+        if previous == keyspace {
+            continue;
+        }
+
+        if previous.strategy != keyspace.strategy
+            || previous.durable_writes != keyspace.durable_writes
+        {
+            events.push(ClusterEvent::schema("updated", "keyspace", name, None));
+        }
+        diff_entries("table", name, &previous.tables, &keyspace.tables, events);
+        diff_entries("view", name, &previous.views, &keyspace.views, events);
+        diff_entries(
+            "type",
+            name,
+            &previous.user_defined_types,
+            &keyspace.user_defined_types,
+            events,
+        );
+    }
+    for name in old_keyspaces.keys() {
+        if !new_keyspaces.contains_key(name) {
+            events.push(ClusterEvent::schema("dropped", "keyspace", name, None));
+        }
+    }
+}
+
+/// Poll the driver's view of the cluster (kept current from control connection events)
+/// and forward what changed.
+async fn watch(
+    session: Arc<ScyllaSession>,
+    poll_interval: Duration,
+    schema_only: bool,
+    events: mpsc::Sender<ClusterEvent>,
+) {
+    let mut previous = Snapshot::take(&session);
+    let mut interval = tokio::time::interval(poll_interval);
+    interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        interval.tick().await;
+        let current = Snapshot::take(&session);
+
+        let mut changes = Vec::new();
+        if !schema_only {
+            diff_nodes(&previous, &current, &mut changes);
+        }
+        diff_schema(&previous, &current, &mut changes);
+        for event in changes {
+            if events.send(event).await.is_err() {
+                return;
+            }
+        }
+
+        previous = current;
+    }
+}
+
+/// Async iterator of topology, node status and schema change events.
+#[pyclass]
+pub struct ClusterEvents {
+    events: Arc<Mutex<mpsc::Receiver<ClusterEvent>>>,
+    task: JoinHandle<()>,
+}
+
+impl ClusterEvents {
+    pub fn start(session: Arc<ScyllaSession>, poll_interval: Duration, schema_only: bool) -> Self {
+        let (sender, receiver) = mpsc::channel(EVENT_QUEUE_SIZE);
+        let task = get_runtime().spawn(watch(session, poll_interval, schema_only, sender));
+
+        ClusterEvents {
+            events: Arc::new(Mutex::new(receiver)),
+            task,
+        }
+    }
+}
+
+impl Drop for ClusterEvents {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[pymethods]
+impl ClusterEvents {
+    pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let events = self.events.clone();
+
+        future_into_py(py, async move {
+            match events.lock().await.recv().await {
+                Some(event) => Ok(event),
+                None => Err(PyErr::new::<pyo3::exceptions::PyStopAsyncIteration, _>(())),
+            }
+        })
+    }
+
+    /// Stop watching; iteration ends once already queued events are consumed.
+    pub fn close(&self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,143 @@
+use futures::stream::{FuturesUnordered, StreamExt};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::statement::prepared::PreparedStatement as ScyllaPreparedStatement;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::fairness::FairnessClasses;
+use crate::query::{check_named_values, check_value_types};
+use crate::rate_limit::RequestLimiter;
+use crate::types::{py_dict_to_serialized_values, SerializableValue};
+
+/// Outcome of `Session.execute_many`: how many parameter sets were applied, and the
+/// index and error of every one that wasn't.
+#[derive(Default)]
+pub struct ManySummary {
+    succeeded: u64,
+    errors: Vec<(u64, String)>,
+}
+
+impl ManySummary {
+    pub fn to_py(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let errors = self
+            .errors
+            .iter()
+            .map(|(index, error)| {
+                let dict = PyDict::new(py);
+                dict.set_item("index", index)?;
+                dict.set_item("error", error)?;
+                Ok(dict)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("succeeded", self.succeeded)?;
+        dict.set_item("failed", self.errors.len())?;
+        dict.set_item("errors", errors)?;
+        Ok(dict.into())
+    }
+}
+
+/// The statement every parameter set is executed with, and the session limits it runs
+/// under.
+pub struct ManyTarget {
+    pub prepared: Arc<ScyllaPreparedStatement>,
+    pub fairness_classes: Arc<FairnessClasses>,
+    pub fairness_class: Option<String>,
+    pub limiter: Arc<RequestLimiter>,
+    pub strict_types: bool,
+}
+
+/// Serialize up to `count` parameter sets from the iterator under a single GIL hold.
+/// Sets that can't be serialized come back as errors; an exception raised by the
+/// iterator itself aborts the call.
+fn next_params(
+    params: &Py<PyIterator>,
+    target: &ManyTarget,
+    count: usize,
+) -> PyResult<Vec<Result<HashMap<String, SerializableValue>, String>>> {
+    Python::attach(|py| {
+        let mut iter = params.bind(py).clone();
+        let mut sets = Vec::with_capacity(count);
+        for item in iter.by_ref().take(count) {
+            let item = item?;
+            let values = if item.is_none() {
+                py_dict_to_serialized_values(None)
+            } else {
+                item.cast::<PyDict>()
+                    .map_err(PyErr::from)
+                    .and_then(|dict| py_dict_to_serialized_values(Some(dict)))
+            }
+            .and_then(|values| {
+                check_named_values(&target.prepared, &values)?;
+                if target.strict_types {
+                    check_value_types(&target.prepared, &values)?;
+                }
+                Ok(values)
+            });
+            sets.push(values.map_err(|e| e.to_string()));
+        }
+        Ok(sets)
+    })
+}
+
+async fn execute_one(
+    session: &ScyllaSession,
+    target: &ManyTarget,
+    index: u64,
+    values: HashMap<String, SerializableValue>,
+) -> (u64, Result<(), String>) {
+    let _permit = target
+        .fairness_classes
+        .acquire(target.fairness_class.as_deref())
+        .await;
+    let _slot = target.limiter.acquire().await;
+    let result = session
+        .execute_unpaged(&target.prepared, values)
+        .await
+        .map(|_| ())
+        .map_err(|err| err.to_string());
+    (index, result)
+}
+
+/// Execute `target` once per parameter set drawn from `params`, keeping at most
+/// `window` executions in flight.
+pub async fn execute_many(
+    session: Arc<ScyllaSession>,
+    target: ManyTarget,
+    params: Py<PyIterator>,
+    window: usize,
+) -> PyResult<ManySummary> {
+    let mut summary = ManySummary::default();
+    let mut in_flight = FuturesUnordered::new();
+    let mut next_index = 0u64;
+    let mut exhausted = false;
+
+    loop {
+        if !exhausted && in_flight.len() < window {
+            let wanted = window - in_flight.len();
+            let sets = next_params(&params, &target, wanted)?;
+            exhausted = sets.len() < wanted;
+            for values in sets {
+                let index = next_index;
+                next_index += 1;
+                match values {
+                    Ok(values) => in_flight.push(execute_one(&session, &target, index, values)),
+                    Err(error) => summary.errors.push((index, error)),
+                }
+            }
+        }
+
+        match in_flight.next().await {
+            Some((_, Ok(()))) => summary.succeeded += 1,
+            Some((index, Err(error))) => summary.errors.push((index, error)),
+            None if exhausted => break,
+            None => {}
+        }
+    }
+
+    summary.errors.sort_by_key(|(index, _)| *index);
+    Ok(summary)
+}
@@ -0,0 +1,69 @@
+use pyo3::prelude::*;
+use scylla::client::execution_profile::{
+    ExecutionProfile as ScyllaExecutionProfile, ExecutionProfileBuilder, ExecutionProfileHandle,
+};
+use std::time::Duration;
+
+use crate::policies::{parse_retry_policy, LoadBalancingPolicy};
+use crate::query::{parse_consistency, parse_serial_consistency};
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ExecutionProfile {
+    builder: ExecutionProfileBuilder,
+}
+
+impl ExecutionProfile {
+    pub(crate) fn handle(&self) -> ExecutionProfileHandle {
+        self.builder.clone().build().into_handle()
+    }
+}
+
+#[pymethods]
+impl ExecutionProfile {
+    #[new]
+    pub fn new() -> Self {
+        ExecutionProfile {
+            builder: ScyllaExecutionProfile::builder(),
+        }
+    }
+
+    pub fn with_consistency(&mut self, consistency: &str) -> PyResult<Self> {
+        let cons = parse_consistency(consistency)?;
+        self.builder = self.builder.clone().consistency(cons);
+        Ok(self.clone())
+    }
+
+    pub fn with_serial_consistency(&mut self, serial_consistency: &str) -> PyResult<Self> {
+        let cons = parse_serial_consistency(serial_consistency)?;
+        self.builder = self.builder.clone().serial_consistency(Some(cons));
+        Ok(self.clone())
+    }
+
+    #[pyo3(signature = (timeout_ms=None))]
+    pub fn with_timeout(&mut self, timeout_ms: Option<u64>) -> PyResult<Self> {
+        self.builder = self
+            .builder
+            .clone()
+            .request_timeout(timeout_ms.map(Duration::from_millis));
+        Ok(self.clone())
+    }
+
+    pub fn with_retry_policy(&mut self, retry_policy: &str) -> PyResult<Self> {
+        let policy = parse_retry_policy(retry_policy)?;
+        self.builder = self.builder.clone().retry_policy(policy);
+        Ok(self.clone())
+    }
+
+    pub fn with_load_balancing_policy(&mut self, policy: &LoadBalancingPolicy) -> PyResult<Self> {
+        let (policy, _) = policy.build();
+        self.builder = self.builder.clone().load_balancing_policy(policy);
+        Ok(self.clone())
+    }
+}
+
+impl Default for ExecutionProfile {
+    fn default() -> Self {
+        Self::new()
+    }
+}
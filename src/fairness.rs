@@ -0,0 +1,64 @@
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+struct Cap {
+    max_in_flight: usize,
+    semaphore: Arc<Semaphore>,
+}
+
+/// Named concurrency caps shared by every statement of a session. Statements without a
+/// class, and classes registered without a cap, are never throttled.
+pub struct FairnessClasses {
+    classes: HashMap<String, Option<Cap>>,
+}
+
+impl FairnessClasses {
+    /// Caps for `{class: max_in_flight}`, with semaphores of their own so sessions built
+    /// from the same builder don't share slots.
+    pub fn new(limits: &HashMap<String, Option<usize>>) -> Self {
+        let classes = limits
+            .iter()
+            .map(|(name, max_in_flight)| {
+                let cap = max_in_flight.map(|max_in_flight| Cap {
+                    max_in_flight,
+                    semaphore: Arc::new(Semaphore::new(max_in_flight)),
+                });
+                (name.clone(), cap)
+            })
+            .collect();
+        FairnessClasses { classes }
+    }
+
+    /// Fail fast on a class the session doesn't know, before any work is queued.
+    pub fn check(&self, class: Option<&str>) -> PyResult<()> {
+        match class {
+            Some(name) if !self.classes.contains_key(name) => {
+                Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown fairness class '{}'",
+                    name
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Wait for a free slot in the statement's class; the slot is held until the permit drops.
+    pub async fn acquire(&self, class: Option<&str>) -> Option<OwnedSemaphorePermit> {
+        let cap = self.classes.get(class?)?.as_ref()?;
+        cap.semaphore.clone().acquire_owned().await.ok()
+    }
+
+    /// `{class: statements currently in flight}` for capped classes.
+    pub fn in_flight(&self) -> HashMap<String, usize> {
+        self.classes
+            .iter()
+            .filter_map(|(name, cap)| {
+                let cap = cap.as_ref()?;
+                let in_flight = cap.max_in_flight - cap.semaphore.available_permits();
+                Some((name.clone(), in_flight))
+            })
+            .collect()
+    }
+}
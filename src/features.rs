@@ -0,0 +1,188 @@
+use futures::future::join_all;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla::client::session::Session as ScyllaSession;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// Protocol version of the OPTIONS request; any version the node speaks gets an answer.
+const REQUEST_VERSION: u8 = 0x04;
+const OPCODE_ERROR: u8 = 0x00;
+const OPCODE_OPTIONS: u8 = 0x05;
+const OPCODE_SUPPORTED: u8 = 0x06;
+/// Far above any SUPPORTED body, so a node speaking something else can't make us
+/// allocate a huge buffer.
+const MAX_BODY_LENGTH: usize = 1 << 20;
+
+/// A node's SUPPORTED response: each option with the values it accepts.
+type Supported = BTreeMap<String, Vec<String>>;
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+fn read_short(body: &mut &[u8]) -> io::Result<usize> {
+    let Some((bytes, rest)) = body.split_first_chunk::<2>() else {
+        return Err(invalid("Truncated SUPPORTED response"));
+    };
+    *body = rest;
+    Ok(u16::from_be_bytes(*bytes) as usize)
+}
+
+fn read_string(body: &mut &[u8]) -> io::Result<String> {
+    let length = read_short(body)?;
+    if body.len() < length {
+        return Err(invalid("Truncated SUPPORTED response"));
+    }
+    let (bytes, rest) = body.split_at(length);
+    *body = rest;
+    String::from_utf8(bytes.to_vec()).map_err(|_| invalid("SUPPORTED option isn't UTF-8"))
+}
+
+/// Decode the `[string multimap]` body of a SUPPORTED response.
+fn parse_supported(mut body: &[u8]) -> io::Result<Supported> {
+    let mut supported = Supported::new();
+    for _ in 0..read_short(&mut body)? {
+        let option = read_string(&mut body)?;
+        let values = (0..read_short(&mut body)?)
+            .map(|_| read_string(&mut body))
+            .collect::<io::Result<_>>()?;
+        supported.insert(option, values);
+    }
+    Ok(supported)
+}
+
+/// Ask the node at `address` for its SUPPORTED options over a connection of our own,
+/// as the driver does before STARTUP, which it keeps to itself.
+async fn fetch_supported(address: SocketAddr) -> io::Result<Supported> {
+    let mut stream = TcpStream::connect(address).await?;
+    let mut header = [REQUEST_VERSION, 0, 0, 0, OPCODE_OPTIONS, 0, 0, 0, 0];
+    stream.write_all(&header).await?;
+
+    stream.read_exact(&mut header).await?;
+    let length = u32::from_be_bytes([header[5], header[6], header[7], header[8]]) as usize;
+    if length > MAX_BODY_LENGTH {
+        return Err(invalid("SUPPORTED response too large"));
+    }
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).await?;
+    match header[4] {
+        OPCODE_SUPPORTED => parse_supported(&body),
+        OPCODE_ERROR => Err(invalid("Node rejected the OPTIONS request")),
+        _ => Err(invalid("Unexpected response to OPTIONS")),
+    }
+}
+
+/// What the cluster's nodes reported in SUPPORTED, fetched from every connected node.
+pub struct ClusterFeatures {
+    nodes: BTreeMap<String, Supported>,
+    shards: BTreeMap<String, u16>,
+    errors: BTreeMap<String, String>,
+}
+
+impl ClusterFeatures {
+    /// Values of `option` every node accepts, in the order the first node lists them.
+    fn common_values(&self, option: &str) -> Vec<String> {
+        let mut nodes = self.nodes.values();
+        let Some(first) = nodes.next().and_then(|node| node.get(option)) else {
+            return Vec::new();
+        };
+        let rest: Vec<_> = nodes.collect();
+        first
+            .iter()
+            .filter(|value| {
+                rest.iter().all(|node| {
+                    node.get(option)
+                        .is_some_and(|values| values.contains(value))
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Whether every node that answered advertises `option`.
+    fn supported_by_all(&self, option: &str) -> bool {
+        !self.nodes.is_empty() && self.nodes.values().all(|node| node.contains_key(option))
+    }
+
+    pub fn to_py<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let extensions: BTreeSet<&String> = self
+            .nodes
+            .values()
+            .flat_map(|node| node.keys())
+            .filter(|option| option.starts_with("SCYLLA_") || option.starts_with("TABLETS_"))
+            .filter(|option| self.supported_by_all(option))
+            .collect();
+        let nodes = PyDict::new(py);
+        for (address, supported) in &self.nodes {
+            nodes.set_item(address, supported.clone())?;
+        }
+
+        let features = PyDict::new(py);
+        features.set_item("cql_versions", self.common_values("CQL_VERSION"))?;
+        features.set_item("compression", self.common_values("COMPRESSION"))?;
+        features.set_item("protocol_versions", self.common_values("PROTOCOL_VERSIONS"))?;
+        features.set_item("extensions", extensions)?;
+        features.set_item("shard_aware", self.supported_by_all("SCYLLA_NR_SHARDS"))?;
+        features.set_item(
+            "rate_limit_error",
+            self.supported_by_all("SCYLLA_RATE_LIMIT_ERROR"),
+        )?;
+        features.set_item(
+            "lwt_metadata_mark",
+            self.supported_by_all("SCYLLA_LWT_ADD_METADATA_MARK"),
+        )?;
+        features.set_item("tablets", self.supported_by_all("TABLETS_ROUTING_V1"))?;
+        features.set_item("shards", self.shards.clone())?;
+        features.set_item("nodes", nodes)?;
+        features.set_item("errors", self.errors.clone())?;
+        Ok(features)
+    }
+}
+
+/// Fetch SUPPORTED from every connected node concurrently, giving each `timeout`.
+pub async fn fetch_features(session: &ScyllaSession, timeout: Duration) -> ClusterFeatures {
+    let cluster_state = session.get_cluster_state();
+    let nodes: Vec<_> = cluster_state
+        .get_nodes_info()
+        .iter()
+        .filter(|node| node.is_connected())
+        .collect();
+    let responses = join_all(nodes.iter().map(|node| {
+        let address = SocketAddr::new(node.address.ip(), node.address.port());
+        async move {
+            match tokio::time::timeout(timeout, fetch_supported(address)).await {
+                Ok(response) => response,
+                Err(_) => Err(io::Error::new(io::ErrorKind::TimedOut, "OPTIONS timed out")),
+            }
+        }
+    }))
+    .await;
+
+    let mut features = ClusterFeatures {
+        nodes: BTreeMap::new(),
+        shards: BTreeMap::new(),
+        errors: BTreeMap::new(),
+    };
+    for (node, response) in nodes.iter().zip(responses) {
+        let address = node.address.to_string();
+        if let Some(sharder) = node.sharder() {
+            features
+                .shards
+                .insert(address.clone(), sharder.nr_shards.get());
+        }
+        match response {
+            Ok(supported) => {
+                features.nodes.insert(address, supported);
+            }
+            Err(err) => {
+                features.errors.insert(address, err.to_string());
+            }
+        }
+    }
+    features
+}
@@ -0,0 +1,223 @@
+use futures::stream::{self, StreamExt};
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::get_runtime;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::response::{PagingState, PagingStateResponse};
+use scylla::statement::prepared::PreparedStatement as ScyllaPreparedStatement;
+use scylla::value::Row as ScyllaRow;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::error::{metadata_error_to_py, prepare_error_to_py, query_error_to_py};
+use crate::metadata::token_ranges;
+use crate::pager::{page_rows, PageRows};
+use crate::rate_limit::RequestLimiter;
+use crate::result::Row;
+use crate::runtime::future_into_py;
+use crate::statement_cache::StatementCache;
+
+pub struct ScanOptions {
+    pub keyspace: String,
+    pub table: String,
+    pub columns: Vec<String>,
+    pub concurrency: usize,
+    pub ranges_per_task: usize,
+    pub page_size: i32,
+    pub bypass_cache: bool,
+    pub limiter: Arc<RequestLimiter>,
+}
+
+type Page = PyResult<PageRows>;
+
+/// Page through every row of `(start, end]`, forwarding pages to the scan consumer.
+/// Returns `false` once the consumer has gone away.
+async fn scan_range(
+    session: &ScyllaSession,
+    limiter: &RequestLimiter,
+    prepared: &ScyllaPreparedStatement,
+    range: (i64, i64),
+    pages: &mpsc::Sender<Page>,
+) -> bool {
+    let mut paging_state = PagingState::start();
+    loop {
+        let slot = limiter.acquire().await;
+        let page = session
+            .execute_single_page(prepared, range, paging_state)
+            .await
+            .map_err(query_error_to_py);
+        drop(slot);
+        let (result, paging_state_response) = match page {
+            Ok(page) => page,
+            Err(err) => {
+                let _ = pages.send(Err(err)).await;
+                return false;
+            }
+        };
+
+        let rows = page_rows(result);
+        let failed = rows.is_err();
+        if pages.send(rows).await.is_err() || failed {
+            return false;
+        }
+
+        match paging_state_response {
+            PagingStateResponse::HasMorePages { state } => paging_state = state,
+            _ => return true,
+        }
+    }
+}
+
+async fn run_scan(
+    session: Arc<ScyllaSession>,
+    statement_cache: Arc<StatementCache>,
+    options: ScanOptions,
+    pages: mpsc::Sender<Page>,
+) -> PyResult<()> {
+    session
+        .refresh_metadata()
+        .await
+        .map_err(metadata_error_to_py)?;
+
+    let (partition_key, ranges) = {
+        let cluster_state = session.get_cluster_state();
+        let table = cluster_state
+            .get_keyspace(&options.keyspace)
+            .and_then(|keyspace| keyspace.tables.get(&options.table))
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown table '{}.{}'",
+                    options.keyspace, options.table
+                ))
+            })?;
+        (
+            table.partition_key.join(", "),
+            token_ranges(&cluster_state, &options.keyspace, Some(&options.table))?,
+        )
+    };
+
+    let columns = if options.columns.is_empty() {
+        "*".to_string()
+    } else {
+        options.columns.join(", ")
+    };
+    let mut cql = format!(
+        "SELECT {} FROM {}.{} WHERE token({pk}) > ? AND token({pk}) <= ?",
+        columns,
+        options.keyspace,
+        options.table,
+        pk = partition_key
+    );
+    if options.bypass_cache {
+        cql.push_str(" BYPASS CACHE");
+    }
+    let mut prepared = statement_cache
+        .get_or_prepare(&session, &cql)
+        .await
+        .map_err(prepare_error_to_py)?;
+    prepared.set_page_size(options.page_size);
+
+    let tasks: Vec<Vec<(i64, i64)>> = ranges
+        .chunks(options.ranges_per_task)
+        .map(|chunk| chunk.iter().map(|(start, end, _)| (*start, *end)).collect())
+        .collect();
+
+    stream::iter(tasks)
+        .for_each_concurrent(options.concurrency, |task| {
+            let session = &session;
+            let limiter = &options.limiter;
+            let prepared = &prepared;
+            let pages = &pages;
+            async move {
+                for range in task {
+                    if !scan_range(session, limiter, prepared, range, pages).await {
+                        return;
+                    }
+                }
+            }
+        })
+        .await;
+
+    Ok(())
+}
+
+struct ScanState {
+    pages: mpsc::Receiver<Page>,
+    names: Arc<[String]>,
+    buffer: VecDeque<ScyllaRow>,
+}
+
+/// Async row iterator over a whole table, scanning token ranges concurrently.
+/// Rows from different ranges are interleaved; order is unspecified.
+#[pyclass]
+pub struct FullScan {
+    state: Arc<Mutex<ScanState>>,
+    task: JoinHandle<()>,
+}
+
+impl FullScan {
+    pub fn start(
+        session: Arc<ScyllaSession>,
+        statement_cache: Arc<StatementCache>,
+        options: ScanOptions,
+    ) -> Self {
+        // A couple of pages per worker keeps them busy without buffering the table
+        let (sender, receiver) = mpsc::channel(options.concurrency * 2);
+
+        let task = get_runtime().spawn(async move {
+            if let Err(err) = run_scan(session, statement_cache, options, sender.clone()).await {
+                let _ = sender.send(Err(err)).await;
+            }
+        });
+
+        FullScan {
+            state: Arc::new(Mutex::new(ScanState {
+                pages: receiver,
+                names: Arc::from([]),
+                buffer: VecDeque::new(),
+            })),
+            task,
+        }
+    }
+}
+
+impl Drop for FullScan {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[pymethods]
+impl FullScan {
+    pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let state = self.state.clone();
+
+        future_into_py(py, async move {
+            let mut state = state.lock().await;
+            while state.buffer.is_empty() {
+                match state.pages.recv().await {
+                    Some(page) => {
+                        let (names, rows) = page?;
+                        state.names = names;
+                        state.buffer = rows.into();
+                    }
+                    None => {
+                        return Err(PyErr::new::<pyo3::exceptions::PyStopAsyncIteration, _>(()))
+                    }
+                }
+            }
+
+            let row = state.buffer.pop_front().expect("buffer is not empty");
+            Ok(Row::with_names(row, state.names.clone()))
+        })
+    }
+
+    pub fn cancel(&self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,91 @@
+use futures::future::join_all;
+use pyo3::prelude::*;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::cluster::Node;
+use scylla::policies::load_balancing::{NodeIdentifier, SingleTargetLoadBalancingPolicy};
+use scylla::policies::retry::FallthroughRetryPolicy;
+use scylla::statement::unprepared::Statement as ScyllaStatement;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Outcome of probing one node with `Session.check_connectivity()`.
+#[pyclass]
+#[derive(Clone)]
+pub struct NodeHealth {
+    #[pyo3(get)]
+    pub address: String,
+    #[pyo3(get)]
+    pub datacenter: Option<String>,
+    #[pyo3(get)]
+    pub rack: Option<String>,
+    #[pyo3(get)]
+    pub host_id: String,
+    /// Whether the session holds at least one open connection to the node.
+    #[pyo3(get)]
+    pub connected: bool,
+    /// Whether the node answered the probe query in time.
+    #[pyo3(get)]
+    pub healthy: bool,
+    #[pyo3(get)]
+    pub latency_ms: Option<f64>,
+    #[pyo3(get)]
+    pub error: Option<String>,
+}
+
+#[pymethods]
+impl NodeHealth {
+    fn __repr__(&self) -> String {
+        format!(
+            "NodeHealth(address={}, healthy={}, latency_ms={:?})",
+            self.address, self.healthy, self.latency_ms
+        )
+    }
+}
+
+/// Query `node`'s own `system.local` row, without retrying elsewhere.
+async fn probe(session: &ScyllaSession, node: &Arc<Node>, timeout: Duration) -> NodeHealth {
+    let mut health = NodeHealth {
+        address: node.address.to_string(),
+        datacenter: node.datacenter.clone(),
+        rack: node.rack.clone(),
+        host_id: node.host_id.to_string(),
+        connected: node.is_connected(),
+        healthy: false,
+        latency_ms: None,
+        error: None,
+    };
+    if !health.connected {
+        health.error = Some("No open connections to the node".to_string());
+        return health;
+    }
+
+    let mut statement = ScyllaStatement::new("SELECT key FROM system.local WHERE key = 'local'");
+    statement.set_load_balancing_policy(Some(SingleTargetLoadBalancingPolicy::new(
+        NodeIdentifier::Node(node.clone()),
+        None,
+    )));
+    statement.set_retry_policy(Some(Arc::new(FallthroughRetryPolicy::new())));
+    statement.set_request_timeout(Some(timeout));
+
+    let started_at = Instant::now();
+    match session.query_unpaged(statement, &[]).await {
+        Ok(_) => {
+            health.healthy = true;
+            health.latency_ms = Some(started_at.elapsed().as_secs_f64() * 1000.0);
+        }
+        Err(err) => health.error = Some(err.to_string()),
+    }
+    health
+}
+
+/// Probe every node the session knows of concurrently.
+pub async fn check_connectivity(session: &ScyllaSession, timeout: Duration) -> Vec<NodeHealth> {
+    let cluster_state = session.get_cluster_state();
+    join_all(
+        cluster_state
+            .get_nodes_info()
+            .iter()
+            .map(|node| probe(session, node, timeout)),
+    )
+    .await
+}
@@ -0,0 +1,35 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla_cql::frame::request::options::DEFAULT_CQL_PROTOCOL_VERSION;
+
+/// CQL native protocol versions spoken by the embedded driver: the one it announces in
+/// STARTUP, as `major.minor.patch`.
+pub(crate) fn protocol_versions() -> Vec<u32> {
+    DEFAULT_CQL_PROTOCOL_VERSION
+        .split('.')
+        .next()
+        .and_then(|major| major.parse().ok())
+        .into_iter()
+        .collect()
+}
+
+/// Cargo features compiled in, collected by the build script.
+fn enabled_features() -> Vec<&'static str> {
+    env!("RSYLLA_FEATURES")
+        .split(',')
+        .filter(|feature| !feature.is_empty())
+        .collect()
+}
+
+#[pyfunction]
+pub fn build_info(py: Python) -> PyResult<Py<PyAny>> {
+    let dict = PyDict::new(py);
+    dict.set_item("rsylla_version", env!("CARGO_PKG_VERSION"))?;
+    dict.set_item("scylla_driver_version", env!("RSYLLA_SCYLLA_VERSION"))?;
+    dict.set_item("features", enabled_features())?;
+    dict.set_item("tls", cfg!(feature = "tls"))?;
+    dict.set_item("protocol_versions", protocol_versions())?;
+    dict.set_item("target", env!("RSYLLA_BUILD_TARGET"))?;
+    dict.set_item("profile", env!("RSYLLA_BUILD_PROFILE"))?;
+    Ok(dict.into())
+}
@@ -0,0 +1,77 @@
+use scylla::value::CqlValue;
+use serde_json::{Map, Number, Value};
+
+/// JSON form of a CQL value, mirroring the shapes `cql_value_to_py` produces.
+pub fn cql_value_to_json(value: &CqlValue) -> Value {
+    match value {
+        CqlValue::Ascii(s) | CqlValue::Text(s) => Value::String(s.clone()),
+        CqlValue::Boolean(b) => Value::Bool(*b),
+        CqlValue::Int(i) => Value::from(*i),
+        CqlValue::BigInt(i) => Value::from(*i),
+        CqlValue::SmallInt(i) => Value::from(*i),
+        CqlValue::TinyInt(i) => Value::from(*i),
+        CqlValue::Counter(c) => Value::from(c.0),
+        // NaN and infinities have no JSON representation.
+        CqlValue::Float(f) => Number::from_f64(*f as f64).map_or(Value::Null, Value::Number),
+        CqlValue::Double(d) => Number::from_f64(*d).map_or(Value::Null, Value::Number),
+        CqlValue::Blob(b) => {
+            let hex: String = b.iter().map(|byte| format!("{:02x}", byte)).collect();
+            Value::String(format!("0x{}", hex))
+        }
+        CqlValue::Uuid(u) => Value::String(u.to_string()),
+        CqlValue::Timeuuid(t) => Value::String(t.to_string()),
+        CqlValue::Inet(addr) => Value::String(addr.to_string()),
+        CqlValue::List(items) | CqlValue::Set(items) => {
+            Value::Array(items.iter().map(cql_value_to_json).collect())
+        }
+        CqlValue::Map(map) => Value::Object(
+            map.iter()
+                .map(|(key, val)| (json_key(key), cql_value_to_json(val)))
+                .collect(),
+        ),
+        CqlValue::Timestamp(ts) => Value::from(ts.0),
+        CqlValue::Date(d) => Value::from(d.0),
+        CqlValue::Time(t) => Value::from(t.0),
+        CqlValue::Duration(d) => {
+            let mut object = Map::new();
+            object.insert("months".to_string(), Value::from(d.months));
+            object.insert("days".to_string(), Value::from(d.days));
+            object.insert("nanoseconds".to_string(), Value::from(d.nanoseconds));
+            Value::Object(object)
+        }
+        CqlValue::Varint(v) => Value::String(format!("{:?}", v)),
+        CqlValue::Decimal(d) => Value::String(format!("{:?}", d)),
+        CqlValue::Tuple(items) => Value::Array(items.iter().map(optional_to_json).collect()),
+        CqlValue::UserDefinedType { fields, .. } => Value::Object(
+            fields
+                .iter()
+                .map(|(name, value)| (name.clone(), optional_to_json(value)))
+                .collect(),
+        ),
+        CqlValue::Empty => Value::Null,
+        _ => Value::String(format!("{:?}", value)),
+    }
+}
+
+fn optional_to_json(value: &Option<CqlValue>) -> Value {
+    value.as_ref().map_or(Value::Null, cql_value_to_json)
+}
+
+/// JSON object keys must be strings, so non-text map keys use their JSON text.
+fn json_key(key: &CqlValue) -> String {
+    match cql_value_to_json(key) {
+        Value::String(s) => s,
+        other => other.to_string(),
+    }
+}
+
+/// One row as a JSON object keyed by column name.
+pub fn row_to_json(names: &[String], columns: &[Option<CqlValue>]) -> Value {
+    Value::Object(
+        names
+            .iter()
+            .zip(columns)
+            .map(|(name, column)| (name.clone(), optional_to_json(column)))
+            .collect(),
+    )
+}
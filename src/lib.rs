@@ -3,32 +3,166 @@
 
 use pyo3::prelude::*;
 
+mod arrow;
 mod batch;
+mod cdc;
+mod clients;
+mod columnar;
+mod config;
+mod contact_points;
+mod copy_from;
+mod copy_to;
+mod cql;
+mod dataframe;
+mod deadline;
+mod diff;
 mod error;
+mod event_loop;
+mod events;
+mod execute_many;
+mod execution_profile;
+mod fairness;
+mod features;
+mod full_scan;
+mod health;
+mod info;
+mod json;
+mod logging;
+mod lwt;
+mod mapper;
+mod metadata;
+mod metrics;
+mod models;
+mod observer;
+mod ordered_writer;
+mod pager;
+mod pending;
+mod pipeline;
+mod policies;
+mod prometheus;
+mod qb;
 mod query;
+mod rate_limit;
 mod result;
+mod retry;
+mod runtime;
 mod session;
+mod statement_cache;
+mod stream;
+mod telemetry;
+mod tracing_info;
 mod types;
 
 use batch::Batch;
-use error::ScyllaError;
-use query::{PreparedStatement, Query};
-use result::{QueryResult, Row};
+use cdc::{CDCReader, ChangeEvent};
+use clients::ServerConnection;
+use diff::{diff_results, diff_rows};
+use error::{
+    BindError, DeadlineExceededError, ForkedProcessError, RateLimitedError, ScyllaError,
+    ScyllaWarning, ValueOutOfRange,
+};
+use event_loop::{event_loop_backend, use_event_loop};
+use events::{ClusterEvent, ClusterEvents};
+use execution_profile::ExecutionProfile;
+use full_scan::FullScan;
+use health::NodeHealth;
+use info::build_info;
+use logging::configure_logging;
+use mapper::Mapper;
+use metadata::{
+    ClusterMetadata, ColumnMetadata, CqlType, IndexMetadata, KeyspaceMetadata,
+    MaterializedViewMetadata, NodeInfo, TableMetadata, UserTypeMetadata,
+};
+use observer::RequestEvent;
+use ordered_writer::OrderedWriter;
+use pager::{PageIterator, Pager};
+use pending::PendingResult;
+use pipeline::Pipeline;
+use policies::LoadBalancingPolicy;
+use prometheus::PrometheusExporter;
+use qb::{delete, insert_into, select, update, Delete, Insert, Select, Update};
+use query::{BoundStatement, PreparedStatement, Query};
+use result::{QueryResult, ResultSnapshot, Row};
+use runtime::shutdown;
 use session::{Session, SessionBuilder};
+use tracing_info::{TracingEvent, TracingInfo};
 
-#[pymodule]
+// Shared state lives behind atomics, locks or PyO3's borrow checking, which raises
+// rather than racing when two threads mutate the same builder, so the module can run
+// without the GIL on free-threaded builds.
+#[pymodule(gil_used = false)]
 fn _rsylla(_py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     // Core types
     m.add_class::<SessionBuilder>()?;
     m.add_class::<Session>()?;
     m.add_class::<Query>()?;
     m.add_class::<PreparedStatement>()?;
+    m.add_class::<BoundStatement>()?;
     m.add_class::<QueryResult>()?;
     m.add_class::<Row>()?;
+    m.add_class::<ResultSnapshot>()?;
+    m.add_class::<PendingResult>()?;
     m.add_class::<Batch>()?;
+    m.add_class::<ExecutionProfile>()?;
+    m.add_class::<Pipeline>()?;
+    m.add_class::<LoadBalancingPolicy>()?;
+    m.add_class::<OrderedWriter>()?;
+    m.add_class::<Pager>()?;
+    m.add_class::<PageIterator>()?;
+    m.add_class::<FullScan>()?;
+    m.add_class::<Mapper>()?;
+    m.add_class::<CDCReader>()?;
+    m.add_class::<ChangeEvent>()?;
+    m.add_class::<ClusterEvents>()?;
+    m.add_class::<ClusterEvent>()?;
+    m.add_class::<PrometheusExporter>()?;
+    m.add_class::<RequestEvent>()?;
+    m.add_class::<TracingInfo>()?;
+    m.add_class::<TracingEvent>()?;
+    m.add_class::<ClusterMetadata>()?;
+    m.add_class::<NodeInfo>()?;
+    m.add_class::<NodeHealth>()?;
+    m.add_class::<ServerConnection>()?;
+    m.add_class::<KeyspaceMetadata>()?;
+    m.add_class::<TableMetadata>()?;
+    m.add_class::<ColumnMetadata>()?;
+    m.add_class::<CqlType>()?;
+    m.add_class::<MaterializedViewMetadata>()?;
+    m.add_class::<IndexMetadata>()?;
+    m.add_class::<UserTypeMetadata>()?;
+
+    // Query builder, re-exported by rsylla.qb
+    m.add_class::<Select>()?;
+    m.add_class::<Insert>()?;
+    m.add_class::<Update>()?;
+    m.add_class::<Delete>()?;
+    m.add_function(wrap_pyfunction!(select, m)?)?;
+    m.add_function(wrap_pyfunction!(insert_into, m)?)?;
+    m.add_function(wrap_pyfunction!(update, m)?)?;
+    m.add_function(wrap_pyfunction!(delete, m)?)?;
+
+    // Functions
+    m.add_function(wrap_pyfunction!(build_info, m)?)?;
+    m.add_function(wrap_pyfunction!(configure_logging, m)?)?;
+    m.add_function(wrap_pyfunction!(use_event_loop, m)?)?;
+    m.add_function(wrap_pyfunction!(event_loop_backend, m)?)?;
+    m.add_function(wrap_pyfunction!(shutdown, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_rows, m)?)?;
+    m.add_function(wrap_pyfunction!(diff_results, m)?)?;
 
     // Exception
     m.add("ScyllaError", _py.get_type::<ScyllaError>())?;
 
+    // Warning category for server-side warnings
+    m.add("ScyllaWarning", _py.get_type::<ScyllaWarning>())?;
+    m.add("RateLimitedError", _py.get_type::<RateLimitedError>())?;
+    m.add("BindError", _py.get_type::<BindError>())?;
+    m.add("ValueOutOfRange", _py.get_type::<ValueOutOfRange>())?;
+    m.add("ForkedProcessError", _py.get_type::<ForkedProcessError>())?;
+    m.add(
+        "DeadlineExceededError",
+        _py.get_type::<DeadlineExceededError>(),
+    )?;
+
     Ok(())
 }
@@ -0,0 +1,142 @@
+use pyo3::prelude::*;
+use pyo3::sync::PyOnceLock;
+use pyo3::types::PyDict;
+use std::fmt::Write as _;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Once;
+use tracing::field::{Field, Visit};
+use tracing::subscriber::Interest;
+use tracing::{Event, Level, Metadata, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::Registry;
+
+const LOGGER_NAME: &str = "rsylla";
+
+/// Python logging level used for `TRACE` events, below `logging.DEBUG`.
+const PY_TRACE: u32 = 5;
+
+/// Most verbose level forwarded to Python: 0 = off, 1 = error ... 5 = trace.
+static MAX_LEVEL: AtomicUsize = AtomicUsize::new(0);
+static INSTALL: Once = Once::new();
+static LOGGER: PyOnceLock<Py<PyAny>> = PyOnceLock::new();
+
+fn level_rank(level: &Level) -> usize {
+    match *level {
+        Level::ERROR => 1,
+        Level::WARN => 2,
+        Level::INFO => 3,
+        Level::DEBUG => 4,
+        Level::TRACE => 5,
+    }
+}
+
+fn parse_level(level: &str) -> PyResult<usize> {
+    match level.to_uppercase().as_str() {
+        "OFF" | "NONE" => Ok(0),
+        "ERROR" | "CRITICAL" => Ok(1),
+        "WARN" | "WARNING" => Ok(2),
+        "INFO" => Ok(3),
+        "DEBUG" => Ok(4),
+        "TRACE" => Ok(5),
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid log level: {}. Must be 'off', 'error', 'warning', 'info', 'debug' or 'trace'",
+            level
+        ))),
+    }
+}
+
+fn python_level(level: &Level) -> u32 {
+    match *level {
+        Level::ERROR => 40,
+        Level::WARN => 30,
+        Level::INFO => 20,
+        Level::DEBUG => 10,
+        Level::TRACE => PY_TRACE,
+    }
+}
+
+/// Renders the `message` field followed by the remaining fields as `key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message.push_str(value);
+        } else {
+            let _ = write!(self.fields, " {}={}", field.name(), value);
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.message, "{:?}", value);
+        } else {
+            let _ = write!(self.fields, " {}={:?}", field.name(), value);
+        }
+    }
+}
+
+/// Forwards driver events to `logging.getLogger("rsylla")`.
+struct PythonLogLayer;
+
+impl<S: Subscriber> Layer<S> for PythonLogLayer {
+    fn register_callsite(&self, _metadata: &'static Metadata<'static>) -> Interest {
+        // The level can change at runtime, so never let the callsite cache a verdict.
+        Interest::sometimes()
+    }
+
+    fn enabled(&self, metadata: &Metadata<'_>, _ctx: Context<'_, S>) -> bool {
+        level_rank(metadata.level()) <= MAX_LEVEL.load(Ordering::Relaxed)
+    }
+
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let message = format!("{}{}", visitor.message, visitor.fields);
+
+        // Skip events raised while the interpreter is shutting down.
+        let _ = Python::try_attach(|py| -> PyResult<()> {
+            let logger = LOGGER.get_or_try_init(py, || {
+                py.import("logging")?
+                    .call_method1("getLogger", (LOGGER_NAME,))
+                    .map(Bound::unbind)
+            })?;
+
+            let extra = PyDict::new(py);
+            extra.set_item("rust_target", metadata.target())?;
+            let kwargs = PyDict::new(py);
+            kwargs.set_item("extra", extra)?;
+            logger.bind(py).call_method(
+                "log",
+                (python_level(metadata.level()), message),
+                Some(&kwargs),
+            )?;
+            Ok(())
+        });
+    }
+}
+
+/// Forward the driver's internal logs (connection errors, reconnects, topology refreshes)
+/// to the `rsylla` Python logger, at `level` and above.
+#[pyfunction]
+#[pyo3(signature = (level="warning"))]
+pub fn configure_logging(level: &str) -> PyResult<()> {
+    MAX_LEVEL.store(parse_level(level)?, Ordering::Relaxed);
+
+    let mut result = Ok(());
+    INSTALL.call_once(|| {
+        let subscriber = Registry::default().with(PythonLogLayer);
+        result = tracing::subscriber::set_global_default(subscriber).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to install log bridge: {}",
+                e
+            ))
+        });
+    });
+    result
+}
@@ -0,0 +1,91 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::value::{CqlValue, Row as ScyllaRow};
+use std::sync::Arc;
+
+use crate::cql::validate_identifier;
+use crate::error::{prepare_error_to_py, query_error_to_py};
+use crate::pager::page_rows;
+use crate::rate_limit::RequestLimiter;
+use crate::result::Row;
+use crate::statement_cache::StatementCache;
+use crate::types::{py_value_to_serializable, SerializableValue};
+
+const APPLIED_COLUMN: &str = "[applied]";
+
+/// Validated column names of `values` and their values, in the same order.
+pub fn columns_and_values(
+    values: &Bound<'_, PyDict>,
+) -> PyResult<(Vec<String>, Vec<SerializableValue>)> {
+    let mut columns = Vec::with_capacity(values.len());
+    let mut serialized = Vec::with_capacity(values.len());
+    for (column, value) in values.iter() {
+        let column = column.extract::<String>()?;
+        validate_identifier(&column)?;
+        columns.push(column);
+        serialized.push(py_value_to_serializable(&value)?);
+    }
+    Ok((columns, serialized))
+}
+
+/// `a = ? AND b = ?` over `columns`.
+pub fn conditions(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| format!("{} = ?", column))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// A conditional statement and its positional values. Values are bound by position so
+/// that a column can appear in both the assignments and the condition.
+pub struct Lwt {
+    pub cql: String,
+    pub values: Vec<SerializableValue>,
+}
+
+/// Run a conditional statement and split its result into whether it was applied and,
+/// when it wasn't, the row currently stored (`None` if there is none).
+pub async fn execute_lwt(
+    session: &ScyllaSession,
+    statement_cache: &StatementCache,
+    limiter: &RequestLimiter,
+    lwt: Lwt,
+) -> PyResult<(bool, Option<Row>)> {
+    let prepared = statement_cache
+        .get_or_prepare(session, &lwt.cql)
+        .await
+        .map_err(prepare_error_to_py)?;
+    let _slot = limiter.acquire().await;
+    let result = session
+        .execute_unpaged(&prepared, lwt.values)
+        .await
+        .map_err(query_error_to_py)?;
+
+    let (names, mut rows) = page_rows(result)?;
+    let applied_index = names.iter().position(|name| name == APPLIED_COLUMN);
+    let (Some(applied_index), false) = (applied_index, rows.is_empty()) else {
+        return Err(PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(
+            "Conditional statement returned no [applied] column",
+        ));
+    };
+
+    let mut columns = rows.swap_remove(0).columns;
+    let applied = matches!(columns.remove(applied_index), Some(CqlValue::Boolean(true)));
+    if applied {
+        return Ok((true, None));
+    }
+
+    // Scylla fills the columns with nulls when no row exists yet
+    if columns.iter().all(Option::is_none) {
+        return Ok((false, None));
+    }
+    let names: Arc<[String]> = names
+        .iter()
+        .enumerate()
+        .filter(|(index, _)| *index != applied_index)
+        .map(|(_, name)| name.clone())
+        .collect();
+    Ok((false, Some(Row::with_names(ScyllaRow { columns }, names))))
+}
@@ -0,0 +1,309 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyTuple};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::response::query_result::QueryResult as ScyllaQueryResult;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::cql::{validate_identifier, validate_table_name};
+use crate::error::{prepare_error_to_py, query_error_to_py};
+use crate::models::ModelSpec;
+use crate::rate_limit::RequestLimiter;
+use crate::result::QueryResult;
+use crate::runtime::future_into_py;
+use crate::statement_cache::StatementCache;
+use crate::types::{py_dict_to_serialized_values, SerializableValue};
+
+/// Prepare `cql` through the session's statement cache and run it once, within the
+/// session's rate limit.
+async fn execute(
+    session: &ScyllaSession,
+    statement_cache: &StatementCache,
+    limiter: &RequestLimiter,
+    cql: &str,
+    values: HashMap<String, SerializableValue>,
+) -> PyResult<ScyllaQueryResult> {
+    let prepared = statement_cache
+        .get_or_prepare(session, cql)
+        .await
+        .map_err(prepare_error_to_py)?;
+    let _slot = limiter.acquire().await;
+    session
+        .execute_unpaged(&prepared, values)
+        .await
+        .map_err(query_error_to_py)
+}
+
+fn equality_conditions(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|column| format!("{} = ?", column))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// Maps a dataclass onto a table: one column per field, with generated statements
+/// prepared once per session and reused.
+#[pyclass]
+pub struct Mapper {
+    session: Arc<ScyllaSession>,
+    statement_cache: Arc<StatementCache>,
+    limiter: Arc<RequestLimiter>,
+    model: Arc<ModelSpec>,
+    table: String,
+    fields: Vec<String>,
+    primary_key: Vec<String>,
+    /// Rows with this column set are treated as deleted, and `delete()` sets it instead
+    /// of removing the row, like `Session.soft_delete`.
+    soft_delete_marker: Option<String>,
+    select_columns: String,
+}
+
+impl Mapper {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        py: Python,
+        session: Arc<ScyllaSession>,
+        statement_cache: Arc<StatementCache>,
+        limiter: Arc<RequestLimiter>,
+        cls: &Bound<'_, PyAny>,
+        table: String,
+        primary_key: Vec<String>,
+        soft_delete_marker: Option<String>,
+    ) -> PyResult<Self> {
+        let dataclasses = py.import("dataclasses")?;
+        if !dataclasses
+            .call_method1("is_dataclass", (cls,))?
+            .is_truthy()?
+        {
+            return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Mapped class must be a dataclass",
+            ));
+        }
+
+        let mut fields = Vec::new();
+        for field in dataclasses.call_method1("fields", (cls,))?.try_iter()? {
+            let name: String = field?.getattr("name")?.extract()?;
+            validate_identifier(&name)?;
+            fields.push(name);
+        }
+
+        validate_table_name(&table)?;
+        if primary_key.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Primary key must contain at least one column",
+            ));
+        }
+        if let Some(column) = primary_key.iter().find(|column| !fields.contains(column)) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Primary key column '{}' is not a field of the mapped class",
+                column
+            )));
+        }
+        if let Some(marker) = &soft_delete_marker {
+            validate_identifier(marker)?;
+        }
+
+        let mut select_columns = fields.clone();
+        if let Some(marker) = soft_delete_marker.as_ref().filter(|m| !fields.contains(m)) {
+            select_columns.push(marker.clone());
+        }
+
+        Ok(Mapper {
+            session,
+            statement_cache,
+            limiter,
+            model: Arc::new(ModelSpec::new(py, cls)?),
+            table,
+            fields,
+            primary_key,
+            soft_delete_marker,
+            select_columns: select_columns.join(", "),
+        })
+    }
+
+    /// The named fields of `obj` as bind values.
+    fn attributes<'py>(
+        &self,
+        obj: &Bound<'py, PyAny>,
+        names: &[String],
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let values = PyDict::new(obj.py());
+        for name in names {
+            values.set_item(name, obj.getattr(name.as_str())?)?;
+        }
+        Ok(values)
+    }
+
+    /// Primary key values from a dict, a tuple in primary key order, or a single value.
+    fn primary_key_values<'py>(&self, pk: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyDict>> {
+        if let Ok(values) = pk.cast::<PyDict>() {
+            return Ok(values.clone());
+        }
+
+        let values = PyDict::new(pk.py());
+        match pk.cast::<PyTuple>() {
+            Ok(tuple) if tuple.len() == self.primary_key.len() => {
+                for (column, value) in self.primary_key.iter().zip(tuple.iter()) {
+                    values.set_item(column, value)?;
+                }
+            }
+            Err(_) if self.primary_key.len() == 1 => {
+                values.set_item(&self.primary_key[0], pk)?;
+            }
+            _ => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Expected values for primary key ({})",
+                    self.primary_key.join(", ")
+                )))
+            }
+        }
+        Ok(values)
+    }
+
+    fn find<'py>(
+        &self,
+        py: Python<'py>,
+        cql: String,
+        values: &Bound<'_, PyDict>,
+        first_only: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let values = py_dict_to_serialized_values(Some(values))?;
+        let session = self.session.clone();
+        let statement_cache = self.statement_cache.clone();
+        let limiter = self.limiter.clone();
+        let model = self.model.clone();
+        let soft_delete_marker = self.soft_delete_marker.clone();
+
+        future_into_py(py, async move {
+            let result = execute(&session, &statement_cache, &limiter, &cql, values).await?;
+            Python::attach(|py| {
+                let instances = QueryResult::new(result).build_models(
+                    py,
+                    &model,
+                    soft_delete_marker.as_deref(),
+                )?;
+                if first_only {
+                    Ok(instances.into_iter().next().unwrap_or_else(|| py.None()))
+                } else {
+                    Ok(instances.into_pyobject(py)?.into_any().unbind())
+                }
+            })
+        })
+    }
+
+    fn run<'py>(
+        &self,
+        py: Python<'py>,
+        cql: String,
+        values: &Bound<'_, PyDict>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let values = py_dict_to_serialized_values(Some(values))?;
+        let session = self.session.clone();
+        let statement_cache = self.statement_cache.clone();
+        let limiter = self.limiter.clone();
+
+        future_into_py(py, async move {
+            execute(&session, &statement_cache, &limiter, &cql, values).await?;
+            Ok(())
+        })
+    }
+}
+
+#[pymethods]
+impl Mapper {
+    /// Insert or overwrite the row for `obj`, writing every field. A soft-delete marker
+    /// that isn't a field is cleared, so saving a deleted row brings it back.
+    pub fn save<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let mut columns = self.fields.clone();
+        let mut markers = vec!["?"; self.fields.len()];
+        if let Some(marker) = self
+            .soft_delete_marker
+            .as_ref()
+            .filter(|marker| !self.fields.contains(marker))
+        {
+            columns.push(marker.clone());
+            markers.push("null");
+        }
+        let cql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.table,
+            columns.join(", "),
+            markers.join(", ")
+        );
+        self.run(obj.py(), cql, &self.attributes(obj, &self.fields)?)
+    }
+
+    /// The instance stored under `pk`, or `None`.
+    pub fn get<'py>(&self, pk: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let cql = format!(
+            "SELECT {} FROM {} WHERE {}",
+            self.select_columns,
+            self.table,
+            equality_conditions(&self.primary_key)
+        );
+        self.find(pk.py(), cql, &self.primary_key_values(pk)?, true)
+    }
+
+    /// Delete the row for `obj`, or mark it deleted when the mapper soft-deletes.
+    pub fn delete<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let cql = match &self.soft_delete_marker {
+            Some(marker) => format!(
+                "UPDATE {} SET {} = toTimestamp(now()) WHERE {}",
+                self.table,
+                marker,
+                equality_conditions(&self.primary_key)
+            ),
+            None => format!(
+                "DELETE FROM {} WHERE {}",
+                self.table,
+                equality_conditions(&self.primary_key)
+            ),
+        };
+        self.run(obj.py(), cql, &self.attributes(obj, &self.primary_key)?)
+    }
+
+    /// Instances whose fields equal the given values, e.g. `filter(user_id=1)`.
+    #[pyo3(signature = (allow_filtering=false, **conditions))]
+    pub fn filter<'py>(
+        &self,
+        py: Python<'py>,
+        allow_filtering: bool,
+        conditions: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let values = conditions.cloned().unwrap_or_else(|| PyDict::new(py));
+        let mut columns: Vec<String> = values.keys().extract()?;
+        if let Some(column) = columns.iter().find(|column| !self.fields.contains(column)) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "'{}' is not a field of the mapped class",
+                column
+            )));
+        }
+        // A stable column order keeps one cached statement per set of conditions
+        columns.sort();
+
+        let mut cql = format!("SELECT {} FROM {}", self.select_columns, self.table);
+        if !columns.is_empty() {
+            cql.push_str(&format!(" WHERE {}", equality_conditions(&columns)));
+        }
+        if allow_filtering {
+            cql.push_str(" ALLOW FILTERING");
+        }
+        self.find(py, cql, &values, false)
+    }
+
+    pub fn table(&self) -> String {
+        self.table.clone()
+    }
+
+    pub fn primary_key(&self) -> Vec<String> {
+        self.primary_key.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Mapper(table={:?}, primary_key={:?})",
+            self.table, self.primary_key
+        )
+    }
+}
@@ -0,0 +1,719 @@
+use futures::future::join_all;
+use pyo3::prelude::*;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::cluster::metadata::{
+    CollectionType, ColumnKind, ColumnType, Keyspace, MaterializedView, NativeType, Strategy, Table,
+};
+use scylla::cluster::ClusterState;
+use scylla::policies::load_balancing::{NodeIdentifier, SingleTargetLoadBalancingPolicy};
+use scylla::routing::Token;
+use scylla::statement::unprepared::Statement as ScyllaQuery;
+use std::collections::HashMap;
+
+use crate::error::{deserialization_error_to_py, metadata_error_to_py, query_error_to_py};
+
+#[pyclass]
+#[derive(Clone)]
+pub struct NodeInfo {
+    #[pyo3(get)]
+    pub address: String,
+    #[pyo3(get)]
+    pub datacenter: Option<String>,
+    #[pyo3(get)]
+    pub rack: Option<String>,
+    #[pyo3(get)]
+    pub host_id: String,
+    #[pyo3(get)]
+    pub scylla_version: Option<String>,
+    #[pyo3(get)]
+    pub shard_count: Option<u16>,
+    #[pyo3(get)]
+    pub is_up: bool,
+}
+
+#[pymethods]
+impl NodeInfo {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "NodeInfo(address={}, datacenter={:?}, rack={:?}, is_up={})",
+            self.address, self.datacenter, self.rack, self.is_up
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ClusterMetadata {
+    #[pyo3(get)]
+    pub cluster_name: String,
+    #[pyo3(get)]
+    pub nodes: Vec<NodeInfo>,
+}
+
+#[pymethods]
+impl ClusterMetadata {
+    pub fn datacenters(&self) -> Vec<String> {
+        let mut datacenters: Vec<String> = self
+            .nodes
+            .iter()
+            .filter_map(|node| node.datacenter.clone())
+            .collect();
+        datacenters.sort();
+        datacenters.dedup();
+        datacenters
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ClusterMetadata(cluster_name={:?}, nodes={})",
+            self.cluster_name,
+            self.nodes.len()
+        )
+    }
+}
+
+/// Ask a single node for its own Scylla version; `None` when the node can't be reached.
+/// `system.local`'s `release_version` is the Cassandra version Scylla is compatible with.
+async fn fetch_scylla_version(session: &ScyllaSession, node: NodeIdentifier) -> Option<String> {
+    let mut query = ScyllaQuery::new("SELECT version FROM system.versions WHERE key = 'local'");
+    query.set_load_balancing_policy(Some(SingleTargetLoadBalancingPolicy::new(node, None)));
+
+    let result = session.query_unpaged(query, &[]).await.ok()?;
+    let rows = result.into_rows_result().ok()?;
+    rows.maybe_first_row::<(Option<String>,)>()
+        .ok()
+        .flatten()
+        .and_then(|(version,)| version)
+}
+
+pub async fn fetch_cluster_metadata(session: &ScyllaSession) -> ClusterMetadata {
+    let cluster_state = session.get_cluster_state();
+    let nodes = cluster_state.get_nodes_info();
+
+    let versions = join_all(
+        nodes
+            .iter()
+            .map(|node| fetch_scylla_version(session, NodeIdentifier::Node(node.clone()))),
+    )
+    .await;
+
+    let nodes = nodes
+        .iter()
+        .zip(versions)
+        .map(|(node, scylla_version)| NodeInfo {
+            address: node.address.to_string(),
+            datacenter: node.datacenter.clone(),
+            rack: node.rack.clone(),
+            host_id: node.host_id.to_string(),
+            scylla_version,
+            shard_count: node.sharder().map(|sharder| sharder.nr_shards.get()),
+            is_up: node.is_connected(),
+        })
+        .collect();
+
+    ClusterMetadata {
+        cluster_name: cluster_state.cluster_name().to_string(),
+        nodes,
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct ColumnMetadata {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub cql_type: String,
+    #[pyo3(get)]
+    pub kind: String,
+}
+
+#[pymethods]
+impl ColumnMetadata {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ColumnMetadata(name={:?}, cql_type={:?}, kind={:?})",
+            self.name, self.cql_type, self.kind
+        )
+    }
+}
+
+/// A CQL type broken down for programmatic use; `str()` gives its CQL spelling.
+#[pyclass]
+#[derive(Clone)]
+pub struct CqlType {
+    /// `native`, `list`, `set`, `map`, `tuple`, `udt`, `vector` or `unknown`.
+    #[pyo3(get)]
+    pub kind: String,
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub frozen: bool,
+    /// Element types: one for list/set/vector, key and value for map, members for tuple.
+    #[pyo3(get)]
+    pub element_types: Vec<CqlType>,
+    #[pyo3(get)]
+    pub udt_name: Option<String>,
+    #[pyo3(get)]
+    pub udt_keyspace: Option<String>,
+    #[pyo3(get)]
+    pub fields: Vec<(String, CqlType)>,
+    #[pyo3(get)]
+    pub dimensions: Option<u16>,
+}
+
+impl From<&ColumnType<'_>> for CqlType {
+    fn from(typ: &ColumnType<'_>) -> Self {
+        let mut cql_type = CqlType {
+            kind: "unknown".to_string(),
+            name: cql_type_name(typ),
+            frozen: false,
+            element_types: Vec::new(),
+            udt_name: None,
+            udt_keyspace: None,
+            fields: Vec::new(),
+            dimensions: None,
+        };
+
+        match typ {
+            ColumnType::Native(_) => cql_type.kind = "native".to_string(),
+            ColumnType::Collection { frozen, typ } => {
+                cql_type.frozen = *frozen;
+                let (kind, elements) = match typ {
+                    CollectionType::List(elem) => ("list", vec![elem.as_ref()]),
+                    CollectionType::Set(elem) => ("set", vec![elem.as_ref()]),
+                    CollectionType::Map(key, value) => ("map", vec![key.as_ref(), value.as_ref()]),
+                    _ => ("unknown", Vec::new()),
+                };
+                cql_type.kind = kind.to_string();
+                cql_type.element_types = elements.into_iter().map(CqlType::from).collect();
+            }
+            ColumnType::Vector { typ, dimensions } => {
+                cql_type.kind = "vector".to_string();
+                cql_type.element_types = vec![CqlType::from(typ.as_ref())];
+                cql_type.dimensions = Some(*dimensions);
+            }
+            ColumnType::UserDefinedType { frozen, definition } => {
+                cql_type.kind = "udt".to_string();
+                cql_type.frozen = *frozen;
+                cql_type.udt_name = Some(definition.name.to_string());
+                cql_type.udt_keyspace = Some(definition.keyspace.to_string());
+                cql_type.fields = definition
+                    .field_types
+                    .iter()
+                    .map(|(name, typ)| (name.to_string(), CqlType::from(typ)))
+                    .collect();
+            }
+            ColumnType::Tuple(elems) => {
+                cql_type.kind = "tuple".to_string();
+                cql_type.element_types = elems.iter().map(CqlType::from).collect();
+            }
+            _ => {}
+        }
+        cql_type
+    }
+}
+
+#[pymethods]
+impl CqlType {
+    pub fn __str__(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("CqlType({})", self.name)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct TableMetadata {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub partition_key: Vec<String>,
+    #[pyo3(get)]
+    pub clustering_key: Vec<String>,
+    /// Key columns first (in key order), then the remaining columns by name.
+    #[pyo3(get)]
+    pub columns: Vec<ColumnMetadata>,
+    #[pyo3(get)]
+    pub partitioner: Option<String>,
+    #[pyo3(get)]
+    pub indexes: Vec<IndexMetadata>,
+}
+
+#[pymethods]
+impl TableMetadata {
+    pub fn column(&self, name: &str) -> Option<ColumnMetadata> {
+        self.columns
+            .iter()
+            .find(|column| column.name == name)
+            .cloned()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "TableMetadata(name={:?}, partition_key={:?}, clustering_key={:?}, columns={})",
+            self.name,
+            self.partition_key,
+            self.clustering_key,
+            self.columns.len()
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct IndexMetadata {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub table: String,
+    /// `COMPOSITES`, `KEYS` or `CUSTOM`, as reported by `system_schema.indexes`.
+    #[pyo3(get)]
+    pub kind: String,
+    /// Indexed column or expression, from the `target` option.
+    #[pyo3(get)]
+    pub target: Option<String>,
+    #[pyo3(get)]
+    pub options: HashMap<String, String>,
+}
+
+#[pymethods]
+impl IndexMetadata {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "IndexMetadata(name={:?}, table={:?}, target={:?})",
+            self.name, self.table, self.target
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct MaterializedViewMetadata {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub base_table: String,
+    #[pyo3(get)]
+    pub partition_key: Vec<String>,
+    #[pyo3(get)]
+    pub clustering_key: Vec<String>,
+    /// Key columns first (in key order), then the remaining included columns by name.
+    #[pyo3(get)]
+    pub columns: Vec<ColumnMetadata>,
+}
+
+#[pymethods]
+impl MaterializedViewMetadata {
+    pub fn included_columns(&self) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|column| column.name.clone())
+            .collect()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "MaterializedViewMetadata(name={:?}, base_table={:?}, columns={})",
+            self.name,
+            self.base_table,
+            self.columns.len()
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct UserTypeMetadata {
+    #[pyo3(get)]
+    pub name: String,
+    /// `(field name, CQL type)` pairs in declaration order.
+    #[pyo3(get)]
+    pub fields: Vec<(String, String)>,
+}
+
+#[pymethods]
+impl UserTypeMetadata {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "UserTypeMetadata(name={:?}, fields={})",
+            self.name,
+            self.fields.len()
+        )
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct KeyspaceMetadata {
+    #[pyo3(get)]
+    pub name: String,
+    #[pyo3(get)]
+    pub strategy: String,
+    /// Strategy options, e.g. `{"replication_factor": "3"}` or per-datacenter factors.
+    #[pyo3(get)]
+    pub replication: HashMap<String, String>,
+    #[pyo3(get)]
+    pub durable_writes: bool,
+    #[pyo3(get)]
+    pub tables: HashMap<String, TableMetadata>,
+    #[pyo3(get)]
+    pub views: HashMap<String, MaterializedViewMetadata>,
+    #[pyo3(get)]
+    pub user_types: HashMap<String, UserTypeMetadata>,
+}
+
+#[pymethods]
+impl KeyspaceMetadata {
+    pub fn table(&self, name: &str) -> Option<TableMetadata> {
+        self.tables.get(name).cloned()
+    }
+
+    pub fn view(&self, name: &str) -> Option<MaterializedViewMetadata> {
+        self.views.get(name).cloned()
+    }
+
+    /// Materialized views built on top of the given base table.
+    pub fn views_of(&self, table: &str) -> Vec<MaterializedViewMetadata> {
+        let mut views: Vec<MaterializedViewMetadata> = self
+            .views
+            .values()
+            .filter(|view| view.base_table == table)
+            .cloned()
+            .collect();
+        views.sort_by(|a, b| a.name.cmp(&b.name));
+        views
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "KeyspaceMetadata(name={:?}, strategy={:?}, tables={})",
+            self.name,
+            self.strategy,
+            self.tables.len()
+        )
+    }
+}
+
+/// Render a column type the way it is written in CQL DDL.
+pub fn cql_type_name(typ: &ColumnType) -> String {
+    let frozen = |frozen: bool, name: String| {
+        if frozen {
+            format!("frozen<{}>", name)
+        } else {
+            name
+        }
+    };
+
+    match typ {
+        ColumnType::Native(native) => native_type_name(native).to_string(),
+        ColumnType::Collection { frozen: f, typ } => {
+            let name = match typ {
+                CollectionType::List(elem) => format!("list<{}>", cql_type_name(elem)),
+                CollectionType::Set(elem) => format!("set<{}>", cql_type_name(elem)),
+                CollectionType::Map(key, value) => {
+                    format!("map<{}, {}>", cql_type_name(key), cql_type_name(value))
+                }
+                other => format!("{:?}", other),
+            };
+            frozen(*f, name)
+        }
+        ColumnType::Vector { typ, dimensions } => {
+            format!("vector<{}, {}>", cql_type_name(typ), dimensions)
+        }
+        ColumnType::UserDefinedType {
+            frozen: f,
+            definition,
+        } => frozen(*f, definition.name.to_string()),
+        ColumnType::Tuple(elems) => format!(
+            "tuple<{}>",
+            elems
+                .iter()
+                .map(cql_type_name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        other => format!("{:?}", other),
+    }
+}
+
+fn native_type_name(native: &NativeType) -> &'static str {
+    match native {
+        NativeType::Ascii => "ascii",
+        NativeType::Boolean => "boolean",
+        NativeType::Blob => "blob",
+        NativeType::Counter => "counter",
+        NativeType::Date => "date",
+        NativeType::Decimal => "decimal",
+        NativeType::Double => "double",
+        NativeType::Duration => "duration",
+        NativeType::Float => "float",
+        NativeType::Int => "int",
+        NativeType::BigInt => "bigint",
+        NativeType::Text => "text",
+        NativeType::Timestamp => "timestamp",
+        NativeType::Inet => "inet",
+        NativeType::SmallInt => "smallint",
+        NativeType::TinyInt => "tinyint",
+        NativeType::Time => "time",
+        NativeType::Timeuuid => "timeuuid",
+        NativeType::Uuid => "uuid",
+        NativeType::Varint => "varint",
+        _ => "unknown",
+    }
+}
+
+fn column_kind_name(kind: &ColumnKind) -> &'static str {
+    match kind {
+        ColumnKind::PartitionKey => "partition_key",
+        ColumnKind::Clustering => "clustering",
+        ColumnKind::Static => "static",
+        ColumnKind::Regular => "regular",
+        _ => "unknown",
+    }
+}
+
+fn strategy_options(strategy: &Strategy) -> (String, HashMap<String, String>) {
+    match strategy {
+        Strategy::SimpleStrategy { replication_factor } => (
+            "SimpleStrategy".to_string(),
+            HashMap::from([(
+                "replication_factor".to_string(),
+                replication_factor.to_string(),
+            )]),
+        ),
+        Strategy::NetworkTopologyStrategy {
+            datacenter_repfactors,
+        } => (
+            "NetworkTopologyStrategy".to_string(),
+            datacenter_repfactors
+                .iter()
+                .map(|(datacenter, factor)| (datacenter.clone(), factor.to_string()))
+                .collect(),
+        ),
+        Strategy::LocalStrategy => ("LocalStrategy".to_string(), HashMap::new()),
+        Strategy::Other { name, data } => (name.clone(), data.clone()),
+        _ => ("unknown".to_string(), HashMap::new()),
+    }
+}
+
+fn columns_metadata(table: &Table) -> Vec<ColumnMetadata> {
+    let key_position = |column: &str| {
+        table
+            .partition_key
+            .iter()
+            .chain(table.clustering_key.iter())
+            .position(|key| key == column)
+            .unwrap_or(usize::MAX)
+    };
+
+    let mut columns: Vec<ColumnMetadata> = table
+        .columns
+        .iter()
+        .map(|(column_name, column)| ColumnMetadata {
+            name: column_name.clone(),
+            cql_type: cql_type_name(&column.typ),
+            kind: column_kind_name(&column.kind).to_string(),
+        })
+        .collect();
+    columns.sort_by(|a, b| (key_position(&a.name), &a.name).cmp(&(key_position(&b.name), &b.name)));
+    columns
+}
+
+fn table_metadata(name: &str, table: &Table, indexes: Vec<IndexMetadata>) -> TableMetadata {
+    TableMetadata {
+        name: name.to_string(),
+        partition_key: table.partition_key.clone(),
+        clustering_key: table.clustering_key.clone(),
+        columns: columns_metadata(table),
+        partitioner: table.partitioner.clone(),
+        indexes,
+    }
+}
+
+fn view_metadata(name: &str, view: &MaterializedView) -> MaterializedViewMetadata {
+    MaterializedViewMetadata {
+        name: name.to_string(),
+        base_table: view.base_table_name.clone(),
+        partition_key: view.view_metadata.partition_key.clone(),
+        clustering_key: view.view_metadata.clustering_key.clone(),
+        columns: columns_metadata(&view.view_metadata),
+    }
+}
+
+fn keyspace_metadata(
+    name: &str,
+    keyspace: &Keyspace,
+    indexes: &mut HashMap<(String, String), Vec<IndexMetadata>>,
+) -> KeyspaceMetadata {
+    let (strategy, replication) = strategy_options(&keyspace.strategy);
+
+    KeyspaceMetadata {
+        name: name.to_string(),
+        strategy,
+        replication,
+        durable_writes: keyspace.durable_writes,
+        tables: keyspace
+            .tables
+            .iter()
+            .map(|(table_name, table)| {
+                let table_indexes = indexes
+                    .remove(&(name.to_string(), table_name.clone()))
+                    .unwrap_or_default();
+                (
+                    table_name.clone(),
+                    table_metadata(table_name, table, table_indexes),
+                )
+            })
+            .collect(),
+        views: keyspace
+            .views
+            .iter()
+            .map(|(view_name, view)| (view_name.clone(), view_metadata(view_name, view)))
+            .collect(),
+        user_types: keyspace
+            .user_defined_types
+            .iter()
+            .map(|(type_name, udt)| {
+                let fields = udt
+                    .field_types
+                    .iter()
+                    .map(|(field, typ)| (field.to_string(), cql_type_name(typ)))
+                    .collect();
+                (
+                    type_name.clone(),
+                    UserTypeMetadata {
+                        name: type_name.clone(),
+                        fields,
+                    },
+                )
+            })
+            .collect(),
+    }
+}
+
+type IndexRow = (
+    String,
+    String,
+    String,
+    String,
+    Option<HashMap<String, String>>,
+);
+
+/// Secondary indexes keyed by `(keyspace, table)`; the driver doesn't track these itself.
+async fn fetch_indexes(
+    session: &ScyllaSession,
+) -> PyResult<HashMap<(String, String), Vec<IndexMetadata>>> {
+    let result = session
+        .query_unpaged(
+            "SELECT keyspace_name, table_name, index_name, kind, options \
+             FROM system_schema.indexes",
+            &[],
+        )
+        .await
+        .map_err(query_error_to_py)?;
+    let rows_result = result.into_rows_result().map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+            "Failed to get rows result: {}",
+            e
+        ))
+    })?;
+
+    let rows = rows_result.rows::<IndexRow>().map_err(|e| {
+        PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+            "Row deserialization error: {}",
+            e
+        ))
+    })?;
+
+    let mut indexes: HashMap<(String, String), Vec<IndexMetadata>> = HashMap::new();
+    for row in rows {
+        let (keyspace, table, name, kind, options) = row.map_err(deserialization_error_to_py)?;
+        let options = options.unwrap_or_default();
+        indexes
+            .entry((keyspace, table.clone()))
+            .or_default()
+            .push(IndexMetadata {
+                name,
+                table,
+                kind,
+                target: options.get("target").cloned(),
+                options,
+            });
+    }
+    for table_indexes in indexes.values_mut() {
+        table_indexes.sort_by(|a, b| a.name.cmp(&b.name));
+    }
+
+    Ok(indexes)
+}
+
+/// Refresh the driver's schema view so recent DDL is visible, then snapshot the keyspaces.
+pub async fn fetch_keyspaces(
+    session: &ScyllaSession,
+) -> PyResult<HashMap<String, KeyspaceMetadata>> {
+    session
+        .refresh_metadata()
+        .await
+        .map_err(metadata_error_to_py)?;
+    let mut indexes = fetch_indexes(session).await?;
+
+    Ok(session
+        .get_cluster_state()
+        .keyspaces_iter()
+        .map(|(name, keyspace)| {
+            (
+                name.to_string(),
+                keyspace_metadata(name, keyspace, &mut indexes),
+            )
+        })
+        .collect())
+}
+
+/// `(start, end, replicas)` for every range of the token ring, with `start` exclusive and
+/// `end` inclusive. The range wrapping around the ring is split in two at the minimum
+/// token, so ranges never wrap and can be scanned with `token(pk) > start AND <= end`.
+pub fn token_ranges(
+    cluster_state: &ClusterState,
+    keyspace: &str,
+    table: Option<&str>,
+) -> PyResult<Vec<(i64, i64, Vec<String>)>> {
+    if cluster_state.get_keyspace(keyspace).is_none() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unknown keyspace '{}'",
+            keyspace
+        )));
+    }
+
+    let replicas = |token: i64| -> Vec<String> {
+        cluster_state
+            .get_token_endpoints(keyspace, table.unwrap_or(""), Token::new(token))
+            .into_iter()
+            .map(|(node, _)| node.address.to_string())
+            .collect()
+    };
+
+    let tokens: Vec<i64> = cluster_state
+        .replica_locator()
+        .ring()
+        .iter()
+        .map(|(token, _)| token.value())
+        .collect();
+    let (Some(&first), Some(&last)) = (tokens.first(), tokens.last()) else {
+        return Ok(Vec::new());
+    };
+
+    let mut ranges = Vec::with_capacity(tokens.len() + 1);
+    ranges.push((i64::MIN, first, replicas(first)));
+    for pair in tokens.windows(2) {
+        ranges.push((pair[0], pair[1], replicas(pair[1])));
+    }
+    if last < i64::MAX {
+        ranges.push((last, i64::MAX, replicas(first)));
+    }
+
+    Ok(ranges)
+}
@@ -0,0 +1,54 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla::client::session::Session as ScyllaSession;
+
+/// Point-in-time copy of the driver's request counters and latency histogram.
+/// Latencies are `None` until the first request completes.
+#[cfg(feature = "metrics")]
+pub fn metrics_snapshot<'py>(
+    py: Python<'py>,
+    session: &ScyllaSession,
+) -> PyResult<Bound<'py, PyDict>> {
+    let metrics = session.get_metrics();
+    let dict = PyDict::new(py);
+
+    dict.set_item(
+        "queries",
+        metrics.get_requests_unpaged_num() + metrics.get_requests_manually_paged_num(),
+    )?;
+    dict.set_item(
+        "queries_iter",
+        metrics.get_requests_automatically_paged_num(),
+    )?;
+    dict.set_item(
+        "errors",
+        metrics.get_errors_unpaged_num() + metrics.get_errors_manually_paged_num(),
+    )?;
+    dict.set_item("errors_iter", metrics.get_errors_automatically_paged_num())?;
+    dict.set_item("retries", metrics.get_retries_num())?;
+    dict.set_item("request_timeouts", metrics.get_request_timeouts())?;
+    dict.set_item("total_connections", metrics.get_total_connections())?;
+    dict.set_item("connection_timeouts", metrics.get_connection_timeouts())?;
+    dict.set_item("mean_rate", metrics.get_mean_rate())?;
+
+    dict.set_item("latency_avg_ms", metrics.get_latency_avg_ms().ok())?;
+    for (key, percentile) in [
+        ("latency_p50_ms", 50.0),
+        ("latency_p95_ms", 95.0),
+        ("latency_p99_ms", 99.0),
+    ] {
+        dict.set_item(key, metrics.get_latency_percentile_ms(percentile).ok())?;
+    }
+
+    Ok(dict)
+}
+
+#[cfg(not(feature = "metrics"))]
+pub fn metrics_snapshot<'py>(
+    _py: Python<'py>,
+    _session: &ScyllaSession,
+) -> PyResult<Bound<'py, PyDict>> {
+    Err(PyErr::new::<crate::error::ScyllaError, _>(
+        "rsylla was built without the 'metrics' feature",
+    ))
+}
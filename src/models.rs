@@ -0,0 +1,164 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+use std::collections::HashMap;
+
+/// How rows are turned into instances of a user-supplied model class.
+pub enum ModelSpec {
+    /// A dataclass: columns are matched to fields and coerced to plain class annotations.
+    Dataclass {
+        cls: Py<PyAny>,
+        fields: HashMap<String, Option<Py<PyType>>>,
+    },
+    /// A pydantic model, which validates and coerces on its own.
+    Pydantic { cls: Py<PyAny> },
+}
+
+/// The class a field annotation coerces to: `int` for `int`, `int | None` or `Optional[int]`,
+/// nothing for generics and multi-type unions.
+fn coercion_target<'py>(
+    py: Python<'py>,
+    annotation: &Bound<'py, PyAny>,
+) -> PyResult<Option<Py<PyType>>> {
+    if let Ok(cls) = annotation.cast::<PyType>() {
+        if annotation.getattr("__module__")?.extract::<String>()? != "typing" {
+            return Ok(Some(cls.clone().unbind()));
+        }
+    }
+
+    let typing = py.import("typing")?;
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    let is_union = origin.is(&typing.getattr("Union")?)
+        || origin.is(&py.import("types")?.getattr("UnionType")?);
+    if !is_union {
+        return Ok(None);
+    }
+
+    let none_type = py.None().bind(py).get_type();
+    let args: Vec<Bound<'py, PyAny>> = typing.call_method1("get_args", (annotation,))?.extract()?;
+    let mut non_none = args.iter().filter(|arg| !arg.is(&none_type));
+    match (non_none.next(), non_none.next()) {
+        (Some(arg), None) => Ok(arg.cast::<PyType>().ok().map(|cls| cls.clone().unbind())),
+        _ => Ok(None),
+    }
+}
+
+impl ModelSpec {
+    pub fn new(py: Python, cls: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if cls.hasattr("model_validate")? {
+            return Ok(ModelSpec::Pydantic {
+                cls: cls.clone().unbind(),
+            });
+        }
+
+        let dataclasses = py.import("dataclasses")?;
+        if !dataclasses
+            .call_method1("is_dataclass", (cls,))?
+            .is_truthy()?
+        {
+            return Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+                "Model class must be a dataclass or a pydantic model",
+            ));
+        }
+
+        let hints = py
+            .import("typing")?
+            .call_method1("get_type_hints", (cls,))?;
+        let mut fields = HashMap::new();
+        for field in dataclasses.call_method1("fields", (cls,))?.try_iter()? {
+            let name: String = field?.getattr("name")?.extract()?;
+            let target = match hints.get_item(&name) {
+                Ok(annotation) => coercion_target(py, &annotation)?,
+                Err(_) => None,
+            };
+            fields.insert(name, target);
+        }
+
+        Ok(ModelSpec::Dataclass {
+            cls: cls.clone().unbind(),
+            fields,
+        })
+    }
+
+    /// Build one instance from a row; conversion failures name the offending column.
+    pub fn build(
+        &self,
+        py: Python,
+        names: &[String],
+        values: Vec<Py<PyAny>>,
+    ) -> PyResult<Py<PyAny>> {
+        match self {
+            ModelSpec::Dataclass { cls, fields } => {
+                let kwargs = PyDict::new(py);
+                for (name, value) in names.iter().zip(values) {
+                    let Some(target) = fields.get(name) else {
+                        continue;
+                    };
+                    let value = value.into_bound(py);
+                    let value = match target {
+                        Some(target)
+                            if !value.is_none() && !value.is_instance(target.bind(py))? =>
+                        {
+                            target
+                                .bind(py)
+                                .call1((&value,))
+                                .map_err(|err| coercion_error(py, name, &value, target, err))?
+                        }
+                        _ => value,
+                    };
+                    kwargs.set_item(name, value)?;
+                }
+                cls.call(py, (), Some(&kwargs))
+            }
+            ModelSpec::Pydantic { cls } => {
+                let data = PyDict::new(py);
+                for (name, value) in names.iter().zip(values) {
+                    data.set_item(name, value)?;
+                }
+                cls.call_method1(py, "model_validate", (data,))
+                    .map_err(|err| pydantic_error(py, err))
+            }
+        }
+    }
+}
+
+fn coercion_error(
+    py: Python,
+    column: &str,
+    value: &Bound<'_, PyAny>,
+    target: &Py<PyType>,
+    err: PyErr,
+) -> PyErr {
+    let coerce_err = PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+        "Column '{}': cannot convert {} to {}: {}",
+        column,
+        value.repr().map(|r| r.to_string()).unwrap_or_default(),
+        target
+            .bind(py)
+            .name()
+            .map(|n| n.to_string())
+            .unwrap_or_default(),
+        err.value(py)
+    ));
+    coerce_err.set_cause(py, Some(err));
+    coerce_err
+}
+
+/// Re-raise a pydantic `ValidationError` as a `ValueError` naming the first failing column.
+fn pydantic_error(py: Python, err: PyErr) -> PyErr {
+    let column = err
+        .value(py)
+        .call_method0("errors")
+        .and_then(|errors| errors.get_item(0)?.get_item("loc")?.get_item(0))
+        .and_then(|loc| loc.str().map(|loc| loc.to_string()));
+    let Ok(column) = column else {
+        return err;
+    };
+
+    let coerce_err = PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+        "Column '{}': {}",
+        column,
+        err.value(py)
+    ));
+    coerce_err.set_cause(py, Some(err));
+    coerce_err
+}
@@ -0,0 +1,389 @@
+use pyo3::prelude::*;
+use scylla::errors::{RequestAttemptError, RequestError};
+use scylla::observability::history::{AttemptId, HistoryListener, RequestId, SpeculativeId};
+use scylla::policies::retry::RetryDecision;
+use scylla::response::query_result::QueryResult as ScyllaQueryResult;
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use crate::telemetry::RequestSpan;
+
+/// What a request hook is told about a request; `elapsed_ms`, `coordinator` and `error`
+/// are only set once it has completed.
+#[pyclass]
+pub struct RequestEvent {
+    #[pyo3(get)]
+    pub operation: String,
+    #[pyo3(get)]
+    pub statement: String,
+    #[pyo3(get)]
+    pub elapsed_ms: Option<f64>,
+    #[pyo3(get)]
+    pub coordinator: Option<String>,
+    #[pyo3(get)]
+    pub error: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl RequestEvent {
+    fn __repr__(&self) -> String {
+        format!(
+            "RequestEvent(operation={}, elapsed_ms={:?}, failed={})",
+            self.operation,
+            self.elapsed_ms,
+            self.error.is_some()
+        )
+    }
+}
+
+#[derive(Clone)]
+struct RequestHook {
+    before: Option<Arc<Py<PyAny>>>,
+    after: Option<Arc<Py<PyAny>>>,
+}
+
+/// Python callables invoked around every execute, prepare and batch call.
+#[derive(Clone, Default)]
+pub struct RequestHooks {
+    hooks: Vec<RequestHook>,
+}
+
+impl RequestHooks {
+    pub fn add(&mut self, before: Option<Py<PyAny>>, after: Option<Py<PyAny>>) {
+        self.hooks.push(RequestHook {
+            before: before.map(Arc::new),
+            after: after.map(Arc::new),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Run the `before` hooks; an exception aborts the request before it is sent.
+    fn before(&self, py: Python, operation: &str, statement: &str) -> PyResult<()> {
+        for before in self.hooks.iter().filter_map(|hook| hook.before.as_ref()) {
+            let event = RequestEvent {
+                operation: operation.to_string(),
+                statement: statement.to_string(),
+                elapsed_ms: None,
+                coordinator: None,
+                error: None,
+            };
+            before.call1(py, (event,))?;
+        }
+        Ok(())
+    }
+
+    /// Run the `after` hooks; their exceptions are reported as unraisable so they
+    /// never replace the request's own outcome.
+    fn after(&self, py: Python, event: RequestEvent) -> PyResult<()> {
+        let event = Py::new(py, event)?;
+        for after in self.hooks.iter().filter_map(|hook| hook.after.as_ref()) {
+            if let Err(err) = after.call1(py, (event.clone_ref(py),)) {
+                err.write_unraisable(py, Some(after.bind(py)));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Requests dropped before completing because the Python task awaiting them was
+/// cancelled. The driver discards their responses when they arrive.
+#[derive(Clone, Default)]
+pub struct OrphanedRequests(Arc<AtomicU64>);
+
+impl OrphanedRequests {
+    pub fn count(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Requests a session has started and not finished, and, when tracked, the request
+/// attempts awaiting a response from each node.
+#[derive(Clone, Default)]
+pub struct RequestLoad {
+    requests: Arc<AtomicUsize>,
+    nodes: Option<Arc<Mutex<HashMap<SocketAddr, usize>>>>,
+}
+
+impl RequestLoad {
+    pub fn new(track_nodes: bool) -> Self {
+        RequestLoad {
+            requests: Arc::default(),
+            nodes: track_nodes.then(Arc::default),
+        }
+    }
+
+    pub fn in_flight(&self) -> usize {
+        self.requests.load(Ordering::Relaxed)
+    }
+
+    /// Attempts in flight per node address; empty unless nodes are tracked.
+    pub fn node_attempts(&self) -> HashMap<String, usize> {
+        let Some(nodes) = &self.nodes else {
+            return HashMap::new();
+        };
+        nodes
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(address, attempts)| (address.to_string(), *attempts))
+            .collect()
+    }
+}
+
+/// History listener counting one request's attempts, overall and, when the session
+/// tracks nodes, against the node they were sent to, forwarding every event to the span's
+/// listener when there is one. Attempts still open when the request is dropped, e.g. on
+/// cancellation, are released then.
+#[derive(Debug)]
+struct AttemptListener {
+    span: Option<Arc<dyn HistoryListener>>,
+    nodes: Option<Arc<Mutex<HashMap<SocketAddr, usize>>>>,
+    open: Mutex<HashMap<AttemptId, SocketAddr>>,
+    started: AtomicUsize,
+}
+
+impl AttemptListener {
+    fn close(&self, attempt_id: AttemptId) {
+        let Some(nodes) = &self.nodes else {
+            return;
+        };
+        if let Some(address) = self.open.lock().unwrap().remove(&attempt_id) {
+            release(nodes, address);
+        }
+    }
+}
+
+fn release(nodes: &Mutex<HashMap<SocketAddr, usize>>, address: SocketAddr) {
+    let mut nodes = nodes.lock().unwrap();
+    if let Some(count) = nodes.get_mut(&address) {
+        *count = count.saturating_sub(1);
+        if *count == 0 {
+            nodes.remove(&address);
+        }
+    }
+}
+
+impl HistoryListener for AttemptListener {
+    fn log_request_start(&self) -> RequestId {
+        self.span
+            .as_ref()
+            .map_or(RequestId(0), |span| span.log_request_start())
+    }
+
+    fn log_request_success(&self, request_id: RequestId) {
+        if let Some(span) = &self.span {
+            span.log_request_success(request_id);
+        }
+    }
+
+    fn log_request_error(&self, request_id: RequestId, error: &RequestError) {
+        if let Some(span) = &self.span {
+            span.log_request_error(request_id, error);
+        }
+    }
+
+    fn log_new_speculative_fiber(&self, request_id: RequestId) -> SpeculativeId {
+        self.span.as_ref().map_or(SpeculativeId(0), |span| {
+            span.log_new_speculative_fiber(request_id)
+        })
+    }
+
+    fn log_attempt_start(
+        &self,
+        request_id: RequestId,
+        speculative_id: Option<SpeculativeId>,
+        node_addr: SocketAddr,
+    ) -> AttemptId {
+        let started = self.started.fetch_add(1, Ordering::Relaxed);
+        let attempt_id = match &self.span {
+            Some(span) => span.log_attempt_start(request_id, speculative_id, node_addr),
+            None => AttemptId(started),
+        };
+        if let Some(nodes) = &self.nodes {
+            *nodes.lock().unwrap().entry(node_addr).or_default() += 1;
+            self.open.lock().unwrap().insert(attempt_id, node_addr);
+        }
+        attempt_id
+    }
+
+    fn log_attempt_success(&self, attempt_id: AttemptId) {
+        self.close(attempt_id);
+        if let Some(span) = &self.span {
+            span.log_attempt_success(attempt_id);
+        }
+    }
+
+    fn log_attempt_error(
+        &self,
+        attempt_id: AttemptId,
+        error: &RequestAttemptError,
+        retry_decision: &RetryDecision,
+    ) {
+        self.close(attempt_id);
+        if let Some(span) = &self.span {
+            span.log_attempt_error(attempt_id, error, retry_decision);
+        }
+    }
+}
+
+impl Drop for AttemptListener {
+    fn drop(&mut self) {
+        if let Some(nodes) = &self.nodes {
+            for (_, address) in self.open.get_mut().unwrap().drain() {
+                release(nodes, address);
+            }
+        }
+    }
+}
+
+/// How a completed request was carried out, as seen by the driver.
+pub struct RequestStats {
+    pub latency: Duration,
+    /// Attempts made, counting retries and speculative executions; `None` for requests
+    /// sent without a history listener.
+    pub attempts: Option<usize>,
+    pub coordinator: String,
+    pub shard: Option<u32>,
+    pub compression: Option<&'static str>,
+}
+
+/// Instrumentation attached to one request: its tracing span and the session's hooks.
+/// Dropping it unfinished counts the request as orphaned.
+pub struct InFlightRequest {
+    span: Option<RequestSpan>,
+    hooks: Option<Arc<RequestHooks>>,
+    orphans: OrphanedRequests,
+    load: RequestLoad,
+    attempts: OnceLock<Arc<AttemptListener>>,
+    compression: Option<&'static str>,
+    finished: bool,
+    operation: &'static str,
+    statement: String,
+    started_at: Instant,
+}
+
+impl InFlightRequest {
+    pub fn start(
+        py: Python,
+        span: Option<RequestSpan>,
+        hooks: &Arc<RequestHooks>,
+        orphans: &OrphanedRequests,
+        load: &RequestLoad,
+        operation: &'static str,
+        statement: &str,
+    ) -> PyResult<Self> {
+        let hooks = (!hooks.is_empty()).then(|| hooks.clone());
+        if let Some(hooks) = &hooks {
+            hooks.before(py, operation, statement)?;
+        }
+        load.requests.fetch_add(1, Ordering::Relaxed);
+
+        Ok(InFlightRequest {
+            span,
+            statement: if hooks.is_some() {
+                statement.to_string()
+            } else {
+                String::new()
+            },
+            hooks,
+            orphans: orphans.clone(),
+            load: load.clone(),
+            attempts: OnceLock::new(),
+            compression: None,
+            finished: false,
+            operation,
+            started_at: Instant::now(),
+        })
+    }
+
+    /// Record the compression the session's connections use, for the request's stats.
+    pub fn with_compression(mut self, compression: Option<&'static str>) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Listener to attach to the statement, counting its attempts for the stats and the
+    /// session's per-node load, and recording the retry history for the span.
+    pub fn listener(&self) -> Arc<dyn HistoryListener> {
+        let listener = self.attempts.get_or_init(|| {
+            Arc::new(AttemptListener {
+                span: self.span.as_ref().map(RequestSpan::listener),
+                nodes: self.load.nodes.clone(),
+                open: Mutex::default(),
+                started: AtomicUsize::new(0),
+            })
+        });
+        listener.clone()
+    }
+
+    /// Override the attempt count for requests the driver doesn't record history for.
+    pub fn set_attempts(&mut self, attempts: usize) {
+        if let Some(span) = &mut self.span {
+            span.set_attempts(attempts);
+        }
+    }
+
+    pub fn finish(mut self, coordinator: Option<String>, error: Option<&PyErr>) {
+        self.finished = true;
+        let elapsed = self.started_at.elapsed();
+        if let Some(span) = self.span.take() {
+            span.finish(coordinator.clone(), error);
+        }
+
+        if let Some(hooks) = self.hooks.take() {
+            let _ = Python::try_attach(|py| {
+                hooks.after(
+                    py,
+                    RequestEvent {
+                        operation: self.operation.to_string(),
+                        statement: std::mem::take(&mut self.statement),
+                        elapsed_ms: Some(elapsed.as_secs_f64() * 1000.0),
+                        coordinator,
+                        error: error.map(|err| err.value(py).clone().into_any().unbind()),
+                    },
+                )
+            });
+        }
+    }
+
+    /// Finish with a query result's coordinator or its error, returning the stats of a
+    /// successful request.
+    pub fn finish_query(self, result: &PyResult<ScyllaQueryResult>) -> Option<RequestStats> {
+        match result {
+            Ok(result) => {
+                let coordinator = result.request_coordinator();
+                let stats = RequestStats {
+                    latency: self.started_at.elapsed(),
+                    attempts: self
+                        .attempts
+                        .get()
+                        .map(|listener| listener.started.load(Ordering::Relaxed)),
+                    coordinator: coordinator.node().address.to_string(),
+                    shard: coordinator.shard(),
+                    compression: self.compression,
+                };
+                self.finish(Some(stats.coordinator.clone()), None);
+                Some(stats)
+            }
+            Err(err) => {
+                self.finish(None, Some(err));
+                None
+            }
+        }
+    }
+}
+
+impl Drop for InFlightRequest {
+    fn drop(&mut self) {
+        self.load.requests.fetch_sub(1, Ordering::Relaxed);
+        if !self.finished {
+            self.orphans.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
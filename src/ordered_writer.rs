@@ -0,0 +1,143 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3_async_runtimes::tokio::get_runtime;
+use scylla::client::session::Session as ScyllaSession;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc::error::TryRecvError;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+use tokio::sync::{oneshot, Semaphore};
+
+use crate::error::query_error_to_py;
+use crate::query::Statement;
+use crate::rate_limit::RequestLimiter;
+use crate::result::QueryResult;
+use crate::runtime::future_into_py;
+use crate::types::{py_dict_to_serialized_values, SerializableValue};
+
+struct Write {
+    statement: Statement,
+    values: HashMap<String, SerializableValue>,
+    done: oneshot::Sender<PyResult<QueryResult>>,
+}
+
+type Queues = Arc<Mutex<HashMap<String, UnboundedSender<Write>>>>;
+
+/// Applies writes sharing a key strictly in submission order, while writes for
+/// different keys run in parallel up to a global concurrency limit.
+#[pyclass]
+pub struct OrderedWriter {
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    queues: Queues,
+    permits: Arc<Semaphore>,
+}
+
+impl OrderedWriter {
+    pub fn new(
+        session: Arc<ScyllaSession>,
+        limiter: Arc<RequestLimiter>,
+        concurrency: usize,
+    ) -> Self {
+        OrderedWriter {
+            session,
+            limiter,
+            queues: Arc::new(Mutex::new(HashMap::new())),
+            permits: Arc::new(Semaphore::new(concurrency)),
+        }
+    }
+}
+
+async fn drain_queue(
+    key: String,
+    mut receiver: UnboundedReceiver<Write>,
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    queues: Queues,
+    permits: Arc<Semaphore>,
+) {
+    loop {
+        let write = match receiver.try_recv() {
+            Ok(write) => write,
+            Err(TryRecvError::Empty) => {
+                // Submitters send while holding the map lock, so once the queue is seen empty
+                // under that lock it is safe to retire it.
+                let mut queues = queues.lock().unwrap();
+                match receiver.try_recv() {
+                    Ok(write) => write,
+                    Err(_) => {
+                        queues.remove(&key);
+                        return;
+                    }
+                }
+            }
+            Err(TryRecvError::Disconnected) => return,
+        };
+
+        let _permit = permits.acquire().await;
+        let _slot = limiter.acquire().await;
+        let result = write
+            .statement
+            .execute_unpaged(&session, write.values)
+            .await
+            .map(QueryResult::new)
+            .map_err(query_error_to_py);
+        let _ = write.done.send(result);
+    }
+}
+
+#[pymethods]
+impl OrderedWriter {
+    #[pyo3(signature = (key, statement, values=None))]
+    pub fn submit<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'_, PyAny>,
+        statement: &Bound<'_, PyAny>,
+        values: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let key = key.repr()?.to_string();
+        let statement = Statement::extract(statement)?;
+        let values = py_dict_to_serialized_values(values)?;
+
+        let (done, result) = oneshot::channel();
+        let write = Write {
+            statement,
+            values,
+            done,
+        };
+
+        {
+            let mut queues = self.queues.lock().unwrap();
+            match queues.get(&key) {
+                Some(sender) => {
+                    // The queue is only retired under this lock, so the receiver is still alive
+                    let _ = sender.send(write);
+                }
+                None => {
+                    let (sender, receiver) = unbounded_channel();
+                    let _ = sender.send(write);
+                    queues.insert(key.clone(), sender);
+                    get_runtime().spawn(drain_queue(
+                        key,
+                        receiver,
+                        self.session.clone(),
+                        self.limiter.clone(),
+                        self.queues.clone(),
+                        self.permits.clone(),
+                    ));
+                }
+            }
+        }
+
+        future_into_py(py, async move {
+            result.await.map_err(|_| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>("Ordered write was dropped")
+            })?
+        })
+    }
+
+    pub fn pending_keys(&self) -> usize {
+        self.queues.lock().unwrap().len()
+    }
+}
@@ -0,0 +1,441 @@
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use pyo3_async_runtimes::tokio::get_runtime;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::errors::ExecutionError;
+use scylla::response::query_result::QueryResult as ScyllaQueryResult;
+use scylla::response::{PagingState, PagingStateResponse};
+use scylla::value::Row as ScyllaRow;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex};
+use tokio::task::JoinHandle;
+
+use crate::error::query_error_to_py;
+use crate::query::Statement;
+use crate::rate_limit::RequestLimiter;
+use crate::result::{QueryResult, Row, RowFactory};
+use crate::runtime::future_into_py;
+use crate::types::SerializableValue;
+
+type PageResponse = Result<(ScyllaQueryResult, PagingStateResponse), ExecutionError>;
+
+/// A page fetched ahead of the consumer, with the paging state of the page after it.
+type FetchedPage = PyResult<(PageRows, Option<PagingState>)>;
+
+/// Paging state to resume from, `None` once the last page has been fetched. Held apart
+/// from the iteration lock so it can be read while a page is in flight.
+type Checkpoint = Arc<std::sync::Mutex<Option<PagingState>>>;
+
+fn checkpoint_bytes<'py>(py: Python<'py>, checkpoint: &Checkpoint) -> Option<Bound<'py, PyBytes>> {
+    checkpoint
+        .lock()
+        .unwrap()
+        .as_ref()
+        .and_then(|paging_state| paging_state.as_bytes_slice())
+        .map(|bytes| PyBytes::new(py, bytes))
+}
+
+#[derive(Default)]
+struct PageCounters {
+    fetched: AtomicU64,
+    consumed: AtomicU64,
+}
+
+fn next_paging_state(response: PagingStateResponse) -> Option<PagingState> {
+    match response {
+        PagingStateResponse::HasMorePages { state } => Some(state),
+        _ => None,
+    }
+}
+
+/// Fetch pages in order into a bounded channel, so at most its capacity of pages sit
+/// fetched but unconsumed.
+async fn prefetch_pages(
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    statement: Statement,
+    values: Arc<HashMap<String, SerializableValue>>,
+    mut paging_state: PagingState,
+    counters: Arc<PageCounters>,
+    pages: mpsc::Sender<FetchedPage>,
+) {
+    loop {
+        let slot = limiter.acquire().await;
+        let page = statement
+            .execute_single_page(&session, values.as_ref(), paging_state)
+            .await
+            .map_err(query_error_to_py)
+            .and_then(|(result, response)| Ok((page_rows(result)?, next_paging_state(response))));
+        drop(slot);
+        let next_page = match &page {
+            Ok((_, next_page)) => {
+                counters.fetched.fetch_add(1, Ordering::Relaxed);
+                next_page.clone()
+            }
+            Err(_) => None,
+        };
+
+        if pages.send(page).await.is_err() {
+            return;
+        }
+        match next_page {
+            Some(state) => paging_state = state,
+            None => return,
+        }
+    }
+}
+
+struct PagerState {
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    statement: Statement,
+    values: Arc<HashMap<String, SerializableValue>>,
+    names: Arc<[String]>,
+    buffer: VecDeque<ScyllaRow>,
+    page_len: usize,
+    /// `None` once the last page has been requested.
+    next_page: Option<PagingState>,
+    /// Paging state of the page after the one being consumed, which read-ahead and
+    /// prefetch don't move.
+    checkpoint: Checkpoint,
+    prefetch: Option<JoinHandle<PageResponse>>,
+    read_ahead: Option<f64>,
+    /// Pages to keep fetched ahead by a background task; 0 fetches on demand.
+    prefetch_pages: usize,
+    prefetched: Option<(mpsc::Receiver<FetchedPage>, JoinHandle<()>)>,
+    counters: Arc<PageCounters>,
+}
+
+impl Drop for PagerState {
+    fn drop(&mut self) {
+        if let Some((_, fetcher)) = &self.prefetched {
+            fetcher.abort();
+        }
+    }
+}
+
+impl PagerState {
+    fn spawn_fetch(&mut self) {
+        if self.prefetch.is_some() {
+            return;
+        }
+        let Some(paging_state) = self.next_page.take() else {
+            return;
+        };
+
+        let session = self.session.clone();
+        let limiter = self.limiter.clone();
+        let statement = self.statement.clone();
+        let values = self.values.clone();
+        self.prefetch = Some(get_runtime().spawn(async move {
+            let _slot = limiter.acquire().await;
+            statement
+                .execute_single_page(&session, values.as_ref(), paging_state)
+                .await
+        }));
+    }
+
+    fn should_read_ahead(&self) -> bool {
+        match self.read_ahead {
+            Some(threshold) => {
+                let consumed = self.page_len - self.buffer.len();
+                consumed as f64 >= threshold * self.page_len as f64
+            }
+            None => false,
+        }
+    }
+
+    /// Start the background fetcher on first use, from where paging currently stands.
+    fn prefetched_pages(&mut self) -> Option<&mut mpsc::Receiver<FetchedPage>> {
+        if self.prefetched.is_none() {
+            let paging_state = self.next_page.clone()?;
+            let (sender, receiver) = mpsc::channel(self.prefetch_pages);
+            let fetcher = get_runtime().spawn(prefetch_pages(
+                self.session.clone(),
+                self.limiter.clone(),
+                self.statement.clone(),
+                self.values.clone(),
+                paging_state,
+                self.counters.clone(),
+                sender,
+            ));
+            self.prefetched = Some((receiver, fetcher));
+        }
+        self.prefetched.as_mut().map(|(receiver, _)| receiver)
+    }
+
+    async fn next_page(&mut self) -> PyResult<Option<PageRows>> {
+        if self.prefetch_pages > 0 {
+            let Some(pages) = self.prefetched_pages() else {
+                return Ok(None);
+            };
+            let Some(page) = pages.recv().await else {
+                self.next_page = None;
+                *self.checkpoint.lock().unwrap() = None;
+                return Ok(None);
+            };
+            let (rows, next_page) = page?;
+            // Checkpoints resume after the page being consumed, not after the prefetched ones.
+            self.next_page = next_page.clone();
+            *self.checkpoint.lock().unwrap() = next_page;
+            return Ok(Some(rows));
+        }
+
+        self.spawn_fetch();
+        let Some(prefetch) = self.prefetch.take() else {
+            return Ok(None);
+        };
+
+        let (result, paging_state_response) = prefetch
+            .await
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(e.to_string()))?
+            .map_err(query_error_to_py)?;
+        self.counters.fetched.fetch_add(1, Ordering::Relaxed);
+
+        self.next_page = next_paging_state(paging_state_response);
+        *self.checkpoint.lock().unwrap() = self.next_page.clone();
+        page_rows(result).map(Some)
+    }
+
+    async fn next_row(&mut self) -> PyResult<Option<ScyllaRow>> {
+        // Empty pages are legal mid-stream, so keep fetching until rows arrive or paging ends
+        while self.buffer.is_empty() {
+            let Some((names, rows)) = self.next_page().await? else {
+                return Ok(None);
+            };
+            self.counters.consumed.fetch_add(1, Ordering::Relaxed);
+            self.names = names;
+            self.buffer = rows.into();
+            self.page_len = self.buffer.len();
+        }
+
+        let row = self.buffer.pop_front();
+        if self.should_read_ahead() {
+            self.spawn_fetch();
+        }
+        Ok(row)
+    }
+}
+
+/// Column names and rows of one page.
+pub(crate) type PageRows = (Arc<[String]>, Vec<ScyllaRow>);
+
+pub(crate) fn page_rows(result: ScyllaQueryResult) -> PyResult<PageRows> {
+    let Ok(rows_result) = result.into_rows_result() else {
+        return Ok((Arc::from([]), Vec::new()));
+    };
+
+    let names: Arc<[String]> = rows_result
+        .column_specs()
+        .iter()
+        .map(|spec| spec.name().to_string())
+        .collect();
+    let rows = rows_result
+        .rows::<ScyllaRow>()
+        .map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Row deserialization error: {}",
+                e
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Row deserialization error: {}",
+                e
+            ))
+        })?;
+    Ok((names, rows))
+}
+
+/// Async row iterator over a paged statement, optionally fetching the next page
+/// in the background once `read_ahead` of the current page has been consumed, or
+/// keeping `prefetch` pages fetched ahead.
+#[pyclass]
+pub struct Pager {
+    state: Arc<Mutex<PagerState>>,
+    counters: Arc<PageCounters>,
+    checkpoint: Checkpoint,
+}
+
+impl Pager {
+    pub fn new(
+        session: Arc<ScyllaSession>,
+        limiter: Arc<RequestLimiter>,
+        statement: Statement,
+        values: HashMap<String, SerializableValue>,
+        paging_state: Option<Vec<u8>>,
+        read_ahead: Option<f64>,
+        prefetch_pages: usize,
+    ) -> Self {
+        let next_page = match paging_state {
+            Some(bytes) => PagingState::new_from_raw_bytes(bytes),
+            None => PagingState::start(),
+        };
+        let counters = Arc::new(PageCounters::default());
+        let checkpoint = Arc::new(std::sync::Mutex::new(Some(next_page.clone())));
+
+        Pager {
+            counters: counters.clone(),
+            checkpoint: checkpoint.clone(),
+            state: Arc::new(Mutex::new(PagerState {
+                session,
+                limiter,
+                statement,
+                values: Arc::new(values),
+                names: Arc::from([]),
+                buffer: VecDeque::new(),
+                page_len: 0,
+                next_page: Some(next_page),
+                checkpoint,
+                prefetch: None,
+                read_ahead,
+                prefetch_pages,
+                prefetched: None,
+                counters,
+            })),
+        }
+    }
+}
+
+#[pymethods]
+impl Pager {
+    pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let state = self.state.clone();
+
+        future_into_py(py, async move {
+            let mut state = state.lock().await;
+            match state.next_row().await? {
+                Some(row) => Ok(Row::with_names(row, state.names.clone())),
+                None => Err(PyErr::new::<pyo3::exceptions::PyStopAsyncIteration, _>(())),
+            }
+        })
+    }
+
+    /// Paging state of the page after the one being iterated, for resuming from a
+    /// checkpoint; `None` once the last page has been fetched.
+    pub fn paging_state<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyBytes>> {
+        checkpoint_bytes(py, &self.checkpoint)
+    }
+
+    pub fn is_prefetching(&self) -> bool {
+        self.state
+            .try_lock()
+            .map(|state| {
+                state.prefetch.is_some()
+                    || state
+                        .prefetched
+                        .as_ref()
+                        .is_some_and(|(pages, fetcher)| !pages.is_empty() || !fetcher.is_finished())
+            })
+            .unwrap_or(false)
+    }
+
+    /// Pages fetched from the cluster so far, including those prefetched but not yet read.
+    pub fn pages_fetched(&self) -> u64 {
+        self.counters.fetched.load(Ordering::Relaxed)
+    }
+
+    /// Pages the iterator has started handing out rows from.
+    pub fn pages_consumed(&self) -> u64 {
+        self.counters.consumed.load(Ordering::Relaxed)
+    }
+}
+
+struct PagesState {
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    statement: Statement,
+    values: HashMap<String, SerializableValue>,
+    row_factory: Option<RowFactory>,
+    /// `None` once the last page has been fetched; left in place while it is fetched.
+    next_page: Checkpoint,
+}
+
+/// Async iterator yielding a `QueryResult` per page, each carrying the paging state of
+/// the page after it, for callers that process or checkpoint page by page.
+#[pyclass]
+pub struct PageIterator {
+    state: Arc<Mutex<PagesState>>,
+    checkpoint: Checkpoint,
+}
+
+impl PageIterator {
+    pub fn new(
+        session: Arc<ScyllaSession>,
+        limiter: Arc<RequestLimiter>,
+        statement: Statement,
+        values: HashMap<String, SerializableValue>,
+        paging_state: Option<Vec<u8>>,
+        row_factory: Option<RowFactory>,
+    ) -> Self {
+        let next_page = match paging_state {
+            Some(bytes) => PagingState::new_from_raw_bytes(bytes),
+            None => PagingState::start(),
+        };
+
+        let checkpoint = Arc::new(std::sync::Mutex::new(Some(next_page)));
+
+        PageIterator {
+            checkpoint: checkpoint.clone(),
+            state: Arc::new(Mutex::new(PagesState {
+                session,
+                limiter,
+                statement,
+                values,
+                row_factory,
+                next_page: checkpoint,
+            })),
+        }
+    }
+}
+
+#[pymethods]
+impl PageIterator {
+    pub fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    pub fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let state = self.state.clone();
+
+        future_into_py(py, async move {
+            let state = state.lock().await;
+            let Some(paging_state) = state.next_page.lock().unwrap().clone() else {
+                return Err(PyErr::new::<pyo3::exceptions::PyStopAsyncIteration, _>(()));
+            };
+
+            // A failed page stays the next one, to be retried by the next call.
+            let slot = state.limiter.acquire().await;
+            let (result, paging_state_response) = state
+                .statement
+                .execute_single_page(&state.session, &state.values, paging_state)
+                .await
+                .map_err(query_error_to_py)?;
+            drop(slot);
+
+            let next_page = next_paging_state(paging_state_response);
+            *state.next_page.lock().unwrap() = next_page.clone();
+            let next_page = next_page
+                .as_ref()
+                .and_then(|paging_state| paging_state.as_bytes_slice())
+                .map(|bytes| bytes.to_vec());
+
+            Ok(QueryResult::new(result)
+                .with_session(&state.session)
+                .with_row_factory(state.row_factory.clone())
+                .with_paging_state(next_page))
+        })
+    }
+
+    /// Paging state of the next page not yet returned, for resuming from a checkpoint.
+    pub fn paging_state<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyBytes>> {
+        checkpoint_bytes(py, &self.checkpoint)
+    }
+}
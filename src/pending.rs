@@ -0,0 +1,139 @@
+use pyo3::exceptions::asyncio::{CancelledError, InvalidStateError};
+use pyo3::prelude::*;
+use pyo3::IntoPyObjectExt;
+use pyo3_async_runtimes::tokio::get_runtime;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use tokio::task::JoinHandle;
+
+#[derive(Default)]
+struct State {
+    outcome: Option<PyResult<Py<PyAny>>>,
+    callbacks: Vec<Py<PyAny>>,
+}
+
+/// Record the outcome unless one is already set, then run the done callbacks.
+fn complete(py: Python, state: &Mutex<State>, outcome: PyResult<Py<PyAny>>) {
+    let callbacks = {
+        let mut state = state.lock().unwrap();
+        if state.outcome.is_some() {
+            return;
+        }
+        state.outcome = Some(outcome);
+        std::mem::take(&mut state.callbacks)
+    };
+
+    for callback in callbacks {
+        if let Err(err) = callback.call0(py) {
+            err.write_unraisable(py, Some(callback.bind(py)));
+        }
+    }
+}
+
+/// A request started outside asyncio, under trio or by `Session.execute_nowait()`. It
+/// doesn't belong to any event loop: completion is signalled through callbacks run on a
+/// runtime thread, and awaiting it under asyncio or trio (directly or through anyio)
+/// parks the awaiting task until then.
+#[pyclass(module = "rsylla")]
+pub struct PendingResult {
+    state: Arc<Mutex<State>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl PendingResult {
+    pub fn spawn<F, T>(py: Python, fut: F) -> PyResult<Bound<PyAny>>
+    where
+        F: Future<Output = PyResult<T>> + Send + 'static,
+        T: for<'py> IntoPyObject<'py> + Send + 'static,
+    {
+        let state = Arc::new(Mutex::new(State::default()));
+        let task_state = state.clone();
+        let task = get_runtime().spawn(async move {
+            let result = fut.await;
+            Python::attach(|py| {
+                let outcome = result.and_then(|value| value.into_py_any(py));
+                complete(py, &task_state, outcome);
+            });
+        });
+
+        let pending = PendingResult {
+            state,
+            task: Mutex::new(Some(task)),
+        };
+        Ok(Bound::new(py, pending)?.into_any())
+    }
+}
+
+#[pymethods]
+impl PendingResult {
+    pub fn done(&self) -> bool {
+        self.state.lock().unwrap().outcome.is_some()
+    }
+
+    /// The request's value; raises its error, `CancelledError` once cancelled, or
+    /// `InvalidStateError` while it is still running.
+    pub fn result(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match &self.state.lock().unwrap().outcome {
+            Some(Ok(value)) => Ok(value.clone_ref(py)),
+            Some(Err(err)) => Err(err.clone_ref(py)),
+            None => Err(PyErr::new::<InvalidStateError, _>("Result is not ready")),
+        }
+    }
+
+    /// Abort the request, dropping it on the runtime. Returns False if it had already
+    /// completed.
+    pub fn cancel(&self, py: Python) -> bool {
+        if self.done() {
+            return false;
+        }
+        if let Some(task) = self.task.lock().unwrap().take() {
+            task.abort();
+        }
+        complete(
+            py,
+            &self.state,
+            Err(PyErr::new::<CancelledError, _>("Request was cancelled")),
+        );
+        true
+    }
+
+    /// Call `callback()` once the request completes, immediately if it already has.
+    /// It runs on a runtime thread, so it must only hand off to the caller's event loop,
+    /// e.g. with `trio.lowlevel.TrioToken.run_sync_soon`.
+    pub fn add_done_callback(&self, py: Python, callback: Py<PyAny>) -> PyResult<()> {
+        {
+            let mut state = self.state.lock().unwrap();
+            if state.outcome.is_none() {
+                state.callbacks.push(callback);
+                return Ok(());
+            }
+        }
+        callback.call0(py)?;
+        Ok(())
+    }
+
+    fn __await__<'py>(slf: Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        let py = slf.py();
+        let asyncio_running = !py
+            .import("asyncio")?
+            .call_method0("_get_running_loop")?
+            .is_none();
+        let waiter = if asyncio_running {
+            "rsylla._asyncio"
+        } else {
+            "rsylla._trio"
+        };
+        py.import(waiter)?
+            .call_method1("wait", (slf,))?
+            .call_method0("__await__")
+    }
+
+    fn __repr__(&self) -> String {
+        let state = match &self.state.lock().unwrap().outcome {
+            None => "pending",
+            Some(Ok(_)) => "finished",
+            Some(Err(_)) => "failed",
+        };
+        format!("PendingResult({})", state)
+    }
+}
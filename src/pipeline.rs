@@ -0,0 +1,147 @@
+use futures::stream::{self, StreamExt};
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::statement::batch::Batch as ScyllaBatch;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::batch::Batch;
+use crate::rate_limit::RequestLimiter;
+use crate::retry::is_retry_safe;
+use crate::types::{py_list_to_batch_values, SerializableValue};
+
+type BatchValues = Vec<HashMap<String, SerializableValue>>;
+
+/// A queue of pre-grouped (ideally single-partition) batches executed with bounded concurrency.
+/// Failed batches are retried only when that can't apply them twice: timeouts and broken
+/// connections are retried for idempotent batches alone.
+#[pyclass]
+#[derive(Clone)]
+pub struct Pipeline {
+    batches: Vec<(ScyllaBatch, BatchValues)>,
+    concurrency: usize,
+    retries: u32,
+    retry_delay: Duration,
+}
+
+pub struct BatchOutcome {
+    index: usize,
+    attempts: u32,
+    error: Option<String>,
+    warnings: Vec<String>,
+}
+
+impl BatchOutcome {
+    pub fn to_py(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let dict = PyDict::new(py);
+        dict.set_item("index", self.index)?;
+        dict.set_item("success", self.error.is_none())?;
+        dict.set_item("attempts", self.attempts)?;
+        dict.set_item("error", self.error.clone())?;
+        dict.set_item("warnings", self.warnings.clone())?;
+        Ok(dict.into())
+    }
+}
+
+impl Pipeline {
+    pub async fn run(
+        self,
+        session: Arc<ScyllaSession>,
+        limiter: Arc<RequestLimiter>,
+    ) -> Vec<BatchOutcome> {
+        let retries = self.retries;
+        let retry_delay = self.retry_delay;
+
+        let mut outcomes: Vec<BatchOutcome> = stream::iter(self.batches.into_iter().enumerate())
+            .map(|(index, (batch, values))| {
+                let session = session.clone();
+                let limiter = limiter.clone();
+                async move {
+                    let mut attempts = 0;
+                    loop {
+                        attempts += 1;
+                        let slot = limiter.acquire().await;
+                        let result = session.batch(&batch, &values).await;
+                        drop(slot);
+                        match result {
+                            Ok(result) => {
+                                return BatchOutcome {
+                                    index,
+                                    attempts,
+                                    error: None,
+                                    warnings: result.warnings().map(|w| w.to_string()).collect(),
+                                }
+                            }
+                            Err(err)
+                                if attempts > retries
+                                    || !is_retry_safe(&err, batch.get_is_idempotent()) =>
+                            {
+                                return BatchOutcome {
+                                    index,
+                                    attempts,
+                                    error: Some(err.to_string()),
+                                    warnings: Vec::new(),
+                                }
+                            }
+                            Err(_) => tokio::time::sleep(retry_delay * attempts).await,
+                        }
+                    }
+                }
+            })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        outcomes.sort_by_key(|outcome| outcome.index);
+        outcomes
+    }
+}
+
+#[pymethods]
+impl Pipeline {
+    #[new]
+    #[pyo3(signature = (concurrency=16, retries=0, retry_delay_ms=100))]
+    pub fn new(concurrency: usize, retries: u32, retry_delay_ms: u64) -> PyResult<Self> {
+        if concurrency == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Concurrency must be greater than 0",
+            ));
+        }
+
+        Ok(Pipeline {
+            batches: Vec::new(),
+            concurrency,
+            retries,
+            retry_delay: Duration::from_millis(retry_delay_ms),
+        })
+    }
+
+    pub fn add(&mut self, batch: &Batch, values: &Bound<'_, PyList>) -> PyResult<()> {
+        let batch_values = py_list_to_batch_values(values)?;
+        if batch_values.len() != batch.inner.statements.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Batch has {} statements but {} value sets were given",
+                batch.inner.statements.len(),
+                batch_values.len()
+            )));
+        }
+
+        self.batches.push((batch.inner.clone(), batch_values));
+        Ok(())
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.batches.len()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "Pipeline(batches={}, concurrency={}, retries={})",
+            self.batches.len(),
+            self.concurrency,
+            self.retries
+        )
+    }
+}
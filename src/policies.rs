@@ -0,0 +1,346 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla::cluster::{ClusterState, NodeRef};
+use scylla::policies::load_balancing::{
+    DefaultPolicy, DefaultPolicyBuilder, FallbackPlan, LatencyAwarenessBuilder,
+    LoadBalancingPolicy as ScyllaLoadBalancingPolicy, NodeIdentifier, RoutingInfo,
+    SingleTargetLoadBalancingPolicy,
+};
+use scylla::policies::retry::{
+    DefaultRetryPolicy, DowngradingConsistencyRetryPolicy, FallthroughRetryPolicy, RetryPolicy,
+};
+use scylla::routing::Shard;
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+pub const DEFAULT_CQL_PORT: u16 = 9042;
+
+pub fn parse_retry_policy(retry_policy: &str) -> PyResult<Arc<dyn RetryPolicy>> {
+    match retry_policy.to_lowercase().as_str() {
+        "default" => Ok(Arc::new(DefaultRetryPolicy::new())),
+        "fallthrough" => Ok(Arc::new(FallthroughRetryPolicy::new())),
+        "downgrading_consistency" | "downgradingconsistency" => {
+            Ok(Arc::new(DowngradingConsistencyRetryPolicy::new()))
+        }
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid retry policy: {}. Must be 'default', 'fallthrough', or 'downgrading_consistency'",
+            retry_policy
+        ))),
+    }
+}
+
+/// Build a policy pinning every request to one node, identified by `ip[:port]` or host id.
+pub fn single_target_policy(
+    host: &str,
+    shard: Option<u32>,
+) -> PyResult<Arc<dyn ScyllaLoadBalancingPolicy>> {
+    let node = if let Ok(addr) = host.parse::<SocketAddr>() {
+        NodeIdentifier::NodeAddress(addr)
+    } else if let Ok(ip) = host.parse::<IpAddr>() {
+        NodeIdentifier::NodeAddress(SocketAddr::new(ip, DEFAULT_CQL_PORT))
+    } else if let Ok(host_id) = Uuid::parse_str(host) {
+        NodeIdentifier::HostId(host_id)
+    } else {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid host: {}. Must be an IP address (optionally with port) or a host id",
+            host
+        )));
+    };
+
+    Ok(SingleTargetLoadBalancingPolicy::new(node, shard))
+}
+
+/// The driver's latency awareness settings that decide when a node counts as slow.
+#[derive(Debug, Clone, Copy)]
+struct LatencySettings {
+    exclusion_threshold: f64,
+    retry_period: Duration,
+    minimum_measurements: usize,
+    scale: Duration,
+}
+
+#[derive(Debug, Clone)]
+struct LatencyAverage {
+    address: String,
+    average: Duration,
+    measurements: usize,
+    updated_at: Instant,
+}
+
+/// Per-node latency averages, decayed the same way as the driver's latency awareness module,
+/// plus failed request attempts per node address.
+#[derive(Debug, Default)]
+pub struct NodeLatencies {
+    settings: Option<LatencySettings>,
+    averages: Mutex<HashMap<Uuid, LatencyAverage>>,
+    errors: Mutex<HashMap<String, u64>>,
+}
+
+impl NodeLatencies {
+    fn new(settings: Option<LatencySettings>) -> Self {
+        NodeLatencies {
+            settings,
+            ..Default::default()
+        }
+    }
+
+    fn record(&self, node: NodeRef<'_>, latency: Duration) {
+        let Some(LatencySettings { scale, .. }) = self.settings else {
+            return;
+        };
+        if latency.is_zero() {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut averages = self.averages.lock().unwrap();
+        let entry = averages
+            .entry(node.host_id)
+            .or_insert_with(|| LatencyAverage {
+                address: node.address.to_string(),
+                average: latency,
+                measurements: 0,
+                updated_at: now,
+            });
+
+        let scaled_delay = now
+            .saturating_duration_since(entry.updated_at)
+            .as_secs_f64()
+            / scale.as_secs_f64();
+        let previous_weight = if scaled_delay <= 0. {
+            1.
+        } else {
+            (scaled_delay + 1.).ln() / scaled_delay
+        };
+        let average = (1. - previous_weight) * latency.as_secs_f64()
+            + previous_weight * entry.average.as_secs_f64();
+
+        entry.average = Duration::try_from_secs_f64(average).unwrap_or(entry.average);
+        entry.measurements += 1;
+        entry.updated_at = now;
+    }
+
+    fn record_failure(&self, node: NodeRef<'_>) {
+        *self
+            .errors
+            .lock()
+            .unwrap()
+            .entry(node.address.to_string())
+            .or_default() += 1;
+    }
+
+    /// `(address, average latency in ms, measurements)` for every node measured so far.
+    pub fn averages_ms(&self) -> Vec<(String, f64, usize)> {
+        self.averages
+            .lock()
+            .unwrap()
+            .values()
+            .map(|average| {
+                (
+                    average.address.clone(),
+                    average.average.as_secs_f64() * 1000.0,
+                    average.measurements,
+                )
+            })
+            .collect()
+    }
+
+    pub fn errors(&self) -> HashMap<String, u64> {
+        self.errors.lock().unwrap().clone()
+    }
+
+    /// Whether latency awareness currently penalises the node, by the driver's rule: enough
+    /// measurements, measured within the retry period, and slower than the exclusion
+    /// threshold times the fastest node with enough measurements.
+    fn is_slow(
+        settings: &LatencySettings,
+        average: &LatencyAverage,
+        min_average: Option<Duration>,
+    ) -> bool {
+        let Some(min_average) = min_average else {
+            return false;
+        };
+        average.measurements >= settings.minimum_measurements
+            && average.updated_at.elapsed() < settings.retry_period
+            && average.average.as_micros() as f64
+                > settings.exclusion_threshold * min_average.as_micros() as f64
+    }
+
+    pub fn to_py(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let dict = PyDict::new(py);
+        let averages = self.averages.lock().unwrap();
+        let min_average = self.settings.and_then(|settings| {
+            averages
+                .values()
+                .filter(|average| average.measurements >= settings.minimum_measurements)
+                .map(|average| average.average)
+                .min()
+        });
+        for (host_id, average) in averages.iter() {
+            let slow = self
+                .settings
+                .is_some_and(|settings| Self::is_slow(&settings, average, min_average));
+            let node = PyDict::new(py);
+            node.set_item("host_id", host_id.to_string())?;
+            node.set_item("average_ms", average.average.as_secs_f64() * 1000.0)?;
+            node.set_item("measurements", average.measurements)?;
+            node.set_item("slow", slow)?;
+            dict.set_item(&average.address, node)?;
+        }
+        Ok(dict.into())
+    }
+}
+
+/// Delegates routing to the wrapped policy while recording observed latencies per node.
+#[derive(Debug)]
+struct LatencyTrackingPolicy {
+    inner: Arc<dyn ScyllaLoadBalancingPolicy>,
+    latencies: Arc<NodeLatencies>,
+}
+
+impl ScyllaLoadBalancingPolicy for LatencyTrackingPolicy {
+    fn pick<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> Option<(NodeRef<'a>, Option<Shard>)> {
+        self.inner.pick(request, cluster)
+    }
+
+    fn fallback<'a>(
+        &'a self,
+        request: &'a RoutingInfo,
+        cluster: &'a ClusterState,
+    ) -> FallbackPlan<'a> {
+        self.inner.fallback(request, cluster)
+    }
+
+    fn on_request_success(&self, request: &RoutingInfo, latency: Duration, node: NodeRef<'_>) {
+        self.latencies.record(node, latency);
+        self.inner.on_request_success(request, latency, node);
+    }
+
+    fn on_request_failure(
+        &self,
+        request: &RoutingInfo,
+        latency: Duration,
+        node: NodeRef<'_>,
+        error: &scylla::errors::RequestAttemptError,
+    ) {
+        self.latencies.record_failure(node);
+        self.inner.on_request_failure(request, latency, node, error);
+    }
+
+    fn name(&self) -> String {
+        self.inner.name()
+    }
+}
+
+/// Builder for the driver's default (token-aware, DC-aware) load balancing policy.
+#[pyclass]
+#[derive(Clone)]
+pub struct LoadBalancingPolicy {
+    builder: DefaultPolicyBuilder,
+    latency_awareness: Option<LatencySettings>,
+}
+
+impl LoadBalancingPolicy {
+    /// The driver policy plus the latencies it records, fresh for every policy built.
+    pub(crate) fn build(&self) -> (Arc<dyn ScyllaLoadBalancingPolicy>, Arc<NodeLatencies>) {
+        let latencies = Arc::new(NodeLatencies::new(self.latency_awareness));
+        let policy = Arc::new(LatencyTrackingPolicy {
+            inner: self.builder.clone().build(),
+            latencies: latencies.clone(),
+        });
+        (policy, latencies)
+    }
+}
+
+#[pymethods]
+impl LoadBalancingPolicy {
+    #[new]
+    pub fn new() -> Self {
+        LoadBalancingPolicy {
+            builder: DefaultPolicy::builder(),
+            latency_awareness: None,
+        }
+    }
+
+    pub fn prefer_datacenter(&mut self, datacenter: &str) -> PyResult<Self> {
+        self.builder = self
+            .builder
+            .clone()
+            .prefer_datacenter(datacenter.to_string());
+        Ok(self.clone())
+    }
+
+    pub fn prefer_datacenter_and_rack(&mut self, datacenter: &str, rack: &str) -> PyResult<Self> {
+        self.builder = self
+            .builder
+            .clone()
+            .prefer_datacenter_and_rack(datacenter.to_string(), rack.to_string());
+        Ok(self.clone())
+    }
+
+    pub fn token_aware(&mut self, enabled: bool) -> PyResult<Self> {
+        self.builder = self.builder.clone().token_aware(enabled);
+        Ok(self.clone())
+    }
+
+    pub fn permit_dc_failover(&mut self, permit: bool) -> PyResult<Self> {
+        self.builder = self.builder.clone().permit_dc_failover(permit);
+        Ok(self.clone())
+    }
+
+    pub fn enable_shuffling_replicas(&mut self, enabled: bool) -> PyResult<Self> {
+        self.builder = self.builder.clone().enable_shuffling_replicas(enabled);
+        Ok(self.clone())
+    }
+
+    #[pyo3(signature = (
+        exclusion_threshold=2.0,
+        retry_period_ms=10_000,
+        update_rate_ms=100,
+        minimum_measurements=50,
+        scale_ms=100
+    ))]
+    pub fn latency_awareness(
+        &mut self,
+        exclusion_threshold: f64,
+        retry_period_ms: u64,
+        update_rate_ms: u64,
+        minimum_measurements: usize,
+        scale_ms: u64,
+    ) -> PyResult<Self> {
+        if scale_ms == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Latency awareness scale must be greater than 0",
+            ));
+        }
+
+        let settings = LatencySettings {
+            exclusion_threshold,
+            retry_period: Duration::from_millis(retry_period_ms),
+            minimum_measurements,
+            scale: Duration::from_millis(scale_ms),
+        };
+        let latency_awareness = LatencyAwarenessBuilder::new()
+            .exclusion_threshold(settings.exclusion_threshold)
+            .retry_period(settings.retry_period)
+            .update_rate(Duration::from_millis(update_rate_ms))
+            .minimum_measurements(settings.minimum_measurements)
+            .scale(settings.scale);
+        self.builder = self.builder.clone().latency_awareness(latency_awareness);
+        self.latency_awareness = Some(settings);
+        Ok(self.clone())
+    }
+}
+
+impl Default for LoadBalancingPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
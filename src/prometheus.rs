@@ -0,0 +1,309 @@
+use pyo3::prelude::*;
+use pyo3_async_runtimes::tokio::get_runtime;
+use scylla::client::session::Session as ScyllaSession;
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::task::JoinHandle;
+
+use crate::policies::NodeLatencies;
+use crate::runtime::future_into_py;
+
+const MAX_REQUEST_SIZE: usize = 8192;
+
+struct Sample {
+    labels: Vec<(&'static str, String)>,
+    value: f64,
+}
+
+impl Sample {
+    #[cfg_attr(not(feature = "metrics"), allow(dead_code))]
+    fn plain(value: impl Into<f64>) -> Self {
+        Sample {
+            labels: Vec::new(),
+            value: value.into(),
+        }
+    }
+
+    fn labelled(labels: Vec<(&'static str, String)>, value: impl Into<f64>) -> Self {
+        Sample {
+            labels,
+            value: value.into(),
+        }
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn write_metric(out: &mut String, name: &str, kind: &str, help: &str, samples: &[Sample]) {
+    let _ = writeln!(out, "# HELP rsylla_{} {}", name, help);
+    let _ = writeln!(out, "# TYPE rsylla_{} {}", name, kind);
+    for sample in samples {
+        let _ = write!(out, "rsylla_{}", name);
+        if !sample.labels.is_empty() {
+            let labels: Vec<String> = sample
+                .labels
+                .iter()
+                .map(|(key, value)| format!("{}=\"{}\"", key, escape_label(value)))
+                .collect();
+            let _ = write!(out, "{{{}}}", labels.join(","));
+        }
+        let _ = writeln!(out, " {}", sample.value);
+    }
+}
+
+#[cfg(feature = "metrics")]
+fn write_driver_metrics(out: &mut String, session: &ScyllaSession) {
+    let metrics = session.get_metrics();
+
+    let counters = [
+        (
+            "requests_total",
+            "Unpaged and manually paged requests sent.",
+            metrics.get_requests_unpaged_num() + metrics.get_requests_manually_paged_num(),
+        ),
+        (
+            "paged_requests_total",
+            "Automatically paged requests sent.",
+            metrics.get_requests_automatically_paged_num(),
+        ),
+        (
+            "errors_total",
+            "Unpaged and manually paged requests that failed.",
+            metrics.get_errors_unpaged_num() + metrics.get_errors_manually_paged_num(),
+        ),
+        (
+            "paged_errors_total",
+            "Automatically paged requests that failed.",
+            metrics.get_errors_automatically_paged_num(),
+        ),
+        (
+            "retries_total",
+            "Request retries.",
+            metrics.get_retries_num(),
+        ),
+        (
+            "request_timeouts_total",
+            "Requests that timed out.",
+            metrics.get_request_timeouts(),
+        ),
+        (
+            "connection_timeouts_total",
+            "Connection attempts that timed out.",
+            metrics.get_connection_timeouts(),
+        ),
+    ];
+    for (name, help, value) in counters {
+        write_metric(out, name, "counter", help, &[Sample::plain(value as f64)]);
+    }
+
+    write_metric(
+        out,
+        "connections",
+        "gauge",
+        "Open connections across all node pools.",
+        &[Sample::plain(metrics.get_total_connections() as f64)],
+    );
+
+    if let Ok(snapshot) = metrics.get_snapshot() {
+        let quantiles = [
+            ("0.5", snapshot.median),
+            ("0.75", snapshot.percentile_75),
+            ("0.95", snapshot.percentile_95),
+            ("0.98", snapshot.percentile_98),
+            ("0.99", snapshot.percentile_99),
+            ("0.999", snapshot.percentile_99_9),
+        ];
+        let samples: Vec<Sample> = quantiles
+            .into_iter()
+            .map(|(quantile, value)| {
+                Sample::labelled(vec![("quantile", quantile.to_string())], value as f64)
+            })
+            .collect();
+        write_metric(
+            out,
+            "request_latency_ms",
+            "gauge",
+            "Request latency percentiles in milliseconds.",
+            &samples,
+        );
+        write_metric(
+            out,
+            "request_latency_mean_ms",
+            "gauge",
+            "Mean request latency in milliseconds.",
+            &[Sample::plain(snapshot.mean as f64)],
+        );
+    }
+}
+
+#[cfg(not(feature = "metrics"))]
+fn write_driver_metrics(_out: &mut String, _session: &ScyllaSession) {}
+
+/// Render the session's metrics in the Prometheus text exposition format.
+pub fn render(session: &ScyllaSession, node_latencies: Option<&NodeLatencies>) -> String {
+    let mut out = String::new();
+    write_driver_metrics(&mut out, session);
+
+    let cluster_state = session.get_cluster_state();
+    let nodes: Vec<Sample> = cluster_state
+        .get_nodes_info()
+        .iter()
+        .map(|node| {
+            Sample::labelled(
+                vec![
+                    ("address", node.address.to_string()),
+                    ("datacenter", node.datacenter.clone().unwrap_or_default()),
+                    ("rack", node.rack.clone().unwrap_or_default()),
+                ],
+                if node.is_connected() { 1.0 } else { 0.0 },
+            )
+        })
+        .collect();
+    write_metric(
+        &mut out,
+        "node_connected",
+        "gauge",
+        "Whether the node's connection pool has at least one working connection.",
+        &nodes,
+    );
+
+    if let Some(latencies) = node_latencies {
+        let errors: Vec<Sample> = latencies
+            .errors()
+            .into_iter()
+            .map(|(address, count)| Sample::labelled(vec![("address", address)], count as f64))
+            .collect();
+        write_metric(
+            &mut out,
+            "node_errors_total",
+            "counter",
+            "Failed request attempts per node.",
+            &errors,
+        );
+
+        let averages: Vec<Sample> = latencies
+            .averages_ms()
+            .into_iter()
+            .map(|(address, average_ms, _)| {
+                Sample::labelled(vec![("address", address)], average_ms)
+            })
+            .collect();
+        write_metric(
+            &mut out,
+            "node_latency_avg_ms",
+            "gauge",
+            "Decaying average request latency per node in milliseconds.",
+            &averages,
+        );
+    }
+
+    out
+}
+
+async fn serve_scrape(
+    mut stream: TcpStream,
+    session: &ScyllaSession,
+    node_latencies: Option<&NodeLatencies>,
+) -> std::io::Result<()> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let read = stream.read(&mut buf).await?;
+        if read == 0 || request.len() > MAX_REQUEST_SIZE {
+            return Ok(());
+        }
+        request.extend_from_slice(&buf[..read]);
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let path = request.split_whitespace().nth(1).unwrap_or_default();
+    let (status, body) = if path == "/metrics" || path.starts_with("/metrics?") {
+        ("200 OK", render(session, node_latencies))
+    } else {
+        ("404 Not Found", "Not Found\n".to_string())
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Serves `/metrics` over HTTP until stopped or garbage collected.
+#[pyclass]
+pub struct PrometheusExporter {
+    #[pyo3(get)]
+    address: String,
+    task: JoinHandle<()>,
+}
+
+impl PrometheusExporter {
+    pub fn start<'py>(
+        py: Python<'py>,
+        address: String,
+        session: Arc<ScyllaSession>,
+        node_latencies: Option<Arc<NodeLatencies>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        future_into_py(py, async move {
+            let listener = TcpListener::bind(&address).await.map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyOSError, _>(format!(
+                    "Failed to bind Prometheus exporter to {}: {}",
+                    address, e
+                ))
+            })?;
+            let local_addr: SocketAddr = listener
+                .local_addr()
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyOSError, _>(e.to_string()))?;
+
+            let task = get_runtime().spawn(async move {
+                while let Ok((stream, _)) = listener.accept().await {
+                    let session = session.clone();
+                    let node_latencies = node_latencies.clone();
+                    tokio::spawn(async move {
+                        let _ = serve_scrape(stream, &session, node_latencies.as_deref()).await;
+                    });
+                }
+            });
+
+            Ok(PrometheusExporter {
+                address: local_addr.to_string(),
+                task,
+            })
+        })
+    }
+}
+
+impl Drop for PrometheusExporter {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+#[pymethods]
+impl PrometheusExporter {
+    pub fn is_running(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Stop accepting scrapes and release the listening socket.
+    pub fn stop(&self) {
+        self.task.abort();
+    }
+
+    fn __repr__(&self) -> String {
+        format!("PrometheusExporter(address={})", self.address)
+    }
+}
@@ -0,0 +1,408 @@
+use pyo3::prelude::*;
+
+use crate::cql::{validate_identifier, validate_table_name};
+
+const OPERATORS: &[&str] = &[
+    "=",
+    "!=",
+    "<",
+    "<=",
+    ">",
+    ">=",
+    "IN",
+    "CONTAINS",
+    "CONTAINS KEY",
+    "LIKE",
+];
+
+fn invalid(message: String) -> PyErr {
+    PyErr::new::<pyo3::exceptions::PyValueError, _>(message)
+}
+
+/// `column <op> :marker`, where the marker defaults to the column name. Values are
+/// never interpolated; they are bound by marker name when the statement runs.
+#[derive(Clone)]
+struct Condition {
+    column: String,
+    op: &'static str,
+    marker: String,
+}
+
+impl Condition {
+    fn new(column: &str, op: &str, name: Option<&str>) -> PyResult<Self> {
+        let normalized = op.split_whitespace().collect::<Vec<_>>().join(" ");
+        let op = OPERATORS
+            .iter()
+            .find(|known| known.eq_ignore_ascii_case(&normalized))
+            .copied()
+            .ok_or_else(|| invalid(format!("Unsupported operator: {:?}", op)))?;
+        Ok(Condition {
+            column: validate_identifier(column)?.to_string(),
+            op,
+            marker: validate_identifier(name.unwrap_or(column))?.to_string(),
+        })
+    }
+}
+
+fn where_clause(conditions: &[Condition]) -> String {
+    if conditions.is_empty() {
+        return String::new();
+    }
+    let conditions: Vec<String> = conditions
+        .iter()
+        .map(|c| format!("{} {} :{}", c.column, c.op, c.marker))
+        .collect();
+    format!(" WHERE {}", conditions.join(" AND "))
+}
+
+fn column_list(columns: &[String]) -> PyResult<Vec<String>> {
+    columns
+        .iter()
+        .map(|column| validate_identifier(column).map(str::to_string))
+        .collect()
+}
+
+#[derive(Clone, Default)]
+struct Using {
+    ttl: Option<u32>,
+    timestamp: Option<i64>,
+}
+
+impl Using {
+    fn clause(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(ttl) = self.ttl {
+            parts.push(format!("TTL {}", ttl));
+        }
+        if let Some(timestamp) = self.timestamp {
+            parts.push(format!("TIMESTAMP {}", timestamp));
+        }
+        if parts.is_empty() {
+            String::new()
+        } else {
+            format!(" USING {}", parts.join(" AND "))
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct Select {
+    table: String,
+    columns: Vec<String>,
+    conditions: Vec<Condition>,
+    order_by: Vec<(String, bool)>,
+    limit: Option<u32>,
+    allow_filtering: bool,
+    bypass_cache: bool,
+}
+
+#[pymethods]
+impl Select {
+    #[pyo3(name = "where", signature = (column, op="=", name=None))]
+    pub fn where_(&mut self, column: &str, op: &str, name: Option<&str>) -> PyResult<Self> {
+        self.conditions.push(Condition::new(column, op, name)?);
+        Ok(self.clone())
+    }
+
+    #[pyo3(signature = (column, descending=false))]
+    pub fn order_by(&mut self, column: &str, descending: bool) -> PyResult<Self> {
+        self.order_by
+            .push((validate_identifier(column)?.to_string(), descending));
+        Ok(self.clone())
+    }
+
+    pub fn limit(&mut self, limit: u32) -> PyResult<Self> {
+        self.limit = Some(limit);
+        Ok(self.clone())
+    }
+
+    pub fn allow_filtering(&mut self) -> PyResult<Self> {
+        self.allow_filtering = true;
+        Ok(self.clone())
+    }
+
+    pub fn bypass_cache(&mut self) -> PyResult<Self> {
+        self.bypass_cache = true;
+        Ok(self.clone())
+    }
+
+    pub fn build(&self) -> String {
+        let columns = if self.columns.is_empty() {
+            "*".to_string()
+        } else {
+            self.columns.join(", ")
+        };
+        let mut cql = format!("SELECT {} FROM {}", columns, self.table);
+        cql.push_str(&where_clause(&self.conditions));
+        if !self.order_by.is_empty() {
+            let order: Vec<String> = self
+                .order_by
+                .iter()
+                .map(|(column, descending)| {
+                    format!("{} {}", column, if *descending { "DESC" } else { "ASC" })
+                })
+                .collect();
+            cql.push_str(&format!(" ORDER BY {}", order.join(", ")));
+        }
+        if let Some(limit) = self.limit {
+            cql.push_str(&format!(" LIMIT {}", limit));
+        }
+        if self.allow_filtering {
+            cql.push_str(" ALLOW FILTERING");
+        }
+        if self.bypass_cache {
+            cql.push_str(" BYPASS CACHE");
+        }
+        cql
+    }
+
+    pub fn __str__(&self) -> String {
+        self.build()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Select({:?})", self.build())
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct Insert {
+    table: String,
+    columns: Vec<String>,
+    if_not_exists: bool,
+    using: Using,
+}
+
+#[pymethods]
+impl Insert {
+    /// Columns to insert, each bound by a marker of the same name.
+    pub fn values(&mut self, columns: Vec<String>) -> PyResult<Self> {
+        self.columns = column_list(&columns)?;
+        Ok(self.clone())
+    }
+
+    pub fn if_not_exists(&mut self) -> PyResult<Self> {
+        self.if_not_exists = true;
+        Ok(self.clone())
+    }
+
+    pub fn using_ttl(&mut self, ttl: u32) -> PyResult<Self> {
+        self.using.ttl = Some(ttl);
+        Ok(self.clone())
+    }
+
+    pub fn using_timestamp(&mut self, timestamp: i64) -> PyResult<Self> {
+        self.using.timestamp = Some(timestamp);
+        Ok(self.clone())
+    }
+
+    pub fn build(&self) -> PyResult<String> {
+        if self.columns.is_empty() {
+            return Err(invalid("INSERT needs at least one column".to_string()));
+        }
+        let markers: Vec<String> = self.columns.iter().map(|c| format!(":{}", c)).collect();
+        let mut cql = format!(
+            "INSERT INTO {} ({}) VALUES ({})",
+            self.table,
+            self.columns.join(", "),
+            markers.join(", ")
+        );
+        if self.if_not_exists {
+            cql.push_str(" IF NOT EXISTS");
+        }
+        cql.push_str(&self.using.clause());
+        Ok(cql)
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        self.build()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Insert(table={:?}, columns={:?})", self.table, self.columns)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct Update {
+    table: String,
+    assignments: Vec<(String, String)>,
+    conditions: Vec<Condition>,
+    if_exists: bool,
+    using: Using,
+}
+
+#[pymethods]
+impl Update {
+    /// Assign `column = :name`; the marker defaults to the column name.
+    #[pyo3(signature = (column, name=None))]
+    pub fn set(&mut self, column: &str, name: Option<&str>) -> PyResult<Self> {
+        self.assignments.push((
+            validate_identifier(column)?.to_string(),
+            validate_identifier(name.unwrap_or(column))?.to_string(),
+        ));
+        Ok(self.clone())
+    }
+
+    #[pyo3(name = "where", signature = (column, op="=", name=None))]
+    pub fn where_(&mut self, column: &str, op: &str, name: Option<&str>) -> PyResult<Self> {
+        self.conditions.push(Condition::new(column, op, name)?);
+        Ok(self.clone())
+    }
+
+    pub fn if_exists(&mut self) -> PyResult<Self> {
+        self.if_exists = true;
+        Ok(self.clone())
+    }
+
+    pub fn using_ttl(&mut self, ttl: u32) -> PyResult<Self> {
+        self.using.ttl = Some(ttl);
+        Ok(self.clone())
+    }
+
+    pub fn using_timestamp(&mut self, timestamp: i64) -> PyResult<Self> {
+        self.using.timestamp = Some(timestamp);
+        Ok(self.clone())
+    }
+
+    pub fn build(&self) -> PyResult<String> {
+        if self.assignments.is_empty() {
+            return Err(invalid(
+                "UPDATE needs at least one set() column".to_string(),
+            ));
+        }
+        if self.conditions.is_empty() {
+            return Err(invalid("UPDATE needs a where() condition".to_string()));
+        }
+        let assignments: Vec<String> = self
+            .assignments
+            .iter()
+            .map(|(column, marker)| format!("{} = :{}", column, marker))
+            .collect();
+        let mut cql = format!(
+            "UPDATE {}{} SET {}{}",
+            self.table,
+            self.using.clause(),
+            assignments.join(", "),
+            where_clause(&self.conditions)
+        );
+        if self.if_exists {
+            cql.push_str(" IF EXISTS");
+        }
+        Ok(cql)
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        self.build()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Update(table={:?})", self.table)
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct Delete {
+    table: String,
+    columns: Vec<String>,
+    conditions: Vec<Condition>,
+    if_exists: bool,
+    using: Using,
+}
+
+#[pymethods]
+impl Delete {
+    #[pyo3(name = "where", signature = (column, op="=", name=None))]
+    pub fn where_(&mut self, column: &str, op: &str, name: Option<&str>) -> PyResult<Self> {
+        self.conditions.push(Condition::new(column, op, name)?);
+        Ok(self.clone())
+    }
+
+    pub fn if_exists(&mut self) -> PyResult<Self> {
+        self.if_exists = true;
+        Ok(self.clone())
+    }
+
+    pub fn using_timestamp(&mut self, timestamp: i64) -> PyResult<Self> {
+        self.using.timestamp = Some(timestamp);
+        Ok(self.clone())
+    }
+
+    pub fn build(&self) -> PyResult<String> {
+        if self.conditions.is_empty() {
+            return Err(invalid("DELETE needs a where() condition".to_string()));
+        }
+        let mut cql = String::from("DELETE");
+        if !self.columns.is_empty() {
+            cql.push_str(&format!(" {}", self.columns.join(", ")));
+        }
+        cql.push_str(&format!(" FROM {}", self.table));
+        cql.push_str(&self.using.clause());
+        cql.push_str(&where_clause(&self.conditions));
+        if self.if_exists {
+            cql.push_str(" IF EXISTS");
+        }
+        Ok(cql)
+    }
+
+    pub fn __str__(&self) -> PyResult<String> {
+        self.build()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("Delete(table={:?})", self.table)
+    }
+}
+
+/// `SELECT` from `table`, all columns unless `columns` is given.
+#[pyfunction]
+#[pyo3(signature = (table, columns=None))]
+pub fn select(table: &str, columns: Option<Vec<String>>) -> PyResult<Select> {
+    Ok(Select {
+        table: validate_table_name(table)?.to_string(),
+        columns: column_list(&columns.unwrap_or_default())?,
+        conditions: Vec::new(),
+        order_by: Vec::new(),
+        limit: None,
+        allow_filtering: false,
+        bypass_cache: false,
+    })
+}
+
+#[pyfunction]
+pub fn insert_into(table: &str) -> PyResult<Insert> {
+    Ok(Insert {
+        table: validate_table_name(table)?.to_string(),
+        columns: Vec::new(),
+        if_not_exists: false,
+        using: Using::default(),
+    })
+}
+
+#[pyfunction]
+pub fn update(table: &str) -> PyResult<Update> {
+    Ok(Update {
+        table: validate_table_name(table)?.to_string(),
+        assignments: Vec::new(),
+        conditions: Vec::new(),
+        if_exists: false,
+        using: Using::default(),
+    })
+}
+
+/// `DELETE` whole rows from `table`, or only `columns` of them.
+#[pyfunction]
+#[pyo3(signature = (table, columns=None))]
+pub fn delete(table: &str, columns: Option<Vec<String>>) -> PyResult<Delete> {
+    Ok(Delete {
+        table: validate_table_name(table)?.to_string(),
+        columns: column_list(&columns.unwrap_or_default())?,
+        conditions: Vec::new(),
+        if_exists: false,
+        using: Using::default(),
+    })
+}
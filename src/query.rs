@@ -1,15 +1,86 @@
+use pyo3::conversion::FromPyObjectOwned;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyBytes, PyDict, PyList};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::errors::ExecutionError;
+use scylla::response::query_result::QueryResult as ScyllaQueryResult;
+use scylla::response::{PagingState, PagingStateResponse};
+use scylla::serialize::row::SerializeRow;
+use scylla::statement::bound::BoundStatement as ScyllaBoundStatement;
 use scylla::statement::prepared::PreparedStatement as ScyllaPreparedStatement;
 use scylla::statement::unprepared::Statement as ScyllaQuery;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::Duration;
 
+use crate::cql::{with_bypass_cache, with_using_timeout};
+use crate::error::{
+    bind_error_to_py, bind_value_error_to_py, partition_key_error_to_py, BindError,
+};
+use crate::execution_profile::ExecutionProfile;
+use crate::policies::{parse_retry_policy, single_target_policy};
+use crate::result::col_specs_to_py;
+use crate::types::{py_dict_to_serialized_values, SerializableValue};
+
 #[pyclass]
 #[derive(Clone)]
 pub struct Query {
     pub(crate) inner: ScyllaQuery,
     query_string: String,
+    pub(crate) fairness_class: Option<String>,
+    db_timeout: Option<u64>,
+    bypass_cache: bool,
+    retry_policy: Option<String>,
+}
+
+/// Keys of `Query.to_dict()`, the only ones `Query.from_dict()` accepts.
+const QUERY_KEYS: &[&str] = &[
+    "query",
+    "consistency",
+    "serial_consistency",
+    "page_size",
+    "timestamp",
+    "timeout_ms",
+    "db_timeout_ms",
+    "bypass_cache",
+    "tracing",
+    "idempotent",
+    "retry_policy",
+    "fairness_class",
+];
+
+/// The value of `key` in `data`, if set and not `None`; the error names the offending key.
+fn option<'py, T: FromPyObjectOwned<'py>>(
+    data: &Bound<'py, PyDict>,
+    key: &str,
+) -> PyResult<Option<T>> {
+    match data.get_item(key)? {
+        Some(value) if !value.is_none() => value.extract::<T>().map(Some).map_err(|err| {
+            let err: PyErr = err.into();
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid value for '{}': {}",
+                key, err
+            ))
+        }),
+        _ => Ok(None),
+    }
+}
+
+impl Query {
+    /// Set the statement text to the original query with the Scylla clauses requested.
+    fn render(&mut self, db_timeout: Option<u64>, bypass_cache: bool) -> PyResult<()> {
+        let mut contents = self.query_string.clone();
+        if bypass_cache {
+            contents = with_bypass_cache(&contents)?;
+        }
+        if let Some(timeout_ms) = db_timeout {
+            contents = with_using_timeout(&contents, timeout_ms)?;
+        }
+        self.inner.contents = contents;
+        self.db_timeout = db_timeout;
+        self.bypass_cache = bypass_cache;
+        Ok(())
+    }
 }
 
 #[pymethods]
@@ -19,7 +90,101 @@ impl Query {
         Query {
             inner: ScyllaQuery::new(query),
             query_string: query.to_string(),
+            fairness_class: None,
+            db_timeout: None,
+            bypass_cache: false,
+            retry_policy: None,
+        }
+    }
+
+    /// Rebuild a query from `to_dict()` output or a dict written by hand, e.g. read from a
+    /// config file. Only `query` is required; unknown keys are rejected.
+    #[staticmethod]
+    pub fn from_dict(data: &Bound<'_, PyDict>) -> PyResult<Self> {
+        for key in data.keys() {
+            let key: String = key.extract()?;
+            if !QUERY_KEYS.contains(&key.as_str()) {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Unknown query option '{}'",
+                    key
+                )));
+            }
+        }
+        let Some(contents) = option::<String>(data, "query")? else {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Query dict needs a 'query' key with the statement text",
+            ));
+        };
+
+        let mut query = Query::new(&contents);
+        if let Some(consistency) = option::<String>(data, "consistency")? {
+            query.with_consistency(&consistency)?;
+        }
+        if let Some(serial_consistency) = option::<String>(data, "serial_consistency")? {
+            query.with_serial_consistency(&serial_consistency)?;
+        }
+        if let Some(page_size) = option::<i32>(data, "page_size")? {
+            query.with_page_size(page_size)?;
+        }
+        if let Some(timestamp) = option::<i64>(data, "timestamp")? {
+            query.with_timestamp(timestamp)?;
+        }
+        if let Some(timeout_ms) = option::<u64>(data, "timeout_ms")? {
+            query.with_timeout(timeout_ms)?;
+        }
+        if let Some(tracing) = option::<bool>(data, "tracing")? {
+            query.with_tracing(tracing)?;
+        }
+        if let Some(idempotent) = option::<bool>(data, "idempotent")? {
+            query.set_idempotent(idempotent);
         }
+        if let Some(retry_policy) = option::<String>(data, "retry_policy")? {
+            query.with_retry_policy(&retry_policy)?;
+        }
+        if let Some(fairness_class) = option::<String>(data, "fairness_class")? {
+            query.with_fairness_class(&fairness_class)?;
+        }
+        let db_timeout = option::<u64>(data, "db_timeout_ms")?;
+        if db_timeout.is_some() {
+            query.with_db_timeout(db_timeout)?;
+        }
+        if let Some(bypass_cache) = option::<bool>(data, "bypass_cache")? {
+            query.with_bypass_cache(bypass_cache)?;
+        }
+        Ok(query)
+    }
+
+    /// The statement text and options as a dict of plain values, for config files or
+    /// passing to another process; `from_dict()` turns it back into a query. It never
+    /// carries a paging state, and the execution profile isn't included.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new(py);
+        dict.set_item("query", &self.query_string)?;
+        dict.set_item(
+            "consistency",
+            self.inner.get_consistency().map(consistency_name),
+        )?;
+        dict.set_item(
+            "serial_consistency",
+            self.inner
+                .get_serial_consistency()
+                .map(serial_consistency_name),
+        )?;
+        dict.set_item("page_size", self.inner.get_page_size())?;
+        dict.set_item("timestamp", self.inner.get_timestamp())?;
+        dict.set_item(
+            "timeout_ms",
+            self.inner
+                .get_request_timeout()
+                .map(|timeout| timeout.as_millis() as u64),
+        )?;
+        dict.set_item("db_timeout_ms", self.db_timeout)?;
+        dict.set_item("bypass_cache", self.bypass_cache)?;
+        dict.set_item("tracing", self.inner.get_tracing())?;
+        dict.set_item("idempotent", self.inner.get_is_idempotent())?;
+        dict.set_item("retry_policy", &self.retry_policy)?;
+        dict.set_item("fairness_class", &self.fairness_class)?;
+        Ok(dict)
     }
 
     pub fn with_consistency(&mut self, consistency: &str) -> PyResult<Self> {
@@ -55,6 +220,53 @@ impl Query {
         Ok(self.clone())
     }
 
+    pub fn with_retry_policy(&mut self, retry_policy: &str) -> PyResult<Self> {
+        let policy = parse_retry_policy(retry_policy)?;
+        self.inner.set_retry_policy(Some(policy));
+        self.retry_policy = Some(retry_policy.to_lowercase());
+        Ok(self.clone())
+    }
+
+    pub fn with_execution_profile(&mut self, profile: &ExecutionProfile) -> PyResult<Self> {
+        self.inner
+            .set_execution_profile_handle(Some(profile.handle()));
+        Ok(self.clone())
+    }
+
+    #[pyo3(signature = (host, shard=None))]
+    pub fn with_host(&mut self, host: &str, shard: Option<u32>) -> PyResult<Self> {
+        let policy = single_target_policy(host, shard)?;
+        self.inner.set_load_balancing_policy(Some(policy));
+        Ok(self.clone())
+    }
+
+    pub fn with_fairness_class(&mut self, fairness_class: &str) -> PyResult<Self> {
+        self.fairness_class = Some(fairness_class.to_string());
+        Ok(self.clone())
+    }
+
+    /// Have Scylla give up on the statement after `timeout_ms` through its `USING TIMEOUT`
+    /// clause, instead of the server's configured default; `None` removes it. This is the
+    /// server-side limit: raise `with_timeout()` too when it exceeds the client's.
+    #[pyo3(signature = (timeout_ms))]
+    pub fn with_db_timeout(&mut self, timeout_ms: Option<u64>) -> PyResult<Self> {
+        if timeout_ms == Some(0) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "timeout_ms must be greater than 0",
+            ));
+        }
+        self.render(timeout_ms, self.bypass_cache)?;
+        Ok(self.clone())
+    }
+
+    /// Read past Scylla's row cache with `BYPASS CACHE`, so a SELECT over cold data
+    /// doesn't evict the hot rows other requests rely on.
+    #[pyo3(signature = (enabled=true))]
+    pub fn with_bypass_cache(&mut self, enabled: bool) -> PyResult<Self> {
+        self.render(self.db_timeout, enabled)?;
+        Ok(self.clone())
+    }
+
     pub fn is_idempotent(&self) -> bool {
         self.inner.get_is_idempotent()
     }
@@ -63,8 +275,9 @@ impl Query {
         self.inner.set_is_idempotent(idempotent);
     }
 
+    /// The statement text sent to the server, including any `with_db_timeout()` clause.
     pub fn get_contents(&self) -> String {
-        self.query_string.clone()
+        self.inner.contents.clone()
     }
 }
 
@@ -72,6 +285,155 @@ impl Query {
 #[derive(Clone)]
 pub struct PreparedStatement {
     pub(crate) prepared: Arc<ScyllaPreparedStatement>,
+    /// Nodes connected when the statement was prepared, not a confirmed list of nodes
+    /// that prepared it.
+    pub(crate) connected_at_prepare: Vec<String>,
+    pub(crate) prepare_attempts: u32,
+    pub(crate) fairness_class: Option<String>,
+    /// Whether `bind` refuses lossy conversions, inherited from the preparing session.
+    pub(crate) strict_types: bool,
+    pub(crate) retry_policy: Option<String>,
+}
+
+impl PreparedStatement {
+    fn with_prepared(&self, prepared: ScyllaPreparedStatement) -> Self {
+        PreparedStatement {
+            prepared: Arc::new(prepared),
+            connected_at_prepare: self.connected_at_prepare.clone(),
+            prepare_attempts: self.prepare_attempts,
+            fairness_class: self.fairness_class.clone(),
+            strict_types: self.strict_types,
+            retry_policy: self.retry_policy.clone(),
+        }
+    }
+}
+
+/// A statement's text and options read from a `to_dict()` dict, to prepare it anew.
+pub(crate) struct StatementDefinition {
+    options: Query,
+    /// Set only when the dict names one, so the session's default applies otherwise.
+    page_size: Option<i32>,
+}
+
+impl StatementDefinition {
+    pub(crate) fn from_dict(data: &Bound<'_, PyDict>) -> PyResult<Self> {
+        Ok(StatementDefinition {
+            options: Query::from_dict(data)?,
+            page_size: option(data, "page_size")?,
+        })
+    }
+
+    /// The statement text to prepare, with any Scylla clauses the options add.
+    pub(crate) fn contents(&self) -> &str {
+        &self.options.inner.contents
+    }
+
+    /// Apply the options to `statement`, prepared from `contents()`.
+    pub(crate) fn apply(&self, mut statement: PreparedStatement) -> PreparedStatement {
+        let options = &self.options.inner;
+        let mut prepared = (*statement.prepared).clone();
+        if let Some(consistency) = options.get_consistency() {
+            prepared.set_consistency(consistency);
+        }
+        if let Some(serial_consistency) = options.get_serial_consistency() {
+            prepared.set_serial_consistency(Some(serial_consistency));
+        }
+        if let Some(page_size) = self.page_size {
+            prepared.set_page_size(page_size);
+        }
+        prepared.set_timestamp(options.get_timestamp());
+        prepared.set_request_timeout(options.get_request_timeout());
+        prepared.set_tracing(options.get_tracing());
+        prepared.set_is_idempotent(options.get_is_idempotent());
+        if let Some(policy) = options.get_retry_policy() {
+            prepared.set_retry_policy(Some(policy.clone()));
+        }
+        statement.prepared = Arc::new(prepared);
+        statement.fairness_class = self.options.fairness_class.clone();
+        statement.retry_policy = self.options.retry_policy.clone();
+        statement
+    }
+}
+
+/// The bound values making up the statement's partition key, in partition key order.
+pub(crate) fn partition_key_values(
+    py: Python<'_>,
+    prepared: &ScyllaPreparedStatement,
+    values: Option<&Py<PyDict>>,
+) -> Option<Py<PyAny>> {
+    let values = values?.bind(py);
+    let col_specs = prepared.get_variable_col_specs();
+    let mut pk_indexes: Vec<_> = prepared.get_variable_pk_indexes().iter().collect();
+    pk_indexes.sort_by_key(|pk| pk.sequence);
+
+    let key = PyDict::new(py);
+    for pk in pk_indexes {
+        let name = col_specs.get_by_index(pk.index as usize)?.name();
+        key.set_item(name, values.get_item(name).ok()??).ok()?;
+    }
+    Some(key.into_any().unbind())
+}
+
+/// Match named values against the statement's bind markers (`:name` markers, or the
+/// column names of `?` markers), reporting every missing and unexpected name at once.
+pub(crate) fn check_named_values(
+    prepared: &ScyllaPreparedStatement,
+    values: &HashMap<String, SerializableValue>,
+) -> PyResult<()> {
+    let mut seen = HashSet::new();
+    let markers: Vec<&str> = prepared
+        .get_variable_col_specs()
+        .iter()
+        .map(|spec| spec.name())
+        .filter(|name| seen.insert(*name))
+        .collect();
+    let missing: Vec<String> = markers
+        .iter()
+        .filter(|marker| !values.contains_key(**marker))
+        .map(|marker| format!("'{}'", marker))
+        .collect();
+    let mut unexpected: Vec<String> = values
+        .keys()
+        .filter(|name| !markers.contains(&name.as_str()))
+        .map(|name| format!("'{}'", name))
+        .collect();
+    unexpected.sort();
+
+    let mut problems = Vec::new();
+    if !missing.is_empty() {
+        problems.push(format!("missing values for {}", missing.join(", ")));
+    }
+    if !unexpected.is_empty() {
+        problems.push(format!("no bind markers named {}", unexpected.join(", ")));
+    }
+    if problems.is_empty() {
+        return Ok(());
+    }
+    Err(PyErr::new::<BindError, _>(format!(
+        "Bind error: {} (the statement binds {})",
+        problems.join("; "),
+        if markers.is_empty() {
+            "no values".to_string()
+        } else {
+            markers.join(", ")
+        }
+    )))
+}
+
+/// Refuse values that only fit their bind marker through a lossy or implicit conversion,
+/// for sessions built with `strict_types()`.
+pub(crate) fn check_value_types(
+    prepared: &ScyllaPreparedStatement,
+    values: &HashMap<String, SerializableValue>,
+) -> PyResult<()> {
+    for spec in prepared.get_variable_col_specs().iter() {
+        if let Some(value) = values.get(spec.name()) {
+            value
+                .check_type(spec.typ(), true)
+                .map_err(|err| bind_value_error_to_py(spec.name(), &err))?;
+        }
+    }
+    Ok(())
 }
 
 #[pymethods]
@@ -80,54 +442,84 @@ impl PreparedStatement {
         let cons = parse_consistency(consistency)?;
         let mut new_prepared = (*self.prepared).clone();
         new_prepared.set_consistency(cons);
-        Ok(PreparedStatement {
-            prepared: Arc::new(new_prepared),
-        })
+        Ok(self.with_prepared(new_prepared))
     }
 
     pub fn with_serial_consistency(&self, serial_consistency: &str) -> PyResult<Self> {
         let cons = parse_serial_consistency(serial_consistency)?;
         let mut new_prepared = (*self.prepared).clone();
         new_prepared.set_serial_consistency(Some(cons));
-        Ok(PreparedStatement {
-            prepared: Arc::new(new_prepared),
-        })
+        Ok(self.with_prepared(new_prepared))
     }
 
     pub fn with_page_size(&self, page_size: i32) -> PyResult<Self> {
         let mut new_prepared = (*self.prepared).clone();
         new_prepared.set_page_size(page_size);
-        Ok(PreparedStatement {
-            prepared: Arc::new(new_prepared),
-        })
+        Ok(self.with_prepared(new_prepared))
     }
 
     pub fn with_timestamp(&self, timestamp: i64) -> PyResult<Self> {
         let mut new_prepared = (*self.prepared).clone();
         new_prepared.set_timestamp(Some(timestamp));
-        Ok(PreparedStatement {
-            prepared: Arc::new(new_prepared),
-        })
+        Ok(self.with_prepared(new_prepared))
     }
 
     pub fn with_tracing(&self, tracing: bool) -> PyResult<Self> {
         let mut new_prepared = (*self.prepared).clone();
         new_prepared.set_tracing(tracing);
+        Ok(self.with_prepared(new_prepared))
+    }
+
+    pub fn with_retry_policy(&self, retry_policy: &str) -> PyResult<Self> {
+        let policy = parse_retry_policy(retry_policy)?;
+        let mut new_prepared = (*self.prepared).clone();
+        new_prepared.set_retry_policy(Some(policy));
         Ok(PreparedStatement {
-            prepared: Arc::new(new_prepared),
+            retry_policy: Some(retry_policy.to_lowercase()),
+            ..self.with_prepared(new_prepared)
         })
     }
 
+    pub fn with_execution_profile(&self, profile: &ExecutionProfile) -> PyResult<Self> {
+        let mut new_prepared = (*self.prepared).clone();
+        new_prepared.set_execution_profile_handle(Some(profile.handle()));
+        Ok(self.with_prepared(new_prepared))
+    }
+
+    #[pyo3(signature = (host, shard=None))]
+    pub fn with_host(&self, host: &str, shard: Option<u32>) -> PyResult<Self> {
+        let policy = single_target_policy(host, shard)?;
+        let mut new_prepared = (*self.prepared).clone();
+        new_prepared.set_load_balancing_policy(Some(policy));
+        Ok(self.with_prepared(new_prepared))
+    }
+
+    pub fn with_fairness_class(&self, fairness_class: &str) -> Self {
+        PreparedStatement {
+            fairness_class: Some(fairness_class.to_string()),
+            ..self.clone()
+        }
+    }
+
     pub fn is_idempotent(&self) -> bool {
         self.prepared.get_is_idempotent()
     }
 
+    pub fn get_page_size(&self) -> i32 {
+        self.prepared.get_page_size()
+    }
+
+    /// Keyspace the statement was prepared against. Statements are bound to it for good:
+    /// neither the driver nor Scylla's protocol version can override it per request, so
+    /// target another keyspace by preparing a keyspace-qualified statement instead.
+    pub fn get_keyspace(&self) -> Option<String> {
+        self.prepared.get_keyspace_name().map(str::to_string)
+    }
+
     pub fn set_idempotent(&self, idempotent: bool) -> Self {
         let mut new_prepared = (*self.prepared).clone();
         new_prepared.set_is_idempotent(idempotent);
-        PreparedStatement {
-            prepared: Arc::new(new_prepared),
-        }
+        self.with_prepared(new_prepared)
     }
 
     pub fn get_id<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
@@ -137,9 +529,225 @@ impl PreparedStatement {
     pub fn get_statement(&self) -> String {
         self.prepared.get_statement().to_string()
     }
+
+    /// The statement text and options as a dict of plain values, in the shape of
+    /// `Query.to_dict()`, which can be pickled or sent to another process and prepared
+    /// there with `Session.prepare_from()`. The execution profile and host aren't included.
+    pub fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let prepared = &self.prepared;
+        let dict = PyDict::new(py);
+        dict.set_item("query", prepared.get_statement())?;
+        dict.set_item(
+            "consistency",
+            prepared.get_consistency().map(consistency_name),
+        )?;
+        dict.set_item(
+            "serial_consistency",
+            prepared
+                .get_serial_consistency()
+                .map(serial_consistency_name),
+        )?;
+        dict.set_item("page_size", prepared.get_page_size())?;
+        dict.set_item("timestamp", prepared.get_timestamp())?;
+        dict.set_item(
+            "timeout_ms",
+            prepared
+                .get_request_timeout()
+                .map(|timeout| timeout.as_millis() as u64),
+        )?;
+        dict.set_item("tracing", prepared.get_tracing())?;
+        dict.set_item("idempotent", prepared.get_is_idempotent())?;
+        dict.set_item("retry_policy", &self.retry_policy)?;
+        dict.set_item("fairness_class", &self.fairness_class)?;
+        Ok(dict)
+    }
+
+    /// Addresses of the nodes connected when the statement was prepared. The driver
+    /// prepares on each of them but doesn't report which ones succeeded.
+    pub fn connected_at_prepare(&self) -> Vec<String> {
+        self.connected_at_prepare.clone()
+    }
+
+    pub fn prepare_attempts(&self) -> u32 {
+        self.prepare_attempts
+    }
+
+    /// One dict per bind marker, in bind order, shaped like `QueryResult.col_specs()`.
+    pub fn variable_specs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        col_specs_to_py(py, self.prepared.get_variable_col_specs())
+    }
+
+    /// The columns the statement returns, as of the latest result metadata the driver has
+    /// seen for it. Empty for statements that return no rows.
+    pub fn result_specs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        col_specs_to_py(py, self.prepared.get_current_result_set_col_specs().get())
+    }
+
+    /// Indexes into `variable_specs()` of the bind markers that make up the partition key,
+    /// in partition key order. Empty when the statement doesn't bind the whole key.
+    pub fn partition_key_indexes(&self) -> Vec<u16> {
+        let mut pk_indexes: Vec<_> = self.prepared.get_variable_pk_indexes().iter().collect();
+        pk_indexes.sort_by_key(|pk| pk.sequence);
+        pk_indexes.into_iter().map(|pk| pk.index).collect()
+    }
+
+    /// Token of the partition `values` address, or `None` when the statement doesn't bind
+    /// the whole partition key.
+    #[pyo3(signature = (values=None))]
+    pub fn compute_token(&self, values: Option<&Bound<'_, PyDict>>) -> PyResult<Option<i64>> {
+        let serialized_values = py_dict_to_serialized_values(values)?;
+        let token = self
+            .prepared
+            .calculate_token(&serialized_values)
+            .map_err(partition_key_error_to_py)?;
+        Ok(token.map(|token| token.value()))
+    }
+
+    /// The serialized partition key `values` address, as Scylla encodes it for routing.
+    #[pyo3(signature = (values=None))]
+    pub fn partition_key<'py>(
+        &self,
+        py: Python<'py>,
+        values: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let serialized_values = py_dict_to_serialized_values(values)?;
+        let key = self
+            .prepared
+            .compute_partition_key(&serialized_values)
+            .map_err(partition_key_error_to_py)?;
+        Ok(PyBytes::new(py, &key))
+    }
+
+    /// Serialize `values` against the statement's bind markers now, so a missing value or
+    /// a value of the wrong type raises `BindError` here rather than at execution. Statements
+    /// prepared by a session built with `strict_types()` also refuse lossy conversions.
+    #[pyo3(signature = (values=None))]
+    pub fn bind(&self, values: Option<&Bound<'_, PyDict>>) -> PyResult<BoundStatement> {
+        let serialized_values = py_dict_to_serialized_values(values)?;
+        check_named_values(&self.prepared, &serialized_values)?;
+        if self.strict_types {
+            check_value_types(&self.prepared, &serialized_values)?;
+        }
+        let bound = (*self.prepared)
+            .clone()
+            .bind(&serialized_values)
+            .map_err(bind_error_to_py)?;
+        Ok(BoundStatement {
+            bound: Arc::new(bound),
+            values: values.map(|values| Arc::new(values.clone().unbind())),
+            fairness_class: self.fairness_class.clone(),
+        })
+    }
 }
 
-fn parse_consistency(consistency: &str) -> PyResult<scylla::statement::Consistency> {
+/// A prepared statement with its values already serialized; execute it any number of times
+/// with `Session.execute_bound`.
+#[pyclass]
+#[derive(Clone)]
+pub struct BoundStatement {
+    pub(crate) bound: Arc<ScyllaBoundStatement>,
+    pub(crate) values: Option<Arc<Py<PyDict>>>,
+    pub(crate) fairness_class: Option<String>,
+}
+
+#[pymethods]
+impl BoundStatement {
+    pub fn get_statement(&self) -> String {
+        self.bound.prepared().get_statement().to_string()
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "BoundStatement({:?})",
+            self.bound.prepared().get_statement()
+        )
+    }
+}
+
+/// Any statement accepted by session helpers: CQL text, a `Query` or a `PreparedStatement`.
+#[derive(Clone)]
+pub enum Statement {
+    Unprepared(ScyllaQuery),
+    Prepared(Arc<ScyllaPreparedStatement>),
+}
+
+impl Statement {
+    pub fn extract(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(query) = obj.extract::<String>() {
+            return Ok(Statement::Unprepared(ScyllaQuery::new(query)));
+        }
+        if let Ok(query) = obj.cast::<Query>() {
+            return Ok(Statement::Unprepared(query.borrow().inner.clone()));
+        }
+        if let Ok(prepared) = obj.cast::<PreparedStatement>() {
+            return Ok(Statement::Prepared(prepared.borrow().prepared.clone()));
+        }
+
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(format!(
+            "Expected str, Query or PreparedStatement, got {}",
+            obj.get_type().name()?
+        )))
+    }
+
+    pub fn set_page_size(&mut self, page_size: i32) {
+        match self {
+            Statement::Unprepared(query) => query.set_page_size(page_size),
+            Statement::Prepared(prepared) => {
+                let mut new_prepared = (**prepared).clone();
+                new_prepared.set_page_size(page_size);
+                *prepared = Arc::new(new_prepared);
+            }
+        }
+    }
+
+    /// The CQL text of the statement.
+    pub fn contents(&self) -> &str {
+        match self {
+            Statement::Unprepared(query) => &query.contents,
+            Statement::Prepared(prepared) => prepared.get_statement(),
+        }
+    }
+
+    pub fn is_idempotent(&self) -> bool {
+        match self {
+            Statement::Unprepared(query) => query.get_is_idempotent(),
+            Statement::Prepared(prepared) => prepared.get_is_idempotent(),
+        }
+    }
+
+    pub async fn execute_unpaged(
+        &self,
+        session: &ScyllaSession,
+        values: impl SerializeRow,
+    ) -> Result<ScyllaQueryResult, ExecutionError> {
+        match self {
+            Statement::Unprepared(query) => session.query_unpaged(query.clone(), values).await,
+            Statement::Prepared(prepared) => session.execute_unpaged(prepared, values).await,
+        }
+    }
+
+    pub async fn execute_single_page(
+        &self,
+        session: &ScyllaSession,
+        values: impl SerializeRow,
+        paging_state: PagingState,
+    ) -> Result<(ScyllaQueryResult, PagingStateResponse), ExecutionError> {
+        match self {
+            Statement::Unprepared(query) => {
+                session
+                    .query_single_page(query.clone(), values, paging_state)
+                    .await
+            }
+            Statement::Prepared(prepared) => {
+                session
+                    .execute_single_page(prepared, values, paging_state)
+                    .await
+            }
+        }
+    }
+}
+
+pub(crate) fn parse_consistency(consistency: &str) -> PyResult<scylla::statement::Consistency> {
     match consistency.to_uppercase().as_str() {
         "ANY" => Ok(scylla::statement::Consistency::Any),
         "ONE" => Ok(scylla::statement::Consistency::One),
@@ -157,7 +765,34 @@ fn parse_consistency(consistency: &str) -> PyResult<scylla::statement::Consisten
     }
 }
 
-fn parse_serial_consistency(consistency: &str) -> PyResult<scylla::statement::SerialConsistency> {
+/// The name `parse_consistency()` reads `consistency` from.
+fn consistency_name(consistency: scylla::statement::Consistency) -> &'static str {
+    use scylla::statement::Consistency;
+    match consistency {
+        Consistency::Any => "any",
+        Consistency::One => "one",
+        Consistency::Two => "two",
+        Consistency::Three => "three",
+        Consistency::Quorum => "quorum",
+        Consistency::All => "all",
+        Consistency::LocalQuorum => "local_quorum",
+        Consistency::EachQuorum => "each_quorum",
+        Consistency::LocalOne => "local_one",
+        Consistency::Serial => "serial",
+        Consistency::LocalSerial => "local_serial",
+    }
+}
+
+fn serial_consistency_name(consistency: scylla::statement::SerialConsistency) -> &'static str {
+    match consistency {
+        scylla::statement::SerialConsistency::Serial => "serial",
+        scylla::statement::SerialConsistency::LocalSerial => "local_serial",
+    }
+}
+
+pub(crate) fn parse_serial_consistency(
+    consistency: &str,
+) -> PyResult<scylla::statement::SerialConsistency> {
     match consistency.to_uppercase().as_str() {
         "SERIAL" => Ok(scylla::statement::SerialConsistency::Serial),
         "LOCAL_SERIAL" | "LOCALSERIAL" => Ok(scylla::statement::SerialConsistency::LocalSerial),
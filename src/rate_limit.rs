@@ -0,0 +1,114 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio::time::Instant;
+
+/// Lowest accepted rate, one request every ~11.6 days, so request spacing stays far from
+/// `Duration` and `Instant` overflow.
+pub const MIN_REQUESTS_PER_SECOND: f64 = 1e-6;
+
+/// Session-wide client-side limits on request rate and concurrency. Requests wait for
+/// their turn before they are sent; a limiter without limits never waits.
+#[derive(Default)]
+pub struct RequestLimiter {
+    requests_per_second: Option<f64>,
+    max_concurrent: Option<usize>,
+    slots: Option<Arc<Semaphore>>,
+    /// When the next request may start, spacing requests `1 / requests_per_second` apart.
+    next_start: Mutex<Option<Instant>>,
+    waiting: AtomicUsize,
+    admitted: AtomicU64,
+    throttled: AtomicU64,
+}
+
+/// Counts a request as waiting until dropped, including when the caller gives up.
+struct Waiting<'a>(&'a AtomicUsize);
+
+impl<'a> Waiting<'a> {
+    fn new(waiting: &'a AtomicUsize) -> Self {
+        waiting.fetch_add(1, Ordering::Relaxed);
+        Waiting(waiting)
+    }
+}
+
+impl Drop for Waiting<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl RequestLimiter {
+    pub fn new(requests_per_second: Option<f64>, max_concurrent: Option<usize>) -> Self {
+        RequestLimiter {
+            requests_per_second,
+            max_concurrent,
+            slots: max_concurrent.map(|max| Arc::new(Semaphore::new(max))),
+            ..Default::default()
+        }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.requests_per_second.is_some() || self.max_concurrent.is_some()
+    }
+
+    /// Reserve the next start time at the configured rate and return how long to wait
+    /// for it.
+    fn reserve_start(&self) -> Option<Duration> {
+        let interval = Duration::from_secs_f64(1.0 / self.requests_per_second?);
+        let now = Instant::now();
+        let mut next_start = self.next_start.lock().unwrap();
+        let start = next_start.map_or(now, |next| next.max(now));
+        *next_start = Some(start + interval);
+        Some(start - now).filter(|wait| !wait.is_zero())
+    }
+
+    /// Wait until a request may be sent; the concurrency slot is held until the permit
+    /// drops.
+    pub async fn acquire(&self) -> Option<OwnedSemaphorePermit> {
+        if !self.is_enabled() {
+            return None;
+        }
+
+        let waiting = Waiting::new(&self.waiting);
+        let mut throttled = false;
+        let permit = match &self.slots {
+            Some(slots) => {
+                throttled = slots.available_permits() == 0;
+                slots.clone().acquire_owned().await.ok()
+            }
+            None => None,
+        };
+        if let Some(wait) = self.reserve_start() {
+            throttled = true;
+            tokio::time::sleep(wait).await;
+        }
+        drop(waiting);
+
+        self.admitted.fetch_add(1, Ordering::Relaxed);
+        if throttled {
+            self.throttled.fetch_add(1, Ordering::Relaxed);
+        }
+        permit
+    }
+
+    /// Configured limits and current usage: requests in flight and waiting, plus totals
+    /// of requests admitted and of those that had to wait.
+    pub fn usage<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let in_flight = match (&self.slots, self.max_concurrent) {
+            (Some(slots), Some(max)) => Some(max - slots.available_permits()),
+            _ => None,
+        };
+
+        let dict = PyDict::new(py);
+        dict.set_item("requests_per_second", self.requests_per_second)?;
+        dict.set_item("max_concurrent", self.max_concurrent)?;
+        dict.set_item("in_flight", in_flight)?;
+        dict.set_item("waiting", self.waiting.load(Ordering::Relaxed))?;
+        dict.set_item("admitted", self.admitted.load(Ordering::Relaxed))?;
+        dict.set_item("throttled", self.throttled.load(Ordering::Relaxed))?;
+        Ok(dict)
+    }
+}
@@ -1,66 +1,391 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
-use scylla::response::query_result::{QueryResult as ScyllaQueryResult, QueryRowsResult};
+use pyo3::types::{PyBytes, PyDict, PyIterator, PyList, PySlice, PyTuple};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::response::query_result::{
+    ColumnSpecs, QueryResult as ScyllaQueryResult, QueryRowsResult,
+};
 use scylla::value::{CqlValue, Row as ScyllaRow};
+use std::ffi::CString;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use tokio::sync::OnceCell;
+use uuid::Uuid;
 
-use crate::types::cql_value_to_py;
+use crate::arrow::{record_batch, to_pyarrow};
+use crate::columnar::{decode_column, decode_columns, Column};
+use crate::dataframe;
+use crate::error::{tracing_error_to_py, ScyllaWarning};
+use crate::json::row_to_json;
+use crate::metadata::CqlType;
+use crate::models::ModelSpec;
+use crate::observer::RequestStats;
+use crate::runtime::future_into_py;
+use crate::tracing_info::TracingInfo;
+use crate::types::{cql_value_to_py, py_to_cql_value};
+
+/// Where server warnings attached to responses are surfaced, besides `QueryResult.warnings()`.
+#[derive(Clone, Default)]
+pub struct WarningSink {
+    emit: bool,
+    collected: Option<Arc<Mutex<Vec<String>>>>,
+}
+
+impl WarningSink {
+    pub fn new(emit: bool, collect: bool) -> Self {
+        WarningSink {
+            emit,
+            collected: collect.then(Default::default),
+        }
+    }
+
+    pub fn report(&self, result: &QueryResult) -> PyResult<()> {
+        if let Some(collected) = &self.collected {
+            if let Ok(mut collected) = collected.lock() {
+                collected.extend(result.warnings.iter().cloned());
+            }
+        }
+        if self.emit {
+            result.emit_warnings()?;
+        }
+        Ok(())
+    }
+
+    /// Drain the warnings accumulated so far; empty when collection is disabled.
+    pub fn take(&self) -> Vec<String> {
+        self.collected
+            .as_ref()
+            .and_then(|collected| collected.lock().ok().map(|mut c| std::mem::take(&mut *c)))
+            .unwrap_or_default()
+    }
+}
+
+/// How result rows are handed to Python: `Row` objects unless a factory is configured.
+#[derive(Clone)]
+pub enum RowFactory {
+    Tuple,
+    Dict,
+    NamedTuple,
+    Callable(Arc<Py<PyAny>>),
+}
+
+impl RowFactory {
+    /// Accepts `"tuple"`, `"dict"`, `"namedtuple"` or a callable taking `(names, values)`.
+    pub fn from_py(factory: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(name) = factory.extract::<String>() {
+            return match name.to_lowercase().as_str() {
+                "tuple" => Ok(RowFactory::Tuple),
+                "dict" => Ok(RowFactory::Dict),
+                "namedtuple" => Ok(RowFactory::NamedTuple),
+                _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Invalid row factory: {}. Must be 'tuple', 'dict', 'namedtuple' or a callable",
+                    name
+                ))),
+            };
+        }
+        if factory.is_callable() {
+            return Ok(RowFactory::Callable(Arc::new(factory.clone().unbind())));
+        }
+        Err(PyErr::new::<pyo3::exceptions::PyTypeError, _>(
+            "Row factory must be 'tuple', 'dict', 'namedtuple' or a callable",
+        ))
+    }
+}
+
+/// One dict per column: keyspace, table, name and `CqlType`.
+pub(crate) fn col_specs_to_py<'py>(
+    py: Python<'py>,
+    specs: ColumnSpecs<'_, '_>,
+) -> PyResult<Bound<'py, PyList>> {
+    let py_list = PyList::empty(py);
+    for spec in specs.iter() {
+        let dict = PyDict::new(py);
+        dict.set_item("keyspace", spec.table_spec().ks_name())?;
+        dict.set_item("table", spec.table_spec().table_name())?;
+        dict.set_item("name", spec.name())?;
+        dict.set_item("typ", CqlType::from(spec.typ()))?;
+        py_list.append(dict)?;
+    }
+    Ok(py_list)
+}
+
+/// Kind of RESULT response a statement produced.
+#[derive(Clone, Copy, PartialEq)]
+enum ResultKind {
+    Rows,
+    Void,
+    SetKeyspace,
+    SchemaChange,
+}
+
+impl ResultKind {
+    /// The kind of a response without rows, told from the statement that produced it: the
+    /// driver folds `SET_KEYSPACE` and `SCHEMA_CHANGE` responses into an empty result.
+    fn of_statement(cql: &str) -> Self {
+        let mut words = cql
+            .split_whitespace()
+            .map(|word| word.trim_end_matches(';').to_ascii_uppercase());
+        match (words.next().as_deref(), words.next().as_deref()) {
+            (Some("USE"), _) => ResultKind::SetKeyspace,
+            (
+                Some("CREATE" | "ALTER" | "DROP"),
+                Some(
+                    "KEYSPACE" | "SCHEMA" | "TABLE" | "COLUMNFAMILY" | "TYPE" | "FUNCTION"
+                    | "AGGREGATE" | "INDEX" | "CUSTOM" | "MATERIALIZED" | "TRIGGER" | "OR",
+                ),
+            ) => ResultKind::SchemaChange,
+            _ => ResultKind::Void,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            ResultKind::Rows => "rows",
+            ResultKind::Void => "void",
+            ResultKind::SetKeyspace => "set_keyspace",
+            ResultKind::SchemaChange => "schema_change",
+        }
+    }
+}
 
 #[pyclass]
 pub struct QueryResult {
     // Store the rows result if available
     rows_result: Option<QueryRowsResult>,
-    tracing_id: Option<String>,
+    kind: ResultKind,
+    tracing_id: Option<Uuid>,
+    /// Session to fetch the trace from, kept for traced results only.
+    tracer: Option<Arc<ScyllaSession>>,
+    /// The trace once fetched by `trace()`, shared with copies of the result.
+    trace: Arc<OnceCell<TracingInfo>>,
+    stats: Option<Arc<RequestStats>>,
     warnings: Vec<String>,
-    current_row: usize,
+    /// Cursor for `__next__`, atomic so threads sharing the result each get distinct rows.
+    current_row: AtomicUsize,
+    row_factory: Option<RowFactory>,
+    row_type: OnceLock<Py<PyAny>>,
+    /// Where the page after this one starts, for results fetched a page at a time.
+    paging_state: Option<Vec<u8>>,
 }
 
 impl QueryResult {
     pub fn new(result: ScyllaQueryResult) -> Self {
-        let tracing_id = result.tracing_id().map(|id| id.to_string());
+        let tracing_id = result.tracing_id();
         let warnings: Vec<String> = result.warnings().map(|s| s.to_string()).collect();
         let rows_result = result.into_rows_result().ok();
+        let kind = match rows_result {
+            Some(_) => ResultKind::Rows,
+            None => ResultKind::Void,
+        };
 
         QueryResult {
             rows_result,
+            kind,
             tracing_id,
+            tracer: None,
+            trace: Arc::default(),
+            stats: None,
             warnings,
-            current_row: 0,
+            current_row: AtomicUsize::new(0),
+            row_factory: None,
+            row_type: OnceLock::new(),
+            paging_state: None,
         }
     }
-}
 
-#[pymethods]
-impl QueryResult {
-    pub fn rows(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let py_list = PyList::empty(py);
+    pub fn with_paging_state(mut self, paging_state: Option<Vec<u8>>) -> Self {
+        self.paging_state = paging_state;
+        self
+    }
 
-        if let Some(ref rows_result) = self.rows_result {
-            let rows: Vec<ScyllaRow> = rows_result
-                .rows()
-                .map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?;
+    pub fn with_row_factory(mut self, row_factory: Option<RowFactory>) -> Self {
+        self.row_factory = row_factory;
+        self
+    }
 
-            for row in rows {
-                let py_row = Py::new(py, Row::new(&row))?;
-                py_list.append(py_row)?;
+    /// Keep `session` to fetch the trace from, if the request was traced.
+    pub fn with_session(mut self, session: &Arc<ScyllaSession>) -> Self {
+        if self.tracing_id.is_some() {
+            self.tracer = Some(session.clone());
+        }
+        self
+    }
+
+    pub fn with_stats(mut self, stats: Option<RequestStats>) -> Self {
+        self.stats = stats.map(Arc::new);
+        self
+    }
+
+    /// Record the statement the result answers, so `kind` can tell a `USE` or schema
+    /// change apart from other statements without rows.
+    pub fn with_statement(mut self, cql: &str) -> Self {
+        if self.kind != ResultKind::Rows {
+            self.kind = ResultKind::of_statement(cql);
+        }
+        self
+    }
+
+    /// Convert a row with the configured row factory.
+    fn build_row(&self, py: Python, names: &Arc<[String]>, row: ScyllaRow) -> PyResult<Py<PyAny>> {
+        let row = Row::with_names(row, names.clone());
+        let Some(row_factory) = &self.row_factory else {
+            return Ok(Py::new(py, row)?.into_any());
+        };
+
+        let values = PyTuple::new(py, row.py_values(py)?)?;
+        match row_factory {
+            RowFactory::Tuple => Ok(values.into_any().unbind()),
+            RowFactory::Dict => {
+                let dict = PyDict::new(py);
+                for (name, value) in names.iter().zip(values.iter()) {
+                    dict.set_item(name, value)?;
+                }
+                Ok(dict.into_any().unbind())
             }
+            RowFactory::NamedTuple => {
+                let row_type = match self.row_type.get() {
+                    Some(row_type) => row_type,
+                    None => {
+                        let row_type = namedtuple_type(py, names)?.unbind();
+                        self.row_type.get_or_init(|| row_type)
+                    }
+                };
+                Ok(row_type.call1(py, values)?)
+            }
+            RowFactory::Callable(factory) => factory.call1(py, (names.to_vec(), values)),
         }
+    }
 
-        Ok(py_list.into())
+    fn shared_column_names(&self) -> Arc<[String]> {
+        self.column_names().into()
     }
 
-    pub fn first_row(&self) -> PyResult<Option<Row>> {
+    pub(crate) fn column_names(&self) -> Vec<String> {
+        self.rows_result
+            .as_ref()
+            .map(|rows_result| {
+                rows_result
+                    .column_specs()
+                    .iter()
+                    .map(|spec| spec.name().to_string())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn scylla_rows(&self) -> PyResult<Vec<ScyllaRow>> {
+        let Some(ref rows_result) = self.rows_result else {
+            return Ok(Vec::new());
+        };
+
+        rows_result
+            .rows()
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Row deserialization error: {}",
+                    e
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Row deserialization error: {}",
+                    e
+                ))
+            })
+    }
+
+    /// Deserialize every row with the GIL released, so other Python threads keep running
+    /// while a large result is decoded; only building Python objects needs the GIL.
+    fn decode_rows(&self, py: Python) -> PyResult<Vec<ScyllaRow>> {
+        match self.rows_result {
+            Some(_) => py.detach(|| self.scylla_rows()),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// One model instance per row, leaving out rows whose `skip_if_set` column holds a value.
+    pub(crate) fn build_models(
+        &self,
+        py: Python,
+        model: &ModelSpec,
+        skip_if_set: Option<&str>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let names = self.column_names();
+        let skip_index = skip_if_set.and_then(|column| names.iter().position(|n| n == column));
+
+        self.decode_rows(py)?
+            .iter()
+            .filter(|row| skip_index.is_none_or(|index| row.columns[index].is_none()))
+            .map(|row| model.build(py, &names, Row::new(row).py_values(py)?))
+            .collect()
+    }
+
+    /// Decode the result column by column, with the GIL released.
+    pub(crate) fn columns(&self, py: Python) -> PyResult<(Vec<Column>, usize)> {
+        let Some(ref rows_result) = self.rows_result else {
+            return Ok((Vec::new(), 0));
+        };
+
+        py.detach(|| {
+            let rows = self.scylla_rows()?;
+            let num_rows = rows.len();
+            Ok((
+                decode_columns(rows_result.column_specs().as_slice(), rows),
+                num_rows,
+            ))
+        })
+    }
+
+    fn first_scylla_row(&self) -> PyResult<Option<ScyllaRow>> {
+        let Some(ref rows_result) = self.rows_result else {
+            return Ok(None);
+        };
+
+        let mut rows_iter = rows_result.rows::<ScyllaRow>().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Row deserialization error: {}",
+                e
+            ))
+        })?;
+        rows_iter.next().transpose().map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Row deserialization error: {}",
+                e
+            ))
+        })
+    }
+
+    /// Re-emit server warnings through Python's `warnings` module as `ScyllaWarning`.
+    pub fn emit_warnings(&self) -> PyResult<()> {
+        if self.warnings.is_empty() {
+            return Ok(());
+        }
+
+        Python::attach(|py| {
+            let category = py.get_type::<ScyllaWarning>();
+            for warning in &self.warnings {
+                let message = CString::new(warning.replace('\0', "")).unwrap_or_default();
+                PyErr::warn(py, &category, &message, 1)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+#[pymethods]
+impl QueryResult {
+    pub fn rows(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let names = self.shared_column_names();
+        let rows = self
+            .decode_rows(py)?
+            .into_iter()
+            .map(|row| self.build_row(py, &names, row))
+            .collect::<PyResult<Vec<_>>>()?;
+
+        Ok(PyList::new(py, rows)?.into())
+    }
+
+    pub fn first_row(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
         if let Some(ref rows_result) = self.rows_result {
             let mut rows_iter = rows_result.rows().map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
@@ -76,7 +401,11 @@ impl QueryResult {
                         e
                     ))
                 })?;
-                Ok(Some(Row::new(&row)))
+                Ok(Some(self.build_row(
+                    py,
+                    &self.shared_column_names(),
+                    row,
+                )?))
             } else {
                 Ok(None)
             }
@@ -85,26 +414,13 @@ impl QueryResult {
         }
     }
 
-    pub fn single_row(&self) -> PyResult<Row> {
-        if let Some(ref rows_result) = self.rows_result {
-            let rows: Vec<ScyllaRow> = rows_result
-                .rows()
-                .map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?;
+    pub fn single_row(&self, py: Python) -> PyResult<Py<PyAny>> {
+        if self.rows_result.is_some() {
+            let rows = self.decode_rows(py)?;
 
             if rows.len() == 1 {
-                Ok(Row::new(&rows[0]))
+                let row = rows.into_iter().next().expect("one row");
+                self.build_row(py, &self.shared_column_names(), row)
             } else if rows.is_empty() {
                 Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
                     "No rows returned",
@@ -122,102 +438,264 @@ impl QueryResult {
         }
     }
 
-    pub fn first_row_typed(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
-        if let Some(ref rows_result) = self.rows_result {
-            let mut rows_iter = rows_result.rows().map_err(|e| {
-                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                    "Row deserialization error: {}",
-                    e
-                ))
-            })?;
+    /// Move every row out of the result, converting each exactly once; the result is
+    /// empty afterwards. Cheaper than `rows()` for large results that are read only once.
+    pub fn take_rows(&mut self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        let names = self.shared_column_names();
+        let rows = self.decode_rows(py)?;
+        self.rows_result = None;
+        *self.current_row.get_mut() = 0;
 
-            if let Some(row_result) = rows_iter.next() {
-                let row: ScyllaRow = row_result.map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?;
-                let py_row = Row::new(&row);
-                Ok(Some(py_row.as_dict(py)?))
-            } else {
-                Ok(None)
-            }
-        } else {
-            Ok(None)
+        rows.into_iter()
+            .map(|row| self.build_row(py, &names, row))
+            .collect()
+    }
+
+    /// First column of the first row, or `None` for an empty result.
+    pub fn scalar(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match self.first_scylla_row()? {
+            Some(row) => match row.columns.first() {
+                Some(Some(val)) => cql_value_to_py(py, val),
+                _ => Ok(py.None()),
+            },
+            None => Ok(py.None()),
         }
     }
 
-    pub fn rows_typed(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
-        let mut result = Vec::new();
+    /// First row as a dict keyed by column name, or `None` for an empty result.
+    #[pyo3(signature = (omit_nulls=false))]
+    pub fn maybe_first_row_typed(
+        &self,
+        py: Python,
+        omit_nulls: bool,
+    ) -> PyResult<Option<Py<PyAny>>> {
+        self.first_row_typed(py, omit_nulls)
+    }
 
-        if let Some(ref rows_result) = self.rows_result {
-            let rows: Vec<ScyllaRow> = rows_result
-                .rows()
-                .map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?
-                .collect::<Result<Vec<_>, _>>()
-                .map_err(|e| {
-                    PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                        "Row deserialization error: {}",
-                        e
-                    ))
-                })?;
+    #[pyo3(signature = (omit_nulls=false))]
+    pub fn first_row_typed(&self, py: Python, omit_nulls: bool) -> PyResult<Option<Py<PyAny>>> {
+        let Some(row) = self.first_scylla_row()? else {
+            return Ok(None);
+        };
+        Row::with_names(row, self.shared_column_names())
+            .as_dict(py, omit_nulls)
+            .map(Some)
+    }
 
-            for row in rows {
-                let py_row = Row::new(&row);
-                result.push(py_row.as_dict(py)?);
-            }
-        }
+    #[pyo3(signature = (omit_nulls=false))]
+    pub fn rows_typed(&self, py: Python, omit_nulls: bool) -> PyResult<Vec<Py<PyAny>>> {
+        let names = self.shared_column_names();
+        self.decode_rows(py)?
+            .into_iter()
+            .map(|row| Row::with_names(row, names.clone()).as_dict(py, omit_nulls))
+            .collect()
+    }
+
+    /// Rows as `collections.namedtuple` instances with one field per column; column names
+    /// that aren't valid identifiers become positional names (`_0`, `_1`, ...).
+    pub fn rows_named(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        let row_type = namedtuple_type(py, &self.column_names())?;
 
-        Ok(result)
+        self.decode_rows(py)?
+            .iter()
+            .map(|row| {
+                let values = PyTuple::new(py, Row::new(row).py_values(py)?)?;
+                Ok(row_type.call1(values)?.unbind())
+            })
+            .collect()
     }
 
-    pub fn col_specs(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let py_list = PyList::empty(py);
+    /// Rows as instances of a dataclass or pydantic model, matching columns to fields by name.
+    pub fn rows_as(&self, py: Python, cls: &Bound<'_, PyAny>) -> PyResult<Vec<Py<PyAny>>> {
+        self.build_models(py, &ModelSpec::new(py, cls)?, None)
+    }
 
-        if let Some(ref rows_result) = self.rows_result {
-            let specs = rows_result.column_specs();
-            for spec in specs.iter() {
-                let dict = PyDict::new(py);
-                dict.set_item("table_spec", format!("{:?}", spec.table_spec()))?;
-                dict.set_item("name", spec.name().to_string())?;
-                dict.set_item("typ", format!("{:?}", spec.typ()))?;
-                py_list.append(dict)?;
-            }
+    /// All rows as a JSON array of objects keyed by column name, serialized without
+    /// building Python objects and with the GIL released.
+    pub fn to_json(&self, py: Python) -> PyResult<String> {
+        let names = self.column_names();
+        let rows = self.decode_rows(py)?;
+        Ok(py.detach(|| {
+            serde_json::Value::Array(
+                rows.iter()
+                    .map(|row| row_to_json(&names, &row.columns))
+                    .collect(),
+            )
+            .to_string()
+        }))
+    }
+
+    /// The result as a `pyarrow.RecordBatch` with one typed column per result column,
+    /// passed over the Arrow C data interface. Collections, tuples, UDTs and durations
+    /// are exported as JSON text.
+    pub fn to_arrow<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let (columns, num_rows) = self.columns(py)?;
+        let batch = py.detach(|| record_batch(columns, num_rows))?;
+        to_pyarrow(py, batch)
+    }
+
+    /// One int, float or boolean column as a numpy array, built without per-row objects.
+    /// Float nulls become NaN; integer and boolean columns with nulls come back as
+    /// `numpy.ma.MaskedArray`.
+    pub fn column_as_numpy<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        let specs = self
+            .rows_result
+            .as_ref()
+            .map(|rows_result| rows_result.column_specs());
+        let Some((index, spec)) = specs.and_then(|specs| specs.get_by_name(name)) else {
+            return Err(PyErr::new::<pyo3::exceptions::PyKeyError, _>(
+                name.to_string(),
+            ));
+        };
+
+        let data = py.detach(|| Ok::<_, PyErr>(decode_column(spec, index, self.scylla_rows()?)))?;
+        dataframe::numpy_column(py, name, data)
+    }
+
+    /// The result as a `polars.DataFrame`, imported from the same Arrow batch `to_arrow`
+    /// builds, so nested CQL values arrive as JSON text.
+    pub fn to_polars<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let polars = py.import("polars").map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyImportError, _>(
+                "This conversion requires the 'polars' package",
+            )
+        })?;
+        polars.call_method1("from_arrow", (self.to_arrow(py)?,))
+    }
+
+    /// The result as a `pandas.DataFrame`. Numeric columns are built as contiguous numpy
+    /// arrays (masked pandas arrays when they hold nulls), so no per-row objects are made.
+    pub fn to_pandas<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let (columns, _) = self.columns(py)?;
+        dataframe::to_pandas(py, columns)
+    }
+
+    pub fn col_specs(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match self.rows_result {
+            Some(ref rows_result) => Ok(col_specs_to_py(py, rows_result.column_specs())?.into()),
+            None => Ok(PyList::empty(py).into()),
         }
+    }
+
+    /// The rows with their values converted, detached from the driver so they can be
+    /// pickled or kept after the session is gone.
+    pub fn snapshot(&self, py: Python) -> PyResult<ResultSnapshot> {
+        let names = self.shared_column_names();
+        let rows = self
+            .decode_rows(py)?
+            .into_iter()
+            .map(|row| {
+                let row = Row::with_names(row, names.clone());
+                row.py_values(py)?;
+                Py::new(py, row)
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(ResultSnapshot { names, rows })
+    }
+
+    /// Results pickle as their `snapshot()`.
+    pub fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        self.snapshot(py)?.reduce(py)
+    }
+
+    /// `"rows"`, `"void"`, `"set_keyspace"` or `"schema_change"`.
+    #[getter]
+    pub fn kind(&self) -> &'static str {
+        self.kind.name()
+    }
+
+    /// Whether the statement returns rows, even if none matched.
+    pub fn is_rows(&self) -> bool {
+        self.rows_result.is_some()
+    }
 
-        Ok(py_list.into())
+    /// Number of rows returned, or `None` when the statement doesn't return rows.
+    #[getter]
+    pub fn rows_num(&self) -> Option<usize> {
+        self.rows_result
+            .as_ref()
+            .map(|rows_result| rows_result.rows_num())
     }
 
     pub fn tracing_id(&self) -> Option<String> {
-        self.tracing_id.clone()
+        self.tracing_id.map(|id| id.to_string())
+    }
+
+    /// How the request was carried out: `latency_ms` from sending to the response,
+    /// `attempts` including retries and speculative executions, the `coordinator` address
+    /// and `shard`, the connection `compression`, and `rows_bytes`, the serialized size of
+    /// the returned rows. `None` for results of requests that don't record it, such as
+    /// pages of a pager or `execute_with_retry()`.
+    pub fn stats<'py>(&self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let Some(stats) = &self.stats else {
+            return Ok(None);
+        };
+        let dict = PyDict::new(py);
+        dict.set_item("latency_ms", stats.latency.as_secs_f64() * 1000.0)?;
+        dict.set_item("attempts", stats.attempts)?;
+        dict.set_item("coordinator", &stats.coordinator)?;
+        dict.set_item("shard", stats.shard)?;
+        dict.set_item("compression", stats.compression)?;
+        dict.set_item(
+            "rows_bytes",
+            self.rows_result
+                .as_ref()
+                .map_or(0, |rows_result| rows_result.rows_bytes_size()),
+        )?;
+        Ok(Some(dict))
+    }
+
+    /// The trace of a request run with tracing on: its events with their source node,
+    /// activity and elapsed time. Fetched on the first call, which waits for the trace
+    /// to be written, and cached after; resolves to `None` when the request wasn't traced.
+    pub fn trace<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let target = self.tracing_id.zip(self.tracer.clone());
+        let trace = self.trace.clone();
+
+        future_into_py(py, async move {
+            let Some((tracing_id, session)) = target else {
+                return Ok(None);
+            };
+            let trace = trace
+                .get_or_try_init(|| async {
+                    let info = session
+                        .get_tracing_info(&tracing_id)
+                        .await
+                        .map_err(tracing_error_to_py)?;
+                    Ok::<_, PyErr>(TracingInfo::from(info))
+                })
+                .await?;
+            Ok(Some(trace.clone()))
+        })
     }
 
     pub fn warnings(&self) -> Vec<String> {
         self.warnings.clone()
     }
 
+    /// Paging state of the next page when this result is one page of a larger one;
+    /// `None` for unpaged results and the last page.
+    pub fn paging_state<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyBytes>> {
+        self.paging_state
+            .as_ref()
+            .map(|bytes| PyBytes::new(py, bytes))
+    }
+
     pub fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
         slf
     }
 
-    pub fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<Row> {
-        if let Some(ref rows_result) = slf.rows_result {
+    pub fn __next__(&self, py: Python) -> PyResult<Option<Py<PyAny>>> {
+        if let Some(ref rows_result) = self.rows_result {
             if let Ok(rows) = rows_result.rows::<ScyllaRow>() {
-                let rows_vec: Vec<ScyllaRow> = rows.filter_map(|r| r.ok()).collect();
-                if slf.current_row < rows_vec.len() {
-                    let row = Row::new(&rows_vec[slf.current_row]);
-                    slf.current_row += 1;
-                    return Some(row);
+                let index = self.current_row.fetch_add(1, Ordering::Relaxed);
+                if let Some(row) = rows.filter_map(|r| r.ok()).nth(index) {
+                    let row = self.build_row(py, &self.shared_column_names(), row)?;
+                    return Ok(Some(row));
                 }
             }
         }
-        None
+        Ok(None)
     }
 
     pub fn __len__(&self) -> usize {
@@ -237,45 +715,223 @@ impl QueryResult {
     }
 }
 
-#[pyclass]
+/// Column names and converted rows of a result, detached from the driver. Unlike a
+/// `QueryResult` it can be pickled, e.g. to pass it through a multiprocessing queue or
+/// store it in a cache.
+#[pyclass(module = "rsylla")]
+pub struct ResultSnapshot {
+    names: Arc<[String]>,
+    rows: Vec<Py<Row>>,
+}
+
+impl ResultSnapshot {
+    fn reduce<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.borrow(py).py_values(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        (py.get_type::<ResultSnapshot>(), (self.names.to_vec(), rows)).into_pyobject(py)
+    }
+}
+
+#[pymethods]
+impl ResultSnapshot {
+    #[new]
+    pub fn new(
+        py: Python,
+        columns: Vec<String>,
+        rows: Vec<Vec<Bound<'_, PyAny>>>,
+    ) -> PyResult<Self> {
+        let names: Arc<[String]> = columns.into();
+        let rows = rows
+            .into_iter()
+            .map(|values| Py::new(py, Row::from_py_values(values, Some(names.clone()))?))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(ResultSnapshot { names, rows })
+    }
+
+    pub fn columns(&self) -> Vec<String> {
+        self.names.to_vec()
+    }
+
+    pub fn rows(&self, py: Python) -> Vec<Py<Row>> {
+        self.rows.iter().map(|row| row.clone_ref(py)).collect()
+    }
+
+    pub fn first_row(&self, py: Python) -> Option<Py<Row>> {
+        self.rows.first().map(|row| row.clone_ref(py))
+    }
+
+    pub fn __reduce__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyTuple>> {
+        self.reduce(py)
+    }
+
+    pub fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        PyList::new(py, self.rows(py))?.try_iter()
+    }
+
+    pub fn __len__(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn __getitem__(&self, py: Python, index: isize) -> PyResult<Py<Row>> {
+        let len = self.rows.len() as isize;
+        let idx = if index < 0 { len + index } else { index };
+        match usize::try_from(idx).ok().and_then(|idx| self.rows.get(idx)) {
+            Some(row) => Ok(row.clone_ref(py)),
+            None => Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
+                "Row index {} out of range",
+                index
+            ))),
+        }
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "ResultSnapshot(columns={}, rows={})",
+            self.names.len(),
+            self.rows.len()
+        )
+    }
+}
+
+/// Build a `namedtuple` class named `Row` over the given column names.
+pub(crate) fn namedtuple_type<'py>(
+    py: Python<'py>,
+    names: &[String],
+) -> PyResult<Bound<'py, PyAny>> {
+    let kwargs = PyDict::new(py);
+    kwargs.set_item("rename", true)?;
+    py.import("collections")?
+        .getattr("namedtuple")?
+        .call(("Row", names.to_vec()), Some(&kwargs))
+}
+
+#[pyclass(module = "rsylla")]
 #[derive(Clone)]
 pub struct Row {
     columns: Vec<Option<CqlValue>>,
+    names: Option<Arc<[String]>>,
+    /// Python values, converted on first access to each column.
+    converted: Arc<[OnceLock<Py<PyAny>>]>,
 }
 
 impl Row {
     pub fn new(row: &ScyllaRow) -> Self {
+        Self::from_columns(row.columns.clone(), None)
+    }
+
+    /// A row that also answers lookups by column name.
+    pub fn with_names(row: ScyllaRow, names: Arc<[String]>) -> Self {
+        Self::from_columns(row.columns, Some(names))
+    }
+
+    fn from_columns(columns: Vec<Option<CqlValue>>, names: Option<Arc<[String]>>) -> Self {
         Row {
-            columns: row.columns.clone(),
+            converted: columns.iter().map(|_| OnceLock::new()).collect(),
+            columns,
+            names,
         }
     }
+
+    /// A row holding already converted values, as rebuilt when unpickling.
+    fn from_py_values(
+        values: Vec<Bound<'_, PyAny>>,
+        names: Option<Arc<[String]>>,
+    ) -> PyResult<Self> {
+        if let Some(names) = &names {
+            if names.len() != values.len() {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Row has {} values but {} column names",
+                    values.len(),
+                    names.len()
+                )));
+            }
+        }
+        let columns = values
+            .iter()
+            .map(|value| {
+                if value.is_none() {
+                    Ok(None)
+                } else {
+                    py_to_cql_value(value).map(Some)
+                }
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(Row {
+            converted: values
+                .into_iter()
+                .map(|value| OnceLock::from(value.unbind()))
+                .collect(),
+            columns,
+            names,
+        })
+    }
+
+    pub(crate) fn cql_values(&self) -> &[Option<CqlValue>] {
+        &self.columns
+    }
+
+    fn column_value(&self, py: Python, index: usize) -> PyResult<Py<PyAny>> {
+        if let Some(value) = self.converted[index].get() {
+            return Ok(value.clone_ref(py));
+        }
+
+        let value = match &self.columns[index] {
+            Some(val) => cql_value_to_py(py, val)?,
+            None => py.None(),
+        };
+        Ok(self.converted[index].get_or_init(|| value).clone_ref(py))
+    }
+
+    fn index_of(&self, name: &str) -> Option<usize> {
+        self.names
+            .as_ref()?
+            .iter()
+            .position(|column| column == name)
+    }
+
+    pub(crate) fn py_values(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        (0..self.columns.len())
+            .map(|index| self.column_value(py, index))
+            .collect()
+    }
 }
 
 #[pymethods]
 impl Row {
+    /// A row of plain Python values, optionally named; rows pickle as their values and
+    /// column names.
+    #[new]
+    #[pyo3(signature = (values, names=None))]
+    pub fn py_new(values: Vec<Bound<'_, PyAny>>, names: Option<Vec<String>>) -> PyResult<Self> {
+        Row::from_py_values(values, names.map(Into::into))
+    }
+
+    pub fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyTuple>> {
+        let py = slf.py();
+        let row = slf.borrow();
+        let names = row.names.as_ref().map(|names| names.to_vec());
+        (slf.get_type(), (row.py_values(py)?, names)).into_pyobject(py)
+    }
+
     pub fn columns(&self, py: Python) -> PyResult<Py<PyAny>> {
-        let py_list = PyList::empty(py);
-        for column in &self.columns {
-            let value = match column {
-                Some(val) => cql_value_to_py(py, val)?,
-                None => py.None(),
-            };
-            py_list.append(value)?;
-        }
-        Ok(py_list.into())
+        Ok(PyList::new(py, self.py_values(py)?)?.into())
     }
 
-    pub fn as_dict(&self, py: Python) -> PyResult<Py<PyAny>> {
+    /// The row as a dict keyed by the names `keys()` returns: column names for rows read
+    /// from a result, `col_<index>` otherwise. With `omit_nulls`, NULL columns are left
+    /// out of the dict instead of mapping to `None`, so a `None` left in it is an empty
+    /// value, never a NULL.
+    #[pyo3(signature = (omit_nulls=false))]
+    pub fn as_dict(&self, py: Python, omit_nulls: bool) -> PyResult<Py<PyAny>> {
         let dict = PyDict::new(py);
-
-        // Note: In a real implementation, you'd need column names from the result metadata
-        // For now, we'll use indices as keys
-        for (i, column) in self.columns.iter().enumerate() {
-            let value = match column {
-                Some(val) => cql_value_to_py(py, val)?,
-                None => py.None(),
-            };
-            dict.set_item(format!("col_{}", i), value)?;
+        for (i, (name, column)) in self.keys().into_iter().zip(&self.columns).enumerate() {
+            if omit_nulls && column.is_none() {
+                continue;
+            }
+            dict.set_item(name, self.column_value(py, i)?)?;
         }
 
         Ok(dict.into())
@@ -283,10 +939,7 @@ impl Row {
 
     pub fn get(&self, py: Python, index: usize) -> PyResult<Py<PyAny>> {
         if index < self.columns.len() {
-            match &self.columns[index] {
-                Some(val) => cql_value_to_py(py, val),
-                None => Ok(py.None()),
-            }
+            self.column_value(py, index)
         } else {
             Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
                 "Column index {} out of range",
@@ -299,7 +952,73 @@ impl Row {
         self.columns.len()
     }
 
-    pub fn __getitem__(&self, py: Python, index: isize) -> PyResult<Py<PyAny>> {
+    /// Column names, or `col_<index>` when the row was built without result metadata.
+    pub fn keys(&self) -> Vec<String> {
+        match &self.names {
+            Some(names) => names.to_vec(),
+            None => (0..self.columns.len())
+                .map(|i| format!("col_{}", i))
+                .collect(),
+        }
+    }
+
+    /// The row as a JSON object keyed by the names `keys()` returns.
+    pub fn to_json(&self) -> String {
+        row_to_json(&self.keys(), &self.columns).to_string()
+    }
+
+    pub fn values(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        self.py_values(py)
+    }
+
+    pub fn items(&self, py: Python) -> PyResult<Vec<(String, Py<PyAny>)>> {
+        Ok(self.keys().into_iter().zip(self.py_values(py)?).collect())
+    }
+
+    /// `row.user_id`; methods such as `get` or `keys` take precedence over same-named columns.
+    pub fn __getattr__(&self, py: Python, name: &str) -> PyResult<Py<PyAny>> {
+        match self.index_of(name) {
+            Some(index) => self.column_value(py, index),
+            None => Err(PyErr::new::<pyo3::exceptions::PyAttributeError, _>(
+                format!("Row has no column '{}'", name),
+            )),
+        }
+    }
+
+    /// Position of the column `name` among `keys()`.
+    pub fn index(&self, name: &str) -> PyResult<usize> {
+        self.keys()
+            .iter()
+            .position(|column| column == name)
+            .ok_or_else(|| {
+                PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                    "Row has no column '{}'",
+                    name
+                ))
+            })
+    }
+
+    /// Index by position (negative counts from the end), by column name, or by slice,
+    /// which returns a tuple of the selected values.
+    pub fn __getitem__(&self, py: Python, key: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        if let Ok(name) = key.extract::<String>() {
+            return match self.index_of(&name) {
+                Some(index) => self.column_value(py, index),
+                None => Err(PyErr::new::<pyo3::exceptions::PyKeyError, _>(name)),
+            };
+        }
+
+        if let Ok(slice) = key.cast::<PySlice>() {
+            let indices = slice.indices(self.columns.len() as isize)?;
+            let values = (0..indices.slicelength)
+                .map(|i| {
+                    self.column_value(py, (indices.start + i as isize * indices.step) as usize)
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            return Ok(PyTuple::new(py, values)?.into_any().unbind());
+        }
+
+        let index: isize = key.extract()?;
         let len = self.columns.len() as isize;
         let idx = if index < 0 {
             (len + index) as usize
@@ -308,10 +1027,7 @@ impl Row {
         };
 
         if idx < self.columns.len() {
-            match &self.columns[idx] {
-                Some(val) => cql_value_to_py(py, val),
-                None => Ok(py.None()),
-            }
+            self.column_value(py, idx)
         } else {
             Err(PyErr::new::<pyo3::exceptions::PyIndexError, _>(format!(
                 "Column index {} out of range",
@@ -0,0 +1,193 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use rand::Rng;
+use scylla::client::session::Session as ScyllaSession;
+use scylla::errors::{
+    DbError, ExecutionError, NewSessionError, PrepareError, RequestAttemptError, UseKeyspaceError,
+};
+use scylla::response::query_result::QueryResult as ScyllaQueryResult;
+use std::collections::HashMap;
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::{query_error_to_py, session_error_to_py};
+use crate::query::Statement;
+use crate::rate_limit::RequestLimiter;
+use crate::types::SerializableValue;
+
+/// Upper bound for a single backoff delay.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Copy)]
+pub enum Backoff {
+    Constant,
+    Linear,
+    Exponential,
+}
+
+impl Backoff {
+    pub fn parse(backoff: &str) -> PyResult<Self> {
+        match backoff.to_lowercase().as_str() {
+            "constant" => Ok(Backoff::Constant),
+            "linear" => Ok(Backoff::Linear),
+            "exponential" => Ok(Backoff::Exponential),
+            _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Invalid backoff: {}. Must be 'constant', 'linear', or 'exponential'",
+                backoff
+            ))),
+        }
+    }
+
+    /// Delay before retry number `retry` (starting at 1). With jitter the delay is drawn
+    /// uniformly from its upper half, so concurrent callers spread out.
+    fn delay(self, base: Duration, retry: u32, jitter: bool) -> Duration {
+        let delay = match self {
+            Backoff::Constant => base,
+            Backoff::Linear => base.saturating_mul(retry),
+            Backoff::Exponential => base.saturating_mul(2u32.saturating_pow(retry - 1)),
+        }
+        .min(MAX_RETRY_DELAY);
+        if jitter {
+            delay.mul_f64(rand::rng().random_range(0.5..=1.0))
+        } else {
+            delay
+        }
+    }
+}
+
+pub struct RetryOptions {
+    pub retries: u32,
+    pub backoff: Backoff,
+    pub jitter: bool,
+    pub base_delay: Duration,
+}
+
+/// Whether `err` may be retried. Errors raised before the request could be applied are
+/// always safe to retry; timeouts and broken connections leave the outcome unknown, so
+/// they are only retried for idempotent statements.
+pub(crate) fn is_retry_safe(err: &ExecutionError, idempotent: bool) -> bool {
+    match err {
+        ExecutionError::EmptyPlan | ExecutionError::ConnectionPoolError(_) => true,
+        ExecutionError::RequestTimeout(_) => idempotent,
+        ExecutionError::LastAttemptError(attempt) => match attempt {
+            RequestAttemptError::UnableToAllocStreamId => true,
+            RequestAttemptError::BrokenConnectionError(_) => idempotent,
+            RequestAttemptError::DbError(db_error, _) => match db_error {
+                DbError::Unavailable { .. }
+                | DbError::Overloaded
+                | DbError::IsBootstrapping
+                | DbError::RateLimitReached { .. } => true,
+                DbError::ReadTimeout { .. }
+                | DbError::WriteTimeout { .. }
+                | DbError::ReadFailure { .. }
+                | DbError::WriteFailure { .. }
+                | DbError::TruncateError
+                | DbError::ServerError => idempotent,
+                _ => false,
+            },
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Whether preparing again may succeed: no node had a working pool, or the connection
+/// broke mid-request. Syntax and invalid-request errors fail the same way every time.
+pub(crate) fn is_transient_prepare_error(err: &PrepareError) -> bool {
+    matches!(
+        err,
+        PrepareError::ConnectionPoolError(_)
+            | PrepareError::AllAttemptsFailed {
+                first_attempt: RequestAttemptError::BrokenConnectionError(_)
+            }
+    )
+}
+
+/// Whether a failed connection attempt may succeed later, e.g. once the cluster accepts
+/// connections or its hostnames resolve. Configuration mistakes fail immediately.
+fn is_transient(err: &NewSessionError) -> bool {
+    matches!(
+        err,
+        NewSessionError::FailedToResolveAnyHostname(_)
+            | NewSessionError::MetadataError(_)
+            | NewSessionError::UseKeyspaceError(UseKeyspaceError::RequestError(_))
+    )
+}
+
+/// Attach the attempt history to the error raised for the last attempt, as a list of
+/// `{"attempt", "error", "delay_ms"}` dicts; `delay_ms` is the backoff before the next
+/// attempt, or `None` for the last one.
+fn with_attempts(py_err: PyErr, history: Vec<(String, Option<Duration>)>) -> PyErr {
+    Python::attach(|py| {
+        let attempts = PyList::empty(py);
+        for (index, (error, delay)) in history.into_iter().enumerate() {
+            let attempt = PyDict::new(py);
+            attempt.set_item("attempt", index + 1)?;
+            attempt.set_item("error", error)?;
+            attempt.set_item("delay_ms", delay.map(|delay| delay.as_millis() as u64))?;
+            attempts.append(attempt)?;
+        }
+        py_err.value(py).setattr("attempts", attempts)
+    })
+    .err()
+    .unwrap_or(py_err)
+}
+
+/// Run `statement`, retrying failed attempts that are safe to repeat with the given
+/// backoff.
+pub async fn execute_with_retry(
+    session: &ScyllaSession,
+    limiter: &RequestLimiter,
+    statement: &Statement,
+    values: &HashMap<String, SerializableValue>,
+    options: RetryOptions,
+) -> PyResult<ScyllaQueryResult> {
+    let idempotent = statement.is_idempotent();
+    let mut history = Vec::new();
+    loop {
+        let slot = limiter.acquire().await;
+        let attempt = statement.execute_unpaged(session, values).await;
+        drop(slot);
+        let err = match attempt {
+            Ok(result) => return Ok(result),
+            Err(err) => err,
+        };
+
+        let retry = history.len() as u32 + 1;
+        if retry > options.retries || !is_retry_safe(&err, idempotent) {
+            history.push((err.to_string(), None));
+            return Err(with_attempts(query_error_to_py(err), history));
+        }
+        let delay = options
+            .backoff
+            .delay(options.base_delay, retry, options.jitter);
+        history.push((err.to_string(), Some(delay)));
+        tokio::time::sleep(delay).await;
+    }
+}
+
+/// Call `connect` until it succeeds, retrying transient failures with the given backoff.
+pub async fn connect_with_retry<F, Fut, T>(mut connect: F, options: RetryOptions) -> PyResult<T>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, NewSessionError>>,
+{
+    let mut history = Vec::new();
+    loop {
+        let err = match connect().await {
+            Ok(session) => return Ok(session),
+            Err(err) => err,
+        };
+
+        let retry = history.len() as u32 + 1;
+        if retry > options.retries || !is_transient(&err) {
+            history.push((err.to_string(), None));
+            return Err(with_attempts(session_error_to_py(err), history));
+        }
+        let delay = options
+            .backoff
+            .delay(options.base_delay, retry, options.jitter);
+        history.push((err.to_string(), Some(delay)));
+        tokio::time::sleep(delay).await;
+    }
+}
@@ -0,0 +1,140 @@
+use pyo3::prelude::*;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use crate::error::{ForkedProcessError, ScyllaError};
+use crate::pending::PendingResult;
+
+/// Process that started the shared Tokio runtime. Its worker threads and the sessions'
+/// sockets belong to that process; a child created with `os.fork()` inherits the memory
+/// but not the threads, so awaiting anything there would hang forever.
+static RUNTIME_PID: OnceLock<u32> = OnceLock::new();
+
+/// Set by `shutdown()`; from then on no request is started or has its result delivered.
+static SHUTTING_DOWN: AtomicBool = AtomicBool::new(false);
+
+/// Awaitables handed to Python whose Rust future hasn't finished yet.
+static IN_FLIGHT: AtomicUsize = AtomicUsize::new(0);
+
+struct InFlight;
+
+impl InFlight {
+    fn enter() -> Self {
+        IN_FLIGHT.fetch_add(1, Ordering::AcqRel);
+        InFlight
+    }
+}
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        IN_FLIGHT.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Fail fast when called in a process forked after the runtime was started.
+pub fn ensure_not_forked() -> PyResult<()> {
+    let pid = std::process::id();
+    let owner = *RUNTIME_PID.get_or_init(|| pid);
+    if owner == pid {
+        return Ok(());
+    }
+
+    Err(PyErr::new::<ForkedProcessError, _>(format!(
+        "rsylla was started in process {} and cannot be used in process {} forked from it; \
+         build sessions after forking (e.g. in gunicorn's post_fork hook) or use the \
+         'spawn' or 'forkserver' multiprocessing start method",
+        owner, pid
+    )))
+}
+
+/// Whether the caller runs under trio, directly or through anyio, rather than asyncio.
+fn running_trio(py: Python) -> PyResult<bool> {
+    if !py
+        .import("asyncio")?
+        .call_method0("_get_running_loop")?
+        .is_none()
+    {
+        return Ok(false);
+    }
+    let Ok(trio) = py.import("sys")?.getattr("modules")?.get_item("trio") else {
+        return Ok(false);
+    };
+    Ok(trio
+        .getattr("lowlevel")?
+        .call_method0("current_trio_token")
+        .is_ok())
+}
+
+/// `fut` counted as in flight, after checking requests may start. Once shutting down,
+/// it never completes: the event loop awaiting it is likely closed, or the interpreter
+/// is on its way out, so handing the result over would only print a traceback.
+fn tracked<F, T>(fut: F) -> PyResult<impl Future<Output = PyResult<T>> + Send + 'static>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: Send + 'static,
+{
+    ensure_not_forked()?;
+    if SHUTTING_DOWN.load(Ordering::Acquire) {
+        return Err(PyErr::new::<ScyllaError, _>("rsylla has been shut down"));
+    }
+
+    let in_flight = InFlight::enter();
+    Ok(async move {
+        let result = fut.await;
+        drop(in_flight);
+        if SHUTTING_DOWN.load(Ordering::Acquire) {
+            std::future::pending::<()>().await;
+        }
+        result
+    })
+}
+
+/// `pyo3_async_runtimes::tokio::future_into_py`, refusing to run in a forked child or
+/// after `shutdown()`. Under trio the request is returned as a `PendingResult` instead
+/// of an asyncio future.
+pub fn future_into_py<F, T>(py: Python, fut: F) -> PyResult<Bound<PyAny>>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: for<'py> IntoPyObject<'py> + Send + 'static,
+{
+    let fut = tracked(fut)?;
+    if running_trio(py)? {
+        PendingResult::spawn(py, fut)
+    } else {
+        pyo3_async_runtimes::tokio::future_into_py(py, fut)
+    }
+}
+
+/// Start `fut` on the runtime right away and return a `PendingResult` for it, whether or
+/// not an event loop is running. Like any request, `shutdown()` waits for it.
+pub fn spawn_pending<F, T>(py: Python, fut: F) -> PyResult<Bound<PyAny>>
+where
+    F: Future<Output = PyResult<T>> + Send + 'static,
+    T: for<'py> IntoPyObject<'py> + Send + 'static,
+{
+    PendingResult::spawn(py, tracked(fut)?)
+}
+
+/// Stop accepting requests and wait up to `timeout_ms` for the ones in flight to finish,
+/// so writes a script didn't await still reach the cluster. Results completing after
+/// this are dropped instead of being delivered. Runs automatically at interpreter exit;
+/// returns the number of requests still unfinished when it gave up.
+#[pyfunction]
+#[pyo3(signature = (timeout_ms=5000))]
+pub fn shutdown(py: Python, timeout_ms: u64) -> usize {
+    if ensure_not_forked().is_err() {
+        // The requests counted here belong to the parent
+        return 0;
+    }
+
+    SHUTTING_DOWN.store(true, Ordering::Release);
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    py.detach(|| {
+        while IN_FLIGHT.load(Ordering::Acquire) > 0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(5));
+        }
+        IN_FLIGHT.load(Ordering::Acquire)
+    })
+}
@@ -1,24 +1,101 @@
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
-use pyo3_async_runtimes::tokio::future_into_py;
 use scylla::client::session::Session as ScyllaSession;
 use scylla::client::session_builder::SessionBuilder as ScyllaSessionBuilder;
+use scylla::client::PoolSize;
+use scylla::cluster::KnownNode;
+use scylla::errors::NewSessionError;
+use scylla::policies::timestamp_generator::{
+    MonotonicTimestampGenerator, SimpleTimestampGenerator, TimestampGenerator,
+};
+use scylla::statement::batch::BatchStatement;
+use scylla::statement::unprepared::Statement as ScyllaStatement;
+use scylla::statement::Consistency;
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::time::Duration;
+use tokio::net::TcpStream;
+use uuid::Uuid;
 
-use crate::batch::Batch;
+use crate::batch::{execute_chunks, Batch};
+use crate::cdc::CDCReader;
+use crate::clients::list_server_connections;
+use crate::config::apply_config;
+use crate::contact_points::{with_default_port, ContactPoints};
+use crate::copy_from::{copy_from, CopyOptions, Format};
+use crate::copy_to::{copy_to, ExportOptions};
+use crate::cql::{
+    create_keyspace_statement, drop_keyspace_statement, validate_identifier, validate_table_name,
+    with_bypass_cache,
+};
+use crate::deadline::{within, Deadline};
 use crate::error::{
-    prepare_error_to_py, query_error_to_py, schema_agreement_error_to_py, session_error_to_py,
+    execution_error_to_py, prepare_error_to_py, prepare_timeout_to_py, query_error_to_py,
+    schema_agreement_error_to_py, session_error_to_py, token_error_to_py, tracing_error_to_py,
     use_keyspace_error_to_py,
 };
-use crate::query::{PreparedStatement, Query};
-use crate::result::QueryResult;
-use crate::types::py_dict_to_serialized_values;
+use crate::events::ClusterEvents;
+use crate::execute_many::{execute_many, ManyTarget};
+use crate::execution_profile::ExecutionProfile;
+use crate::fairness::FairnessClasses;
+use crate::features::fetch_features;
+use crate::full_scan::{FullScan, ScanOptions};
+use crate::health::check_connectivity;
+use crate::info::protocol_versions;
+use crate::lwt::{columns_and_values, conditions, execute_lwt, Lwt};
+use crate::mapper::Mapper;
+use crate::metadata::{fetch_cluster_metadata, fetch_keyspaces, token_ranges};
+use crate::metrics::metrics_snapshot;
+use crate::observer::{InFlightRequest, OrphanedRequests, RequestHooks, RequestLoad};
+use crate::ordered_writer::OrderedWriter;
+use crate::pager::{PageIterator, Pager};
+use crate::pipeline::Pipeline;
+use crate::policies::{LoadBalancingPolicy, NodeLatencies};
+use crate::prometheus::{self, PrometheusExporter};
+use crate::query::{
+    check_named_values, check_value_types, parse_consistency, partition_key_values, BoundStatement,
+    PreparedStatement, Query, Statement, StatementDefinition,
+};
+use crate::rate_limit::{RequestLimiter, MIN_REQUESTS_PER_SECOND};
+use crate::result::{QueryResult, RowFactory, WarningSink};
+use crate::retry::{
+    connect_with_retry, execute_with_retry, is_transient_prepare_error, Backoff, RetryOptions,
+};
+use crate::runtime::{future_into_py, spawn_pending};
+use crate::statement_cache::StatementCache;
+use crate::stream::{execute_stream, StreamCallback};
+use crate::telemetry::Telemetry;
+use crate::tracing_info::TracingInfo;
+use crate::types::{py_dict_to_serialized_values, py_list_to_batch_values, SerializableValue};
+
+const PREPARE_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+/// Page sizes used by the paging helpers when neither the call nor the session sets one.
+const DEFAULT_PAGINATE_PAGE_SIZE: i32 = 100;
+const DEFAULT_SCAN_PAGE_SIZE: i32 = 1000;
 
 #[pyclass]
 #[derive(Clone, Default)]
 pub struct SessionBuilder {
     builder: ScyllaSessionBuilder,
+    emit_warnings: bool,
+    collect_warnings: bool,
+    /// `{class: max_in_flight}`; each built session gets its own semaphores.
+    fairness_classes: HashMap<String, Option<usize>>,
+    hedged_startup: Option<Duration>,
+    /// Applied to the default profile at build time, whichever profile is set last.
+    load_balancing_policy: Option<LoadBalancingPolicy>,
+    telemetry: Option<Telemetry>,
+    request_hooks: RequestHooks,
+    row_factory: Option<RowFactory>,
+    default_page_size: Option<i32>,
+    rate_limit: (Option<f64>, Option<usize>),
+    strict_types: bool,
+    profiles: HashMap<String, ExecutionProfile>,
+    dns_refresh: Option<Duration>,
+    service_level: Option<String>,
+    track_node_requests: bool,
 }
 
 #[pymethods]
@@ -28,6 +105,18 @@ impl SessionBuilder {
         Self::default()
     }
 
+    /// Builder configured from a TOML or YAML file: contact points, credentials, pool and
+    /// default consistency, plus named execution profiles under `[profiles.<name>]`.
+    /// With `environment`, the `[environments.<environment>]` table overrides the
+    /// top-level settings.
+    #[staticmethod]
+    #[pyo3(signature = (path, environment=None))]
+    pub fn from_config(py: Python, path: PathBuf, environment: Option<&str>) -> PyResult<Self> {
+        let mut builder = SessionBuilder::new();
+        apply_config(py, &mut builder, &path, environment)?;
+        Ok(builder)
+    }
+
     pub fn known_node(&mut self, hostname: &str) -> PyResult<Self> {
         self.builder = self.builder.clone().known_node(hostname);
         Ok(self.clone())
@@ -66,11 +155,45 @@ impl SessionBuilder {
         Ok(self.clone())
     }
 
+    /// Open `connections` connections to every shard of each node instead of one, spreading
+    /// concurrent requests over more connections. The driver caps each connection at its
+    /// protocol stream ids and closes one that accumulates too many abandoned requests, so
+    /// this is the lever for workloads that multiplex too much on a single connection.
+    pub fn connections_per_shard(&mut self, connections: usize) -> PyResult<Self> {
+        let connections = std::num::NonZeroUsize::new(connections).ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Connections per shard must be greater than 0",
+            )
+        })?;
+        self.builder = self
+            .builder
+            .clone()
+            .pool_size(scylla::client::PoolSize::PerShard(connections));
+        Ok(self.clone())
+    }
+
     pub fn user(&mut self, username: &str, password: &str) -> PyResult<Self> {
         self.builder = self.builder.clone().user(username, password);
         Ok(self.clone())
     }
 
+    /// Count request attempts awaiting a response per node, for `node_queue_depths()`.
+    /// Off by default since it attaches a history listener to every request.
+    #[pyo3(signature = (enabled=true))]
+    pub fn track_node_requests(&mut self, enabled: bool) -> PyResult<Self> {
+        self.track_node_requests = enabled;
+        Ok(self.clone())
+    }
+
+    /// Record the workload prioritization service level `name` the session is meant to run
+    /// under, reported by `Session.service_level()` and `describe()`. Scylla picks the level
+    /// from the role the session logs in as, so this doesn't change scheduling by itself:
+    /// attach the level to that role once, e.g. with `Session.attach_service_level()`.
+    pub fn service_level(&mut self, name: &str) -> PyResult<Self> {
+        self.service_level = Some(validate_identifier(name)?.to_string());
+        Ok(self.clone())
+    }
+
     #[pyo3(signature = (compression=None))]
     pub fn compression(&mut self, compression: Option<&str>) -> PyResult<Self> {
         let comp = match compression {
@@ -87,6 +210,37 @@ impl SessionBuilder {
         Ok(self.clone())
     }
 
+    /// Client-side timestamps for every request without an explicit one: `"monotonic"`
+    /// (strictly increasing, warning through the log bridge when the clock lags by more
+    /// than `clock_skew_warning_ms`), `"simple"` (the system clock) or `None` to let the
+    /// server assign them.
+    #[pyo3(signature = (generator, clock_skew_warning_ms=Some(1000)))]
+    pub fn timestamp_generator(
+        &mut self,
+        generator: Option<&str>,
+        clock_skew_warning_ms: Option<u64>,
+    ) -> PyResult<Self> {
+        let generator: Option<Arc<dyn TimestampGenerator>> = match generator {
+            Some("monotonic") => {
+                let monotonic = MonotonicTimestampGenerator::new();
+                Some(Arc::new(match clock_skew_warning_ms {
+                    Some(ms) => monotonic
+                        .with_warning_times(Duration::from_millis(ms), Duration::from_secs(1)),
+                    None => monotonic.without_warnings(),
+                }))
+            }
+            Some("simple") => Some(Arc::new(SimpleTimestampGenerator::new())),
+            None => None,
+            _ => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Invalid timestamp generator. Must be 'monotonic', 'simple', or None",
+                ))
+            }
+        };
+        self.builder.config.timestamp_generator = generator;
+        Ok(self.clone())
+    }
+
     pub fn tcp_nodelay(&mut self, nodelay: bool) -> PyResult<Self> {
         self.builder = self.builder.clone().tcp_nodelay(nodelay);
         Ok(self.clone())
@@ -99,23 +253,494 @@ impl SessionBuilder {
         Ok(self.clone())
     }
 
+    pub fn default_execution_profile(&mut self, profile: &ExecutionProfile) -> PyResult<Self> {
+        self.builder = self
+            .builder
+            .clone()
+            .default_execution_profile_handle(profile.handle());
+        Ok(self.clone())
+    }
+
+    /// Re-resolve contact points given as hostnames every `interval_ms` and refresh the
+    /// cluster metadata when they point at a node the session doesn't know, for DNS names
+    /// whose addresses rotate, like a Kubernetes headless service. `None` disables it.
+    #[pyo3(signature = (interval_ms=Some(30000)))]
+    pub fn dns_refresh(&mut self, interval_ms: Option<u64>) -> PyResult<Self> {
+        if interval_ms == Some(0) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "DNS refresh interval must be greater than 0",
+            ));
+        }
+        self.dns_refresh = interval_ms.map(Duration::from_millis);
+        Ok(self.clone())
+    }
+
+    /// Register `profile` under `name`, to be looked up with `Session.execution_profile()`.
+    pub fn execution_profile(
+        &mut self,
+        name: String,
+        profile: &ExecutionProfile,
+    ) -> PyResult<Self> {
+        self.profiles.insert(name, profile.clone());
+        Ok(self.clone())
+    }
+
+    /// Route requests of the default execution profile with `policy`, whether
+    /// `default_execution_profile()` is called before or after this.
+    pub fn load_balancing_policy(&mut self, policy: &LoadBalancingPolicy) -> PyResult<Self> {
+        self.load_balancing_policy = Some(policy.clone());
+        Ok(self.clone())
+    }
+
+    pub fn emit_warnings(&mut self, enabled: bool) -> PyResult<Self> {
+        self.emit_warnings = enabled;
+        Ok(self.clone())
+    }
+
+    /// Accumulate server warnings on the session, to be drained with `Session.take_warnings()`.
+    pub fn collect_warnings(&mut self, enabled: bool) -> PyResult<Self> {
+        self.collect_warnings = enabled;
+        Ok(self.clone())
+    }
+
+    /// Register a named fairness class; statements assigned to it with `with_fairness_class()`
+    /// share at most `max_in_flight` concurrent requests (unlimited when `None`).
+    #[pyo3(signature = (name, max_in_flight=None))]
+    pub fn fairness_class(&mut self, name: String, max_in_flight: Option<usize>) -> PyResult<Self> {
+        if max_in_flight == Some(0) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Max in-flight must be greater than 0",
+            ));
+        }
+        self.fairness_classes.insert(name, max_in_flight);
+        Ok(self.clone())
+    }
+
+    /// Limit the session to `requests_per_second` and/or `max_concurrent` requests in
+    /// flight, client side. Requests over the limit wait instead of failing.
+    #[pyo3(signature = (requests_per_second=None, max_concurrent=None))]
+    pub fn rate_limit(
+        &mut self,
+        requests_per_second: Option<f64>,
+        max_concurrent: Option<usize>,
+    ) -> PyResult<Self> {
+        if requests_per_second.is_some_and(|rps| !(rps > 0.0 && rps.is_finite()))
+            || max_concurrent == Some(0)
+        {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Requests per second and max concurrent must be greater than 0",
+            ));
+        }
+        if requests_per_second.is_some_and(|rps| rps < MIN_REQUESTS_PER_SECOND) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Requests per second must be at least {}",
+                MIN_REQUESTS_PER_SECOND
+            )));
+        }
+        self.rate_limit = (requests_per_second, max_concurrent);
+        Ok(self.clone())
+    }
+
+    #[pyo3(signature = (enabled, probe_timeout_ms=2000))]
+    pub fn hedged_startup(&mut self, enabled: bool, probe_timeout_ms: u64) -> PyResult<Self> {
+        self.hedged_startup = enabled.then(|| Duration::from_millis(probe_timeout_ms));
+        Ok(self.clone())
+    }
+
+    /// Emit an OpenTelemetry client span for every execute, prepare and batch call, using
+    /// `tracer_provider` or the globally configured one.
+    #[pyo3(signature = (enabled=true, tracer_provider=None))]
+    pub fn opentelemetry(
+        &mut self,
+        py: Python,
+        enabled: bool,
+        tracer_provider: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        self.telemetry = if enabled {
+            Some(Telemetry::new(py, tracer_provider)?)
+        } else {
+            None
+        };
+        Ok(self.clone())
+    }
+
+    /// Register callables run before every execute, prepare and batch call is sent and after
+    /// it completes; each receives a `RequestEvent`. Raising from `before` aborts the request.
+    #[pyo3(signature = (before=None, after=None))]
+    pub fn request_hook(
+        &mut self,
+        before: Option<Py<PyAny>>,
+        after: Option<Py<PyAny>>,
+    ) -> PyResult<Self> {
+        if before.is_none() && after.is_none() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "A request hook needs a before or after callable",
+            ));
+        }
+        self.request_hooks.add(before, after);
+        Ok(self.clone())
+    }
+
+    /// Default shape of result rows: `"tuple"`, `"dict"`, `"namedtuple"`, a callable taking
+    /// `(names, values)`, or `None` for `Row` objects.
+    pub fn row_factory(&mut self, row_factory: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        self.row_factory = row_factory.map(RowFactory::from_py).transpose()?;
+        Ok(self.clone())
+    }
+
+    /// Page size for prepared statements and paging helpers that aren't given one.
+    pub fn default_page_size(&mut self, page_size: i32) -> PyResult<Self> {
+        if page_size <= 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Page size must be greater than 0",
+            ));
+        }
+        self.default_page_size = Some(page_size);
+        Ok(self.clone())
+    }
+
+    /// Refuse bound values that only fit their column through a lossy or implicit
+    /// conversion, such as a float that isn't exactly representable as a CQL `float` or an
+    /// int bound to a floating-point column. Applies to prepared statements.
+    #[pyo3(signature = (enabled=true))]
+    pub fn strict_types(&mut self, enabled: bool) -> PyResult<Self> {
+        self.strict_types = enabled;
+        Ok(self.clone())
+    }
+
     pub fn build<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let builder = self.builder.clone();
+        self.build_session(py, None)
+    }
+
+    /// Like `build()`, but retry while the cluster is unreachable or its hostnames don't
+    /// resolve yet, e.g. for a service starting alongside it, making up to `max_attempts`
+    /// attempts `backoff_ms` apart (growing per `backoff`). The last error is raised with
+    /// an `attempts` attribute listing every attempt.
+    #[pyo3(signature = (max_attempts=5, backoff_ms=1000, backoff="exponential", jitter=true))]
+    pub fn build_with_retry<'py>(
+        &self,
+        py: Python<'py>,
+        max_attempts: u32,
+        backoff_ms: u64,
+        backoff: &str,
+        jitter: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if max_attempts == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "max_attempts must be at least 1",
+            ));
+        }
+        let options = RetryOptions {
+            retries: max_attempts - 1,
+            backoff: Backoff::parse(backoff)?,
+            jitter,
+            base_delay: Duration::from_millis(backoff_ms),
+        };
+        self.build_session(py, Some(options))
+    }
+}
+
+impl SessionBuilder {
+    fn build_session<'py>(
+        &self,
+        py: Python<'py>,
+        retry: Option<RetryOptions>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut builder = self.builder.clone();
+        let mut node_latencies = None;
+        if let Some(policy) = &self.load_balancing_policy {
+            let (routing, latencies) = policy.build();
+            let profile = builder
+                .config
+                .default_execution_profile_handle
+                .pointee_to_builder()
+                .load_balancing_policy(routing)
+                .build();
+            builder = builder.default_execution_profile_handle(profile.into_handle());
+            node_latencies = Some(latencies);
+        }
+        let service_level = self.service_level.clone();
+        let warnings = WarningSink::new(self.emit_warnings, self.collect_warnings);
+        let fairness_classes = Arc::new(FairnessClasses::new(&self.fairness_classes));
+        let hedged_startup = self.hedged_startup;
+        let telemetry = self.telemetry.clone();
+        let request_hooks = Arc::new(self.request_hooks.clone());
+        let row_factory = self.row_factory.clone();
+        let default_page_size = self.default_page_size;
+        let strict_types = self.strict_types;
+        let profiles = Arc::new(self.profiles.clone());
+        let dns_refresh = self.dns_refresh;
+        let load = RequestLoad::new(self.track_node_requests);
+        let compression = builder
+            .config
+            .compression
+            .map(|compression| match compression {
+                scylla::client::Compression::Lz4 => "lz4",
+                scylla::client::Compression::Snappy => "snappy",
+            });
+        let pool_size = builder.config.connection_pool_size;
+        let contact_points = Arc::new(ContactPoints::new(&builder.config.known_nodes));
+        let (requests_per_second, max_concurrent) = self.rate_limit;
+        let limiter = Arc::new(RequestLimiter::new(requests_per_second, max_concurrent));
 
         future_into_py(py, async move {
-            let session = builder.build().await.map_err(session_error_to_py)?;
+            let attempt = || connect(builder.clone(), hedged_startup);
+            let session = match retry {
+                Some(options) => connect_with_retry(attempt, options).await?,
+                None => attempt().await.map_err(session_error_to_py)?,
+            };
+            let session = Arc::new(session);
+            if let Some(interval) = dns_refresh {
+                contact_points.watch(&session, interval);
+            }
 
             Ok(Session {
-                session: Arc::new(session),
+                session,
+                warnings,
+                fairness_classes,
+                statement_cache: Arc::new(StatementCache::default()),
+                node_latencies,
+                telemetry,
+                request_hooks,
+                orphaned_requests: OrphanedRequests::default(),
+                load,
+                compression,
+                pool_size,
+                row_factory,
+                default_page_size,
+                limiter,
+                strict_types,
+                profiles,
+                contact_points,
+                service_level,
             })
         })
     }
 }
 
+/// Open the driver session, first narrowing the contact points with hedged startup.
+async fn connect(
+    mut builder: ScyllaSessionBuilder,
+    hedged_startup: Option<Duration>,
+) -> Result<ScyllaSession, NewSessionError> {
+    if let Some(probe_timeout) = hedged_startup {
+        // Hand the driver only the first contact point that answers; the rest of the
+        // topology is discovered from it. If nobody answers, keep the original list
+        // so the driver reports its usual connection error.
+        if let Some(winner) = race_contact_points(&builder.config.known_nodes, probe_timeout).await
+        {
+            builder.config.known_nodes = vec![winner];
+        }
+    }
+    builder.build().await
+}
+
+/// Probe all contact points concurrently and return the first one accepting TCP connections.
+async fn race_contact_points(nodes: &[KnownNode], probe_timeout: Duration) -> Option<KnownNode> {
+    if nodes.len() < 2 {
+        return None;
+    }
+
+    let probes: Vec<_> = nodes
+        .iter()
+        .filter_map(|node| {
+            let target = match node {
+                KnownNode::Hostname(hostname) => with_default_port(hostname),
+                KnownNode::Address(addr) => addr.to_string(),
+                _ => return None,
+            };
+
+            Some(Box::pin(async move {
+                match tokio::time::timeout(probe_timeout, TcpStream::connect(target)).await {
+                    Ok(Ok(_)) => Ok(node.clone()),
+                    _ => Err(()),
+                }
+            }))
+        })
+        .collect();
+    if probes.is_empty() {
+        return None;
+    }
+
+    futures::future::select_ok(probes)
+        .await
+        .ok()
+        .map(|(node, _)| node)
+}
+
+/// A connection to the cluster. Requests run on the shared Tokio runtime and each returned
+/// awaitable belongs to the event loop running when it was created, so one session can
+/// serve several event loops, such as one per worker thread or per test.
 #[pyclass]
 #[derive(Clone)]
 pub struct Session {
     pub(crate) session: Arc<ScyllaSession>,
+    warnings: WarningSink,
+    fairness_classes: Arc<FairnessClasses>,
+    statement_cache: Arc<StatementCache>,
+    node_latencies: Option<Arc<NodeLatencies>>,
+    telemetry: Option<Telemetry>,
+    request_hooks: Arc<RequestHooks>,
+    orphaned_requests: OrphanedRequests,
+    load: RequestLoad,
+    compression: Option<&'static str>,
+    pool_size: PoolSize,
+    row_factory: Option<RowFactory>,
+    default_page_size: Option<i32>,
+    limiter: Arc<RequestLimiter>,
+    strict_types: bool,
+    profiles: Arc<HashMap<String, ExecutionProfile>>,
+    contact_points: Arc<ContactPoints>,
+    service_level: Option<String>,
+}
+
+impl Session {
+    /// The per-call row factory if given, else the session's default.
+    fn row_factory(&self, row_factory: Option<&Bound<'_, PyAny>>) -> PyResult<Option<RowFactory>> {
+        match row_factory {
+            Some(row_factory) => RowFactory::from_py(row_factory).map(Some),
+            None => Ok(self.row_factory.clone()),
+        }
+    }
+
+    fn run_lwt<'py>(&self, py: Python<'py>, lwt: Lwt) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+        let statement_cache = self.statement_cache.clone();
+        let limiter = self.limiter.clone();
+
+        future_into_py(py, async move {
+            execute_lwt(&session, &statement_cache, &limiter, lwt).await
+        })
+    }
+
+    /// The per-call page size if given, else the session's default, else `fallback`.
+    fn page_size(&self, page_size: Option<i32>, fallback: i32) -> i32 {
+        page_size.or(self.default_page_size).unwrap_or(fallback)
+    }
+
+    fn start_request(
+        &self,
+        py: Python,
+        operation: &'static str,
+        statement: &str,
+        consistency: Option<Consistency>,
+    ) -> PyResult<InFlightRequest> {
+        let span = self
+            .telemetry
+            .as_ref()
+            .map(|telemetry| telemetry.start(py, operation, statement, consistency))
+            .transpose()?;
+        InFlightRequest::start(
+            py,
+            span,
+            &self.request_hooks,
+            &self.orphaned_requests,
+            &self.load,
+            operation,
+            statement,
+        )
+        .map(|request| request.with_compression(self.compression))
+    }
+
+    /// Run a DDL `statement`, then wait for every node to agree on the new schema.
+    fn run_schema_change<'py>(
+        &self,
+        py: Python<'py>,
+        statement: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            session
+                .query_unpaged(statement, &[])
+                .await
+                .map_err(query_error_to_py)?;
+            session
+                .await_schema_agreement()
+                .await
+                .map_err(schema_agreement_error_to_py)?;
+            Ok(())
+        })
+    }
+
+    /// Prepare `query_str`, retrying failed attempts `retries` times, and apply
+    /// `definition`'s options to the statement when given.
+    fn prepare_statement<'py>(
+        &self,
+        py: Python<'py>,
+        query_str: String,
+        timeout_ms: Option<u64>,
+        retries: u32,
+        definition: Option<StatementDefinition>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut request = Some(self.start_request(py, "prepare", &query_str, None)?);
+        let session = self.session.clone();
+        let default_page_size = self.default_page_size;
+        let strict_types = self.strict_types;
+        let statement_cache = self.statement_cache.clone();
+
+        future_into_py(py, async move {
+            let mut attempt = 0;
+            loop {
+                attempt += 1;
+                let prepared = session.prepare(query_str.as_str());
+                let result = match timeout_ms {
+                    Some(ms) => tokio::time::timeout(Duration::from_millis(ms), prepared)
+                        .await
+                        .map_err(|_| (true, prepare_timeout_to_py(ms))),
+                    None => Ok(prepared.await),
+                }
+                .and_then(|result| {
+                    result
+                        .map_err(|err| (is_transient_prepare_error(&err), prepare_error_to_py(err)))
+                });
+
+                // Only failures another attempt may get past are retried
+                let retry = matches!(result, Err((true, _))) && attempt <= retries;
+                let result = result.map_err(|(_, err)| err);
+                if let Some(mut request) = request.take_if(|_| !retry) {
+                    request.set_attempts(attempt as usize);
+                    request.finish(None, result.as_ref().err());
+                }
+
+                match result {
+                    Ok(mut prepared) => {
+                        statement_cache.remember(&query_str);
+                        if let Some(page_size) = default_page_size {
+                            prepared.set_page_size(page_size);
+                        }
+
+                        // The driver prepares on every node it holds a working connection to
+                        let connected_at_prepare = session
+                            .get_cluster_state()
+                            .get_nodes_info()
+                            .iter()
+                            .filter(|node| node.is_connected())
+                            .map(|node| node.address.to_string())
+                            .collect();
+
+                        let statement = PreparedStatement {
+                            prepared: Arc::new(prepared),
+                            connected_at_prepare,
+                            prepare_attempts: attempt,
+                            fairness_class: None,
+                            strict_types,
+                            retry_policy: None,
+                        };
+                        return Ok(match &definition {
+                            Some(definition) => definition.apply(statement),
+                            None => statement,
+                        });
+                    }
+                    Err(err) if !retry => return Err(err),
+                    Err(_) => {
+                        // Give restarting nodes a moment before trying again
+                        tokio::time::sleep(PREPARE_RETRY_DELAY * attempt).await;
+                    }
+                }
+            }
+        })
+    }
 }
 
 #[pymethods]
@@ -127,141 +752,1117 @@ impl Session {
         builder.build(py)
     }
 
-    #[pyo3(signature = (query, values=None))]
+    /// `consistency` and `timeout_ms` override the session defaults for this call only.
+    /// `deadline`, a Unix timestamp like `time.time()` returns, bounds the whole call
+    /// including retries and speculative executions, unlike the per-attempt `timeout_ms`;
+    /// past it, the request is abandoned with `DeadlineExceededError`.
+    #[pyo3(signature = (query, values=None, row_factory=None, consistency=None, timeout_ms=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn execute<'py>(
         &self,
         py: Python<'py>,
         query: &str,
         values: Option<&Bound<'_, PyDict>>,
+        row_factory: Option<&Bound<'_, PyAny>>,
+        consistency: Option<&str>,
+        timeout_ms: Option<u64>,
+        deadline: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let serialized_values = py_dict_to_serialized_values(values)?;
+        let row_factory = self.row_factory(row_factory)?;
+        let consistency = consistency.map(parse_consistency).transpose()?;
+        let deadline = Deadline::from_timestamp(deadline)?;
+
+        let request = self.start_request(py, "execute", query, consistency)?;
+        let mut statement = ScyllaStatement::new(query.to_string());
+        if let Some(consistency) = consistency {
+            statement.set_consistency(consistency);
+        }
+        if let Some(ms) = timeout_ms {
+            statement.set_request_timeout(Some(Duration::from_millis(ms)));
+        }
+        statement.set_history_listener(request.listener());
 
         let session = self.session.clone();
-        let query_str = query.to_string();
+        let warnings = self.warnings.clone();
+        let limiter = self.limiter.clone();
+        let query = query.to_string();
 
         future_into_py(py, async move {
-            let result = session
-                .query_unpaged(query_str, serialized_values)
+            let result = within(deadline, async {
+                let _slot = limiter.acquire().await;
+                session
+                    .query_unpaged(statement, serialized_values)
+                    .await
+                    .map_err(query_error_to_py)
+            })
+            .await;
+            let stats = request.finish_query(&result);
+
+            let result = QueryResult::new(result?)
+                .with_session(&session)
+                .with_stats(stats)
+                .with_row_factory(row_factory)
+                .with_statement(&query);
+            warnings.report(&result)?;
+            Ok(result)
+        })
+    }
+
+    /// Start executing `statement` (CQL text, a `Query` or a `PreparedStatement`) and
+    /// return at once with a `PendingResult` exposing `done()` and `result()`, for
+    /// best-effort writes not worth awaiting. It needn't be awaited nor kept: nothing
+    /// reports its failure unless `result()` is checked, and `shutdown()` at interpreter
+    /// exit waits for it to finish.
+    #[pyo3(signature = (statement, values=None))]
+    pub fn execute_nowait<'py>(
+        &self,
+        statement: &Bound<'py, PyAny>,
+        values: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let py = statement.py();
+        let statement = Statement::extract(statement)?;
+        let values = py_dict_to_serialized_values(values)?;
+        let request = self.start_request(py, "execute_nowait", statement.contents(), None)?;
+        let row_factory = self.row_factory.clone();
+        let session = self.session.clone();
+        let warnings = self.warnings.clone();
+        let limiter = self.limiter.clone();
+
+        spawn_pending(py, async move {
+            let _slot = limiter.acquire().await;
+            let result = statement
+                .execute_unpaged(&session, values)
                 .await
-                .map_err(query_error_to_py)?;
+                .map_err(query_error_to_py);
+            let stats = request.finish_query(&result);
+
+            let result = QueryResult::new(result?)
+                .with_session(&session)
+                .with_stats(stats)
+                .with_row_factory(row_factory)
+                .with_statement(statement.contents());
+            warnings.report(&result)?;
+            Ok(result)
+        })
+    }
+
+    /// Execute `statement`, retrying failures that are safe to repeat up to `retries`
+    /// times. Timeouts and lost connections are only retried when the statement is marked
+    /// idempotent. The error of the last attempt is raised with an `attempts` attribute
+    /// listing every attempt.
+    #[pyo3(signature = (statement, values=None, retries=3, backoff="exponential", jitter=true, base_delay_ms=100))]
+    pub fn execute_with_retry<'py>(
+        &self,
+        statement: &Bound<'py, PyAny>,
+        values: Option<&Bound<'_, PyDict>>,
+        retries: u32,
+        backoff: &str,
+        jitter: bool,
+        base_delay_ms: u64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let options = RetryOptions {
+            retries,
+            backoff: Backoff::parse(backoff)?,
+            jitter,
+            base_delay: Duration::from_millis(base_delay_ms),
+        };
+        let py = statement.py();
+        let statement = Statement::extract(statement)?;
+        let values = py_dict_to_serialized_values(values)?;
+        let row_factory = self.row_factory.clone();
+        let session = self.session.clone();
+        let warnings = self.warnings.clone();
+        let limiter = self.limiter.clone();
 
-            Ok(QueryResult::new(result))
+        future_into_py(py, async move {
+            let result =
+                execute_with_retry(&session, &limiter, &statement, &values, options).await?;
+            let result = QueryResult::new(result)
+                .with_session(&session)
+                .with_row_factory(row_factory)
+                .with_statement(statement.contents());
+            warnings.report(&result)?;
+            Ok(result)
         })
     }
 
-    #[pyo3(signature = (query, values=None))]
+    /// `deadline` bounds the whole call as it does for `execute()`.
+    #[pyo3(signature = (query, values=None, row_factory=None, deadline=None))]
     pub fn query<'py>(
         &self,
         py: Python<'py>,
         query: &Query,
         values: Option<&Bound<'_, PyDict>>,
+        row_factory: Option<&Bound<'_, PyAny>>,
+        deadline: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let serialized_values = py_dict_to_serialized_values(values)?;
+        let row_factory = self.row_factory(row_factory)?;
+        let deadline = Deadline::from_timestamp(deadline)?;
+
+        self.fairness_classes
+            .check(query.fairness_class.as_deref())?;
+
+        let request = self.start_request(
+            py,
+            "query",
+            &query.inner.contents,
+            query.inner.get_consistency(),
+        )?;
+        let mut scylla_query = query.inner.clone();
+        scylla_query.set_history_listener(request.listener());
+        let contents = query.inner.contents.clone();
 
         let session = self.session.clone();
-        let scylla_query = query.inner.clone();
+        let warnings = self.warnings.clone();
+        let fairness_classes = self.fairness_classes.clone();
+        let limiter = self.limiter.clone();
+        let fairness_class = query.fairness_class.clone();
 
         future_into_py(py, async move {
-            let result = session
-                .query_unpaged(scylla_query, serialized_values)
-                .await
-                .map_err(query_error_to_py)?;
+            let result = within(deadline, async {
+                let _permit = fairness_classes.acquire(fairness_class.as_deref()).await;
+                let _slot = limiter.acquire().await;
+                session
+                    .query_unpaged(scylla_query, serialized_values)
+                    .await
+                    .map_err(query_error_to_py)
+            })
+            .await;
+            let stats = request.finish_query(&result);
 
-            Ok(QueryResult::new(result))
+            let result = QueryResult::new(result?)
+                .with_session(&session)
+                .with_stats(stats)
+                .with_row_factory(row_factory)
+                .with_statement(&contents);
+            warnings.report(&result)?;
+            Ok(result)
         })
     }
 
-    pub fn prepare<'py>(&self, py: Python<'py>, query: &str) -> PyResult<Bound<'py, PyAny>> {
+    /// With `bypass_cache`, the SELECT is prepared with Scylla's `BYPASS CACHE` clause.
+    #[pyo3(signature = (query, timeout_ms=None, retries=0, bypass_cache=false))]
+    pub fn prepare<'py>(
+        &self,
+        py: Python<'py>,
+        query: &str,
+        timeout_ms: Option<u64>,
+        retries: u32,
+        bypass_cache: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let query_str = if bypass_cache {
+            with_bypass_cache(query)?
+        } else {
+            query.to_string()
+        };
+        self.prepare_statement(py, query_str, timeout_ms, retries, None)
+    }
+
+    /// Prepare a statement defined elsewhere on this session: a `PreparedStatement` from
+    /// another session, or the dict of its or a `Query`'s `to_dict()`, e.g. unpickled in a
+    /// worker process. The statement comes back with the definition's options applied.
+    #[pyo3(signature = (definition, timeout_ms=None, retries=0))]
+    pub fn prepare_from<'py>(
+        &self,
+        py: Python<'py>,
+        definition: &Bound<'py, PyAny>,
+        timeout_ms: Option<u64>,
+        retries: u32,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let definition = match definition.cast::<PreparedStatement>() {
+            Ok(prepared) => prepared.borrow().to_dict(py)?,
+            Err(_) => definition.cast::<PyDict>()?.clone(),
+        };
+        let definition = StatementDefinition::from_dict(&definition)?;
+        let query_str = definition.contents().to_string();
+        self.prepare_statement(py, query_str, timeout_ms, retries, Some(definition))
+    }
+
+    /// Prepare every statement prepared through this session again on all nodes, e.g. after
+    /// a rolling schema migration. Returns how many statements were prepared.
+    pub fn reprepare_all<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let session = self.session.clone();
-        let query_str = query.to_string();
+        let statement_cache = self.statement_cache.clone();
 
         future_into_py(py, async move {
-            let prepared = session
-                .prepare(query_str)
+            statement_cache
+                .reprepare_all(&session)
                 .await
-                .map_err(prepare_error_to_py)?;
-
-            Ok(PreparedStatement {
-                prepared: Arc::new(prepared),
-            })
+                .map_err(prepare_error_to_py)
         })
     }
 
-    #[pyo3(signature = (prepared, values=None))]
+    /// `consistency` and `timeout_ms` override the statement's own settings for this call only.
+    /// `deadline` bounds the whole call as it does for `execute()`.
+    #[pyo3(signature = (prepared, values=None, row_factory=None, consistency=None, timeout_ms=None, deadline=None))]
+    #[allow(clippy::too_many_arguments)]
     pub fn execute_prepared<'py>(
         &self,
         py: Python<'py>,
         prepared: &PreparedStatement,
         values: Option<&Bound<'_, PyDict>>,
+        row_factory: Option<&Bound<'_, PyAny>>,
+        consistency: Option<&str>,
+        timeout_ms: Option<u64>,
+        deadline: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
         let serialized_values = py_dict_to_serialized_values(values)?;
+        check_named_values(&prepared.prepared, &serialized_values)?;
+        if self.strict_types {
+            check_value_types(&prepared.prepared, &serialized_values)?;
+        }
+        let row_factory = self.row_factory(row_factory)?;
+        let consistency = consistency.map(parse_consistency).transpose()?;
+        let deadline = Deadline::from_timestamp(deadline)?;
+
+        self.fairness_classes
+            .check(prepared.fairness_class.as_deref())?;
+
+        let request = self.start_request(
+            py,
+            "execute_prepared",
+            prepared.prepared.get_statement(),
+            consistency.or(prepared.prepared.get_consistency()),
+        )?;
+        let mut overridden = (*prepared.prepared).clone();
+        if let Some(consistency) = consistency {
+            overridden.set_consistency(consistency);
+        }
+        if let Some(ms) = timeout_ms {
+            overridden.set_request_timeout(Some(Duration::from_millis(ms)));
+        }
+        overridden.set_history_listener(request.listener());
+        let prep = Arc::new(overridden);
 
         let session = self.session.clone();
-        let prep = prepared.prepared.clone();
+        let warnings = self.warnings.clone();
+        let py_values = values.map(|values| values.clone().unbind());
+        let fairness_classes = self.fairness_classes.clone();
+        let limiter = self.limiter.clone();
+        let fairness_class = prepared.fairness_class.clone();
 
         future_into_py(py, async move {
-            let result = session
-                .execute_unpaged(&prep, serialized_values)
-                .await
-                .map_err(query_error_to_py)?;
+            let result = within(deadline, async {
+                let _permit = fairness_classes.acquire(fairness_class.as_deref()).await;
+                let _slot = limiter.acquire().await;
+                session
+                    .execute_unpaged(&prep, serialized_values)
+                    .await
+                    .map_err(|err| {
+                        execution_error_to_py(err, |py| {
+                            partition_key_values(py, &prep, py_values.as_ref())
+                        })
+                    })
+            })
+            .await;
+            let stats = request.finish_query(&result);
+
+            let result = QueryResult::new(result?)
+                .with_session(&session)
+                .with_stats(stats)
+                .with_row_factory(row_factory)
+                .with_statement(prep.get_statement());
+            warnings.report(&result)?;
+            Ok(result)
+        })
+    }
+
+    /// Execute a statement bound with `PreparedStatement.bind`. Its values were checked at
+    /// bind time, so any error raised here comes from executing it. `deadline` bounds the
+    /// whole call as it does for `execute()`.
+    #[pyo3(signature = (bound, row_factory=None, deadline=None))]
+    pub fn execute_bound<'py>(
+        &self,
+        py: Python<'py>,
+        bound: &BoundStatement,
+        row_factory: Option<&Bound<'_, PyAny>>,
+        deadline: Option<f64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let row_factory = self.row_factory(row_factory)?;
+        let deadline = Deadline::from_timestamp(deadline)?;
+
+        self.fairness_classes
+            .check(bound.fairness_class.as_deref())?;
+
+        let prepared = bound.bound.prepared();
+        let request = self.start_request(
+            py,
+            "execute_bound",
+            prepared.get_statement(),
+            prepared.get_consistency(),
+        )?;
+        let mut traced = (*bound.bound).clone();
+        traced.set_history_listener(request.listener());
+        let statement = Arc::new(traced);
+
+        let session = self.session.clone();
+        let warnings = self.warnings.clone();
+        let py_values = bound.values.clone();
+        let fairness_classes = self.fairness_classes.clone();
+        let limiter = self.limiter.clone();
+        let fairness_class = bound.fairness_class.clone();
 
-            Ok(QueryResult::new(result))
+        future_into_py(py, async move {
+            let result = within(deadline, async {
+                let _permit = fairness_classes.acquire(fairness_class.as_deref()).await;
+                let _slot = limiter.acquire().await;
+                session
+                    .execute_bound_unpaged(&statement)
+                    .await
+                    .map_err(|err| {
+                        execution_error_to_py(err, |py| {
+                            partition_key_values(py, statement.prepared(), py_values.as_deref())
+                        })
+                    })
+            })
+            .await;
+            let stats = request.finish_query(&result);
+
+            let result = QueryResult::new(result?)
+                .with_session(&session)
+                .with_stats(stats)
+                .with_row_factory(row_factory)
+                .with_statement(statement.prepared().get_statement());
+            warnings.report(&result)?;
+            Ok(result)
         })
     }
 
+    /// `deadline` bounds the whole call as it does for `execute()`.
+    #[pyo3(signature = (batch, values, deadline=None))]
     pub fn batch<'py>(
         &self,
         py: Python<'py>,
         batch: &Batch,
         values: &Bound<'_, PyList>,
+        deadline: Option<f64>,
     ) -> PyResult<Bound<'py, PyAny>> {
+        let row_factory = self.row_factory.clone();
+        let deadline = Deadline::from_timestamp(deadline)?;
+        self.fairness_classes
+            .check(batch.fairness_class.as_deref())?;
+
+        let batch_values = py_list_to_batch_values(values)?;
+
+        let statements: Vec<&str> = batch
+            .inner
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                BatchStatement::Query(query) => Some(query.contents.as_str()),
+                BatchStatement::PreparedStatement(prepared) => Some(prepared.get_statement()),
+                _ => None,
+            })
+            .collect();
+        let request = self.start_request(
+            py,
+            "batch",
+            &statements.join("; "),
+            batch.inner.get_consistency(),
+        )?;
+        let mut scylla_batch = batch.inner.clone();
+        scylla_batch.set_history_listener(request.listener());
+
         let session = self.session.clone();
-        let scylla_batch = batch.inner.clone();
-
-        let mut batch_values = Vec::new();
-        for item in values.iter() {
-            if let Ok(dict) = item.cast::<PyDict>() {
-                let serialized = py_dict_to_serialized_values(Some(dict))?;
-                batch_values.push(serialized);
-            } else {
-                batch_values.push(py_dict_to_serialized_values(None)?);
-            }
-        }
+        let warnings = self.warnings.clone();
+        let fairness_classes = self.fairness_classes.clone();
+        let limiter = self.limiter.clone();
+        let fairness_class = batch.fairness_class.clone();
 
         future_into_py(py, async move {
-            let result = session
-                .batch(&scylla_batch, batch_values)
-                .await
-                .map_err(query_error_to_py)?;
+            let result = within(deadline, async {
+                let _permit = fairness_classes.acquire(fairness_class.as_deref()).await;
+                let _slot = limiter.acquire().await;
+                session
+                    .batch(&scylla_batch, batch_values)
+                    .await
+                    .map_err(query_error_to_py)
+            })
+            .await;
+            let stats = request.finish_query(&result);
 
-            Ok(QueryResult::new(result))
+            let result = QueryResult::new(result?)
+                .with_session(&session)
+                .with_stats(stats)
+                .with_row_factory(row_factory);
+            warnings.report(&result)?;
+            Ok(result)
         })
     }
 
-    pub fn use_keyspace<'py>(
+    /// Run `batch` as consecutive server batches of at most `chunk_size` statements, for
+    /// batches too large to send at once. Each chunk is atomic on its own, not the whole.
+    /// Resolves to `{"succeeded": int, "failed": int, "chunks": [{"index": int,
+    /// "statements": (start, end), "success": bool, "error": str | None, "warnings":
+    /// [str]}]}`, so the failed chunks' statements can be retried.
+    #[pyo3(signature = (batch, values, chunk_size=100))]
+    pub fn batch_chunked<'py>(
         &self,
         py: Python<'py>,
-        keyspace_name: &str,
-        case_sensitive: bool,
+        batch: &Batch,
+        values: &Bound<'_, PyList>,
+        chunk_size: usize,
     ) -> PyResult<Bound<'py, PyAny>> {
+        if chunk_size == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Chunk size must be greater than 0",
+            ));
+        }
+        self.fairness_classes
+            .check(batch.fairness_class.as_deref())?;
+        let batch_values = py_list_to_batch_values(values)?;
+        if batch_values.len() != batch.inner.statements.len() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Batch has {} statements but {} value sets were given",
+                batch.inner.statements.len(),
+                batch_values.len()
+            )));
+        }
+
+        let chunks = batch.chunks(chunk_size);
         let session = self.session.clone();
-        let ks = keyspace_name.to_string();
+        let fairness_classes = self.fairness_classes.clone();
+        let limiter = self.limiter.clone();
+        let fairness_class = batch.fairness_class.clone();
 
         future_into_py(py, async move {
-            session
-                .use_keyspace(ks, case_sensitive)
-                .await
-                .map_err(use_keyspace_error_to_py)?;
+            let _permit = fairness_classes.acquire(fairness_class.as_deref()).await;
+            let outcomes = execute_chunks(&session, &limiter, chunks, &batch_values).await;
 
-            Ok(())
+            Python::attach(|py| {
+                let succeeded = outcomes.iter().filter(|o| o.succeeded()).count();
+                let chunks = outcomes
+                    .iter()
+                    .map(|outcome| outcome.to_py(py))
+                    .collect::<PyResult<Vec<_>>>()?;
+                let summary = PyDict::new(py);
+                summary.set_item("succeeded", succeeded)?;
+                summary.set_item("failed", outcomes.len() - succeeded)?;
+                summary.set_item("chunks", chunks)?;
+                Ok(summary.unbind())
+            })
         })
     }
 
-    pub fn await_schema_agreement<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+    pub fn execute_pipeline<'py>(
+        &self,
+        py: Python<'py>,
+        pipeline: &Pipeline,
+    ) -> PyResult<Bound<'py, PyAny>> {
         let session = self.session.clone();
+        let pipeline = pipeline.clone();
+        let limiter = self.limiter.clone();
 
         future_into_py(py, async move {
-            session
+            let outcomes = pipeline.run(session, limiter).await;
+
+            Python::attach(|py| {
+                outcomes
+                    .iter()
+                    .map(|outcome| outcome.to_py(py))
+                    .collect::<PyResult<Vec<_>>>()
+            })
+        })
+    }
+
+    /// Execute `prepared` once per parameter set (a dict, or `None` for no values) drawn
+    /// lazily from `params`, with at most `window` executions in flight. Resolves to
+    /// `{"succeeded": int, "failed": int, "errors": [{"index": int, "error": str}]}`.
+    #[pyo3(signature = (prepared, params, window=64))]
+    pub fn execute_many<'py>(
+        &self,
+        prepared: &PreparedStatement,
+        params: &Bound<'py, PyAny>,
+        window: usize,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if window == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Window must be greater than 0",
+            ));
+        }
+        self.fairness_classes
+            .check(prepared.fairness_class.as_deref())?;
+
+        let py = params.py();
+        let params = params.try_iter()?.unbind();
+        let target = ManyTarget {
+            prepared: prepared.prepared.clone(),
+            fairness_classes: self.fairness_classes.clone(),
+            fairness_class: prepared.fairness_class.clone(),
+            limiter: self.limiter.clone(),
+            strict_types: self.strict_types,
+        };
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            let summary = execute_many(session, target, params, window).await?;
+            Python::attach(|py| summary.to_py(py))
+        })
+    }
+
+    /// `INSERT ... IF NOT EXISTS`. Resolves to `(applied, current_row)`, where
+    /// `current_row` is the row that already existed when the insert wasn't applied.
+    #[pyo3(signature = (table, values, ttl=None))]
+    pub fn insert_if_not_exists<'py>(
+        &self,
+        py: Python<'py>,
+        table: &str,
+        values: &Bound<'_, PyDict>,
+        ttl: Option<i32>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        validate_table_name(table)?;
+        let (columns, mut values) = columns_and_values(values)?;
+        if columns.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Values must contain at least one column",
+            ));
+        }
+        let using_ttl = match ttl {
+            Some(ttl) if ttl <= 0 => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "TTL must be greater than 0",
+                ))
+            }
+            Some(ttl) => {
+                values.push(SerializableValue::Int(ttl));
+                " USING TTL ?"
+            }
+            None => "",
+        };
+
+        let cql = format!(
+            "INSERT INTO {} ({}) VALUES ({}) IF NOT EXISTS{}",
+            table,
+            columns.join(", "),
+            vec!["?"; columns.len()].join(", "),
+            using_ttl
+        );
+        self.run_lwt(py, Lwt { cql, values })
+    }
+
+    /// Update the row at `key` with `values` only if its columns currently equal
+    /// `expected` (or, without `expected`, if the row exists). Resolves to
+    /// `(applied, current_row)`.
+    #[pyo3(signature = (table, key, values, expected=None))]
+    pub fn compare_and_set<'py>(
+        &self,
+        py: Python<'py>,
+        table: &str,
+        key: &Bound<'_, PyDict>,
+        values: &Bound<'_, PyDict>,
+        expected: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        validate_table_name(table)?;
+        let (set_columns, mut all_values) = columns_and_values(values)?;
+        let (key_columns, key_values) = columns_and_values(key)?;
+        if set_columns.is_empty() || key_columns.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Key and values must each contain at least one column",
+            ));
+        }
+        all_values.extend(key_values);
+        let condition = match expected {
+            Some(expected) if !expected.is_empty() => {
+                let (columns, values) = columns_and_values(expected)?;
+                all_values.extend(values);
+                conditions(&columns)
+            }
+            _ => "EXISTS".to_string(),
+        };
+
+        let assignments: Vec<String> = set_columns
+            .iter()
+            .map(|column| format!("{} = ?", column))
+            .collect();
+        let cql = format!(
+            "UPDATE {} SET {} WHERE {} IF {}",
+            table,
+            assignments.join(", "),
+            conditions(&key_columns),
+            condition
+        );
+        self.run_lwt(
+            py,
+            Lwt {
+                cql,
+                values: all_values,
+            },
+        )
+    }
+
+    /// Delete the row at `key` only if its columns currently equal `expected` (or,
+    /// without `expected`, if the row exists). Resolves to `(applied, current_row)`.
+    #[pyo3(signature = (table, key, expected=None))]
+    pub fn delete_if<'py>(
+        &self,
+        py: Python<'py>,
+        table: &str,
+        key: &Bound<'_, PyDict>,
+        expected: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        validate_table_name(table)?;
+        let (key_columns, mut values) = columns_and_values(key)?;
+        if key_columns.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Key must contain at least one column",
+            ));
+        }
+        let condition = match expected {
+            Some(expected) if !expected.is_empty() => {
+                let (columns, expected_values) = columns_and_values(expected)?;
+                values.extend(expected_values);
+                conditions(&columns)
+            }
+            _ => "EXISTS".to_string(),
+        };
+
+        let cql = format!(
+            "DELETE FROM {} WHERE {} IF {}",
+            table,
+            conditions(&key_columns),
+            condition
+        );
+        self.run_lwt(py, Lwt { cql, values })
+    }
+
+    #[pyo3(signature = (table, primary_key, ttl=None, marker_column="deleted_at"))]
+    pub fn soft_delete<'py>(
+        &self,
+        py: Python<'py>,
+        table: &str,
+        primary_key: &Bound<'_, PyDict>,
+        ttl: Option<i32>,
+        marker_column: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        validate_table_name(table)?;
+        validate_identifier(marker_column)?;
+        if primary_key.is_empty() {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Primary key must contain at least one column",
+            ));
+        }
+
+        let mut conditions = Vec::new();
+        for key in primary_key.keys() {
+            let column = key.extract::<String>()?;
+            validate_identifier(&column)?;
+            conditions.push(format!("{} = ?", column));
+        }
+
+        let mut values = py_dict_to_serialized_values(Some(primary_key))?;
+        let using_ttl = match ttl {
+            Some(ttl) if ttl <= 0 => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "TTL must be greater than 0",
+                ))
+            }
+            Some(ttl) => {
+                values.insert("[ttl]".to_string(), SerializableValue::Int(ttl));
+                " USING TTL ?"
+            }
+            None => "",
+        };
+
+        let cql = format!(
+            "UPDATE {}{} SET {} = toTimestamp(now()) WHERE {}",
+            table,
+            using_ttl,
+            marker_column,
+            conditions.join(" AND ")
+        );
+
+        let primary_key = primary_key.clone().unbind();
+        let session = self.session.clone();
+        let statement_cache = self.statement_cache.clone();
+        let limiter = self.limiter.clone();
+
+        future_into_py(py, async move {
+            let prepared = statement_cache
+                .get_or_prepare(&session, &cql)
+                .await
+                .map_err(prepare_error_to_py)?;
+            let _slot = limiter.acquire().await;
+            session
+                .execute_unpaged(&prepared, values)
+                .await
+                .map_err(|err| {
+                    execution_error_to_py(err, |py| {
+                        partition_key_values(py, &prepared, Some(&primary_key))
+                    })
+                })?;
+
+            Ok(())
+        })
+    }
+
+    /// Read the CDC log of `keyspace.table` from `start_ms` (default: now) on, as an async
+    /// iterator of `ChangeEvent`s. Without `end_ms` it keeps polling for new changes.
+    #[pyo3(signature = (keyspace, table, start_ms=None, end_ms=None, poll_interval_ms=1000, confidence_window_ms=30000))]
+    pub fn cdc_reader(
+        &self,
+        keyspace: &str,
+        table: &str,
+        start_ms: Option<i64>,
+        end_ms: Option<i64>,
+        poll_interval_ms: u64,
+        confidence_window_ms: i64,
+    ) -> PyResult<CDCReader> {
+        validate_identifier(keyspace)?;
+        validate_identifier(table)?;
+        Ok(CDCReader::new(
+            self.session.clone(),
+            format!("{}.{}_scylla_cdc_log", keyspace, table),
+            start_ms,
+            end_ms,
+            poll_interval_ms,
+            confidence_window_ms,
+        ))
+    }
+
+    /// Map dataclass `cls` onto `table`, whose columns are named after the class fields.
+    #[pyo3(signature = (cls, table, primary_key, keyspace=None, soft_delete_marker=None))]
+    pub fn mapper(
+        &self,
+        cls: &Bound<'_, PyAny>,
+        table: &str,
+        primary_key: Vec<String>,
+        keyspace: Option<&str>,
+        soft_delete_marker: Option<String>,
+    ) -> PyResult<Mapper> {
+        let table = match keyspace {
+            Some(keyspace) => format!("{}.{}", validate_identifier(keyspace)?, table),
+            None => table.to_string(),
+        };
+        Mapper::new(
+            cls.py(),
+            self.session.clone(),
+            self.statement_cache.clone(),
+            self.limiter.clone(),
+            cls,
+            table,
+            primary_key,
+            soft_delete_marker,
+        )
+    }
+
+    /// Load a CSV, TSV or Parquet file into `table`, like cqlsh's `COPY FROM`. The file's
+    /// header (or Parquet schema) names the columns. Rows are grouped by partition into
+    /// unlogged batches of up to `batch_size`, with at most `concurrency` batches in flight.
+    /// `on_progress(rows_loaded)` is called as rows land. Resolves to the number of rows
+    /// loaded.
+    #[pyo3(signature = (table, path, format="csv", concurrency=16, batch_size=50, on_progress=None))]
+    pub fn copy_from<'py>(
+        &self,
+        table: &str,
+        path: &Bound<'py, PyAny>,
+        format: &str,
+        concurrency: usize,
+        batch_size: usize,
+        on_progress: Option<Py<PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if concurrency == 0 || batch_size == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Concurrency and batch size must be greater than 0",
+            ));
+        }
+        let options = CopyOptions {
+            table: validate_table_name(table)?.to_string(),
+            path: path.extract()?,
+            format: Format::parse(format)?,
+            concurrency,
+            batch_size,
+            on_progress,
+        };
+        let session = self.session.clone();
+        let limiter = self.limiter.clone();
+
+        future_into_py(path.py(), copy_from(session, limiter, options))
+    }
+
+    /// Export every row of a table, or of a query's result, to a CSV, TSV or Parquet file.
+    /// Pages are converted and written in Rust without building Python rows.
+    /// `on_progress(rows_written)` is called after each page. Resolves to the number of
+    /// rows written.
+    #[pyo3(signature = (query_or_table, path, format="csv", values=None, page_size=None, on_progress=None))]
+    pub fn copy_to<'py>(
+        &self,
+        query_or_table: &Bound<'py, PyAny>,
+        path: &Bound<'py, PyAny>,
+        format: &str,
+        values: Option<&Bound<'_, PyDict>>,
+        page_size: Option<i32>,
+        on_progress: Option<Py<PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let page_size = self.page_size(page_size, DEFAULT_SCAN_PAGE_SIZE);
+        if page_size <= 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Page size must be greater than 0",
+            ));
+        }
+
+        let py = query_or_table.py();
+        let mut statement = match query_or_table.extract::<&str>() {
+            Ok(table) if validate_table_name(table).is_ok() => {
+                Statement::Unprepared(ScyllaStatement::new(format!("SELECT * FROM {}", table)))
+            }
+            _ => Statement::extract(query_or_table)?,
+        };
+        statement.set_page_size(page_size);
+        let values = py_dict_to_serialized_values(values)?;
+        let options = ExportOptions {
+            path: path.extract()?,
+            format: Format::parse(format)?,
+            on_progress,
+        };
+        let session = self.session.clone();
+        let limiter = self.limiter.clone();
+
+        future_into_py(py, copy_to(session, limiter, statement, values, options))
+    }
+
+    #[pyo3(signature = (statement, values=None, page_size=None, paging_state=None, read_ahead=None, prefetch=0))]
+    pub fn paginate(
+        &self,
+        statement: &Bound<'_, PyAny>,
+        values: Option<&Bound<'_, PyDict>>,
+        page_size: Option<i32>,
+        paging_state: Option<Vec<u8>>,
+        read_ahead: Option<f64>,
+        prefetch: usize,
+    ) -> PyResult<Pager> {
+        let page_size = self.page_size(page_size, DEFAULT_PAGINATE_PAGE_SIZE);
+        if page_size <= 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Page size must be greater than 0",
+            ));
+        }
+        if read_ahead.is_some_and(|fraction| !(0.0..=1.0).contains(&fraction)) {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Read-ahead must be a fraction between 0.0 and 1.0",
+            ));
+        }
+        if read_ahead.is_some() && prefetch > 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Read-ahead and prefetch cannot be combined",
+            ));
+        }
+
+        let mut statement = Statement::extract(statement)?;
+        statement.set_page_size(page_size);
+        let values = py_dict_to_serialized_values(values)?;
+
+        Ok(Pager::new(
+            self.session.clone(),
+            self.limiter.clone(),
+            statement,
+            values,
+            paging_state,
+            read_ahead,
+            prefetch,
+        ))
+    }
+
+    /// Iterate over a paged statement one `QueryResult` page at a time.
+    #[pyo3(signature = (statement, values=None, page_size=None, paging_state=None))]
+    pub fn pages(
+        &self,
+        statement: &Bound<'_, PyAny>,
+        values: Option<&Bound<'_, PyDict>>,
+        page_size: Option<i32>,
+        paging_state: Option<Vec<u8>>,
+    ) -> PyResult<PageIterator> {
+        let page_size = self.page_size(page_size, DEFAULT_PAGINATE_PAGE_SIZE);
+        if page_size <= 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Page size must be greater than 0",
+            ));
+        }
+
+        let mut statement = Statement::extract(statement)?;
+        statement.set_page_size(page_size);
+        let values = py_dict_to_serialized_values(values)?;
+
+        Ok(PageIterator::new(
+            self.session.clone(),
+            self.limiter.clone(),
+            statement,
+            values,
+            paging_state,
+            self.row_factory.clone(),
+        ))
+    }
+
+    /// Stream every row of a paged statement to `on_row`, or each page to
+    /// `on_page(rows, column_names)`, fetching at most `prefetch_pages` pages ahead of the
+    /// callback. Resolves to the number of rows delivered.
+    #[pyo3(signature = (statement, values=None, on_row=None, on_page=None, page_size=None, prefetch_pages=2))]
+    pub fn execute_stream<'py>(
+        &self,
+        statement: &Bound<'py, PyAny>,
+        values: Option<&Bound<'_, PyDict>>,
+        on_row: Option<Py<PyAny>>,
+        on_page: Option<Py<PyAny>>,
+        page_size: Option<i32>,
+        prefetch_pages: usize,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let callback = match (on_row, on_page) {
+            (Some(on_row), None) => StreamCallback::Row(on_row),
+            (None, Some(on_page)) => StreamCallback::Page(on_page),
+            _ => {
+                return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                    "Exactly one of on_row and on_page must be given",
+                ))
+            }
+        };
+        let page_size = self.page_size(page_size, DEFAULT_PAGINATE_PAGE_SIZE);
+        if page_size <= 0 || prefetch_pages == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Page size and prefetch pages must be greater than 0",
+            ));
+        }
+
+        let py = statement.py();
+        let mut statement = Statement::extract(statement)?;
+        statement.set_page_size(page_size);
+        let values = py_dict_to_serialized_values(values)?;
+        let session = self.session.clone();
+        let limiter = self.limiter.clone();
+
+        future_into_py(py, async move {
+            execute_stream(
+                session,
+                limiter,
+                statement,
+                values,
+                callback,
+                prefetch_pages,
+            )
+            .await
+        })
+    }
+
+    /// With `bypass_cache`, the scan reads past Scylla's row cache instead of filling it
+    /// with the whole table.
+    #[pyo3(signature = (
+        table, concurrency=16, ranges_per_task=1, columns=None, page_size=None, bypass_cache=false
+    ))]
+    pub fn full_scan(
+        &self,
+        table: &str,
+        concurrency: usize,
+        ranges_per_task: usize,
+        columns: Option<Vec<String>>,
+        page_size: Option<i32>,
+        bypass_cache: bool,
+    ) -> PyResult<FullScan> {
+        let page_size = self.page_size(page_size, DEFAULT_SCAN_PAGE_SIZE);
+        if concurrency == 0 || ranges_per_task == 0 || page_size <= 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Concurrency, ranges per task and page size must be greater than 0",
+            ));
+        }
+
+        let columns = columns.unwrap_or_default();
+        for column in &columns {
+            validate_identifier(column)?;
+        }
+        let (keyspace, table) = match validate_table_name(table)?.split_once('.') {
+            Some((keyspace, table)) => (keyspace.to_string(), table.to_string()),
+            None => match self.session.get_keyspace() {
+                Some(keyspace) => (keyspace.to_string(), table.to_string()),
+                None => {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Table must be qualified with a keyspace when none is in use",
+                    ))
+                }
+            },
+        };
+
+        Ok(FullScan::start(
+            self.session.clone(),
+            self.statement_cache.clone(),
+            ScanOptions {
+                keyspace,
+                table,
+                columns,
+                concurrency,
+                ranges_per_task,
+                page_size,
+                bypass_cache,
+                limiter: self.limiter.clone(),
+            },
+        ))
+    }
+
+    #[pyo3(signature = (concurrency=64))]
+    pub fn ordered_writer(&self, concurrency: usize) -> PyResult<OrderedWriter> {
+        if concurrency == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Concurrency must be greater than 0",
+            ));
+        }
+        Ok(OrderedWriter::new(
+            self.session.clone(),
+            self.limiter.clone(),
+            concurrency,
+        ))
+    }
+
+    pub fn use_keyspace<'py>(
+        &self,
+        py: Python<'py>,
+        keyspace_name: &str,
+        case_sensitive: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+        let ks = keyspace_name.to_string();
+
+        future_into_py(py, async move {
+            session
+                .use_keyspace(ks, case_sensitive)
+                .await
+                .map_err(use_keyspace_error_to_py)?;
+
+            Ok(())
+        })
+    }
+
+    /// Create keyspace `name` and wait for the cluster to agree on the schema. `replication`
+    /// holds the strategy `class` and its options, e.g. `{"class": "NetworkTopologyStrategy",
+    /// "dc1": 3, "dc2": 2}` or `{"class": "SimpleStrategy", "replication_factor": 1}`.
+    #[pyo3(signature = (name, replication, durable_writes=true, if_not_exists=true))]
+    pub fn create_keyspace<'py>(
+        &self,
+        py: Python<'py>,
+        name: &str,
+        replication: &Bound<'_, PyDict>,
+        durable_writes: bool,
+        if_not_exists: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let statement =
+            create_keyspace_statement(name, replication, durable_writes, if_not_exists)?;
+        self.run_schema_change(py, statement)
+    }
+
+    /// Drop keyspace `name` and wait for the cluster to agree on the schema.
+    #[pyo3(signature = (name, if_exists=true))]
+    pub fn drop_keyspace<'py>(
+        &self,
+        py: Python<'py>,
+        name: &str,
+        if_exists: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let statement = drop_keyspace_statement(name, if_exists)?;
+        self.run_schema_change(py, statement)
+    }
+
+    pub fn await_schema_agreement<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            session
                 .await_schema_agreement()
                 .await
                 .map_err(schema_agreement_error_to_py)?;
@@ -271,9 +1872,360 @@ impl Session {
         })
     }
 
+    pub fn cluster_metadata<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+
+        future_into_py(
+            py,
+            async move { Ok(fetch_cluster_metadata(&session).await) },
+        )
+    }
+
+    /// Kept for backwards compatibility: a one-line summary of the topology the driver
+    /// knows. Use `cluster_metadata()` for the nodes themselves.
     pub fn get_cluster_data(&self) -> PyResult<String> {
-        // ClusterData doesn't implement Debug, so we return a simple message
-        Ok("ClusterData available (not serializable)".to_string())
+        let cluster_state = self.session.get_cluster_state();
+        let nodes = cluster_state.get_nodes_info();
+        let mut datacenters: Vec<&str> = nodes
+            .iter()
+            .filter_map(|node| node.datacenter.as_deref())
+            .collect();
+        datacenters.sort();
+        datacenters.dedup();
+        Ok(format!(
+            "ClusterData(nodes={}, datacenters={:?})",
+            nodes.len(),
+            datacenters
+        ))
+    }
+
+    #[pyo3(signature = (poll_interval_ms=1000))]
+    pub fn cluster_events(&self, poll_interval_ms: u64) -> PyResult<ClusterEvents> {
+        if poll_interval_ms == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Poll interval must be greater than 0",
+            ));
+        }
+        Ok(ClusterEvents::start(
+            self.session.clone(),
+            Duration::from_millis(poll_interval_ms),
+            false,
+        ))
+    }
+
+    /// Like `cluster_events()`, limited to keyspace, table, view and type changes.
+    #[pyo3(signature = (poll_interval_ms=1000))]
+    pub fn schema_events(&self, poll_interval_ms: u64) -> PyResult<ClusterEvents> {
+        if poll_interval_ms == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Poll interval must be greater than 0",
+            ));
+        }
+        Ok(ClusterEvents::start(
+            self.session.clone(),
+            Duration::from_millis(poll_interval_ms),
+            true,
+        ))
+    }
+
+    pub fn get_tracing_info<'py>(
+        &self,
+        py: Python<'py>,
+        tracing_id: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let tracing_id = Uuid::parse_str(tracing_id).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Invalid tracing id: {}", e))
+        })?;
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            let info = session
+                .get_tracing_info(&tracing_id)
+                .await
+                .map_err(tracing_error_to_py)?;
+            Ok(TracingInfo::from(info))
+        })
+    }
+
+    pub fn keyspaces<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+
+        future_into_py(py, async move { fetch_keyspaces(&session).await })
+    }
+
+    pub fn keyspace<'py>(&self, py: Python<'py>, name: String) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            let mut keyspaces = fetch_keyspaces(&session).await?;
+            Ok(keyspaces.remove(&name))
+        })
+    }
+
+    #[pyo3(signature = (keyspace, table=None))]
+    pub fn token_ranges(
+        &self,
+        keyspace: &str,
+        table: Option<&str>,
+    ) -> PyResult<Vec<(i64, i64, Vec<String>)>> {
+        token_ranges(&self.session.get_cluster_state(), keyspace, table)
+    }
+
+    /// Murmur3 token of the partition identified by `partition_key` (partition key column values).
+    pub fn token_for(
+        &self,
+        keyspace: &str,
+        table: &str,
+        partition_key: &Bound<'_, PyDict>,
+    ) -> PyResult<i64> {
+        let values = py_dict_to_serialized_values(Some(partition_key))?;
+        let token = self
+            .session
+            .get_cluster_state()
+            .compute_token(keyspace, table, &values)
+            .map_err(token_error_to_py)?;
+        Ok(token.value())
+    }
+
+    /// `(address, shard)` of every replica owning the partition, primary replica first.
+    pub fn replicas_for(
+        &self,
+        keyspace: &str,
+        table: &str,
+        partition_key: &Bound<'_, PyDict>,
+    ) -> PyResult<Vec<(String, u32)>> {
+        let values = py_dict_to_serialized_values(Some(partition_key))?;
+        let replicas = self
+            .session
+            .get_cluster_state()
+            .get_endpoints(keyspace, table, &values)
+            .map_err(token_error_to_py)?;
+        Ok(replicas
+            .into_iter()
+            .map(|(node, shard)| (node.address.to_string(), shard))
+            .collect())
+    }
+
+    /// Driver metrics, plus the client-side rate limiter's usage under `rate_limiter`
+    /// when the session has one.
+    pub fn metrics<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let metrics = metrics_snapshot(py, &self.session)?;
+        if self.limiter.is_enabled() {
+            metrics.set_item("rate_limiter", self.limiter.usage(py)?)?;
+        }
+        Ok(metrics)
+    }
+
+    /// Current driver metrics in the Prometheus text exposition format.
+    pub fn prometheus_metrics(&self) -> String {
+        prometheus::render(&self.session, self.node_latencies.as_deref())
+    }
+
+    /// Serve `/metrics` for Prometheus scrapes on `address`; every scrape reads live counters.
+    #[pyo3(signature = (address="127.0.0.1:9464"))]
+    pub fn serve_prometheus<'py>(
+        &self,
+        py: Python<'py>,
+        address: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        PrometheusExporter::start(
+            py,
+            address.to_string(),
+            self.session.clone(),
+            self.node_latencies.clone(),
+        )
+    }
+
+    pub fn node_latencies(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match &self.node_latencies {
+            Some(latencies) => latencies.to_py(py),
+            None => Ok(PyDict::new(py).into()),
+        }
+    }
+
+    /// Requests abandoned mid-flight because the task awaiting them was cancelled, e.g. by
+    /// `asyncio.wait_for`. The request is dropped rather than left running.
+    pub fn orphaned_requests(&self) -> u64 {
+        self.orphaned_requests.count()
+    }
+
+    /// Requests started and not yet finished, including those waiting on the rate limiter
+    /// or a fairness class, to shed load before the driver becomes the bottleneck.
+    pub fn inflight_requests(&self) -> usize {
+        self.load.in_flight()
+    }
+
+    /// Request attempts awaiting a response per node address, with nodes that have none
+    /// left out. Empty unless built with `SessionBuilder.track_node_requests()`.
+    pub fn node_queue_depths(&self) -> HashMap<String, usize> {
+        self.load.node_attempts()
+    }
+
+    /// Addresses the hostname contact points resolved to when last looked up by
+    /// `SessionBuilder.dns_refresh()`; empty when it's disabled.
+    pub fn contact_points(&self) -> Vec<String> {
+        self.contact_points.resolved()
+    }
+
+    /// A summary of the session for bug reports and startup logs: the contact points as
+    /// configured and resolved, every known node with its connection state, the keyspace,
+    /// compression, connection pool size, page size and service level, and the rsylla,
+    /// driver and CQL protocol versions. Reads local state only, without a round trip.
+    pub fn describe<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let nodes = PyList::empty(py);
+        for node in self.session.get_cluster_state().get_nodes_info() {
+            let info = PyDict::new(py);
+            info.set_item("address", node.address.to_string())?;
+            info.set_item("datacenter", node.datacenter.as_deref())?;
+            info.set_item("rack", node.rack.as_deref())?;
+            info.set_item("connected", node.is_connected())?;
+            nodes.append(info)?;
+        }
+        let (pool_kind, pool_size) = match self.pool_size {
+            PoolSize::PerHost(size) => ("per_host", size.get()),
+            PoolSize::PerShard(size) => ("per_shard", size.get()),
+        };
+        let pool = PyDict::new(py);
+        pool.set_item("kind", pool_kind)?;
+        pool.set_item("size", pool_size)?;
+
+        let summary = PyDict::new(py);
+        summary.set_item("contact_points", self.contact_points.configured())?;
+        summary.set_item("resolved_contact_points", self.contact_points.resolved())?;
+        summary.set_item("nodes", nodes)?;
+        summary.set_item("keyspace", self.get_keyspace())?;
+        summary.set_item("compression", self.compression)?;
+        summary.set_item("pool_size", pool)?;
+        summary.set_item("page_size", self.default_page_size)?;
+        summary.set_item("service_level", &self.service_level)?;
+        summary.set_item("rsylla_version", env!("CARGO_PKG_VERSION"))?;
+        summary.set_item("driver_version", env!("RSYLLA_SCYLLA_VERSION"))?;
+        summary.set_item("protocol_version", protocol_versions().into_iter().max())?;
+        Ok(summary)
+    }
+
+    /// The service level configured with `SessionBuilder.service_level()`, if any.
+    pub fn service_level(&self) -> Option<String> {
+        self.service_level.clone()
+    }
+
+    /// Attach service level `service_level` to `role`, replacing the level it had. This is
+    /// an administrative change to the cluster, not to this session: it applies to every
+    /// client logging in as `role`, and needs permission to alter roles.
+    pub fn attach_service_level<'py>(
+        &self,
+        py: Python<'py>,
+        service_level: &str,
+        role: &str,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let statement = format!(
+            "ATTACH SERVICE_LEVEL {} TO \"{}\"",
+            validate_identifier(service_level)?,
+            role.replace('"', "\"\"")
+        );
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            session
+                .query_unpaged(statement, &[])
+                .await
+                .map_err(query_error_to_py)?;
+            Ok(())
+        })
+    }
+
+    /// Whether the session holds an open connection to at least one node. Reads pool
+    /// state without a round trip; use `check_connectivity()` to probe the nodes.
+    pub fn is_connected(&self) -> bool {
+        self.session
+            .get_cluster_state()
+            .get_nodes_info()
+            .iter()
+            .any(|node| node.is_connected())
+    }
+
+    /// Query `system.local` on every node concurrently, bypassing the rate limiter and
+    /// retries, and return a `NodeHealth` per node, e.g. for a readiness probe.
+    #[pyo3(signature = (timeout_ms=2000))]
+    pub fn check_connectivity<'py>(
+        &self,
+        py: Python<'py>,
+        timeout_ms: u64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if timeout_ms == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "timeout_ms must be greater than 0",
+            ));
+        }
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            Ok(check_connectivity(&session, Duration::from_millis(timeout_ms)).await)
+        })
+    }
+
+    /// The client connections each connected node lists in its `system.clients` table, to
+    /// check how the application's connections spread over the nodes' shards. Scylla
+    /// doesn't say which process opened a connection, so `own_only` narrows them to those
+    /// opened by the Rust driver rsylla is built on, which includes other processes using
+    /// it; each connection's `address` tells the client hosts apart.
+    #[pyo3(signature = (own_only=true))]
+    pub fn list_server_connections<'py>(
+        &self,
+        py: Python<'py>,
+        own_only: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            list_server_connections(&session, own_only).await
+        })
+    }
+
+    /// What the cluster's nodes advertise in their SUPPORTED response to an OPTIONS
+    /// request, asked of every connected node concurrently, to feature-gate behavior per
+    /// cluster. Resolves to a dict with the `cql_versions`, `compression` algorithms and
+    /// `protocol_versions` every node accepts, the Scylla protocol `extensions` they all
+    /// advertise, flags for `shard_aware`, `rate_limit_error`, `lwt_metadata_mark` and
+    /// `tablets` support, the number of `shards` per node, the raw options per node under
+    /// `nodes`, and `errors` for the nodes that didn't answer.
+    #[pyo3(signature = (timeout_ms=2000))]
+    pub fn supported_features<'py>(
+        &self,
+        py: Python<'py>,
+        timeout_ms: u64,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        if timeout_ms == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "timeout_ms must be greater than 0",
+            ));
+        }
+        let session = self.session.clone();
+
+        future_into_py(py, async move {
+            let features = fetch_features(&session, Duration::from_millis(timeout_ms)).await;
+            Python::attach(|py| features.to_py(py).map(Bound::unbind))
+        })
+    }
+
+    /// The execution profile registered under `name`, e.g. from a config file's
+    /// `[profiles.<name>]` table, to pass to `with_execution_profile()`.
+    pub fn execution_profile(&self, name: &str) -> PyResult<ExecutionProfile> {
+        self.profiles.get(name).cloned().ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyKeyError, _>(format!(
+                "No execution profile named '{}'",
+                name
+            ))
+        })
+    }
+
+    /// Statements currently in flight per capped fairness class.
+    pub fn fairness_in_flight(&self) -> HashMap<String, usize> {
+        self.fairness_classes.in_flight()
+    }
+
+    pub fn take_warnings(&self) -> Vec<String> {
+        self.warnings.take()
     }
 
     pub fn get_keyspace(&self) -> Option<String> {
@@ -0,0 +1,52 @@
+use scylla::client::session::Session as ScyllaSession;
+use scylla::errors::PrepareError;
+use scylla::statement::prepared::PreparedStatement as ScyllaPreparedStatement;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+
+/// Prepared statements generated by session-level helpers, keyed by CQL text.
+#[derive(Default)]
+pub struct StatementCache {
+    statements: Mutex<HashMap<String, ScyllaPreparedStatement>>,
+    /// CQL text of statements handed out by `Session.prepare`, which live on in Python.
+    user_statements: Mutex<BTreeSet<String>>,
+}
+
+impl StatementCache {
+    pub async fn get_or_prepare(
+        &self,
+        session: &ScyllaSession,
+        cql: &str,
+    ) -> Result<ScyllaPreparedStatement, PrepareError> {
+        if let Some(prepared) = self.statements.lock().unwrap().get(cql) {
+            return Ok(prepared.clone());
+        }
+
+        let prepared = session.prepare(cql).await?;
+        self.statements
+            .lock()
+            .unwrap()
+            .insert(cql.to_string(), prepared.clone());
+        Ok(prepared)
+    }
+
+    pub fn remember(&self, cql: &str) {
+        self.user_statements.lock().unwrap().insert(cql.to_string());
+    }
+
+    /// Prepare every statement the session knows about again, on every node. The driver
+    /// already re-prepares a statement on a node that answers `Unprepared`; this does it
+    /// up front, e.g. after a rolling schema migration. Returns how many were prepared.
+    pub async fn reprepare_all(&self, session: &ScyllaSession) -> Result<usize, PrepareError> {
+        let mut statements = self.user_statements.lock().unwrap().clone();
+        statements.extend(self.statements.lock().unwrap().keys().cloned());
+
+        for cql in &statements {
+            let prepared = session.prepare(cql.as_str()).await?;
+            if let Some(cached) = self.statements.lock().unwrap().get_mut(cql) {
+                *cached = prepared;
+            }
+        }
+        Ok(statements.len())
+    }
+}
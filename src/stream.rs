@@ -0,0 +1,147 @@
+use pyo3::prelude::*;
+use pyo3::types::PyList;
+use pyo3_async_runtimes::tokio::{get_runtime, into_future};
+use scylla::client::session::Session as ScyllaSession;
+use scylla::response::{PagingState, PagingStateResponse};
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use tokio::sync::mpsc;
+
+use crate::error::query_error_to_py;
+use crate::pager::{page_rows, PageRows};
+use crate::query::Statement;
+use crate::rate_limit::RequestLimiter;
+use crate::result::Row;
+use crate::types::SerializableValue;
+
+type Page = PyResult<PageRows>;
+
+/// Where streamed rows are delivered: one call per row, or one call per page with a
+/// list of rows. Callbacks may be coroutine functions; their result is awaited before
+/// the next delivery.
+pub enum StreamCallback {
+    Row(Py<PyAny>),
+    Page(Py<PyAny>),
+}
+
+impl StreamCallback {
+    /// Call the callback on rows until one returns an awaitable, which is handed back
+    /// to be awaited outside the GIL.
+    fn deliver_until_pending(
+        &self,
+        py: Python,
+        names: &Arc<[String]>,
+        rows: &mut impl Iterator<Item = Row>,
+        delivered: &mut u64,
+    ) -> PyResult<Option<impl Future<Output = PyResult<Py<PyAny>>> + Send + 'static>> {
+        loop {
+            let result = match self {
+                StreamCallback::Row(on_row) => {
+                    let Some(row) = rows.next() else {
+                        return Ok(None);
+                    };
+                    *delivered += 1;
+                    on_row.call1(py, (row,))?
+                }
+                StreamCallback::Page(on_page) => {
+                    let page: Vec<Row> = rows.collect();
+                    if page.is_empty() {
+                        return Ok(None);
+                    }
+                    *delivered += page.len() as u64;
+                    on_page.call1(py, (PyList::new(py, page)?, names.to_vec()))?
+                }
+            };
+
+            let result = result.into_bound(py);
+            if result.hasattr("__await__")? {
+                return into_future(result).map(Some);
+            }
+        }
+    }
+
+    async fn deliver(&self, (names, rows): PageRows) -> PyResult<u64> {
+        let mut rows = rows
+            .into_iter()
+            .map(|row| Row::with_names(row, names.clone()));
+        let mut delivered = 0;
+        loop {
+            let pending = Python::attach(|py| {
+                self.deliver_until_pending(py, &names, &mut rows, &mut delivered)
+            })?;
+            match pending {
+                Some(pending) => {
+                    pending.await?;
+                }
+                None => return Ok(delivered),
+            }
+        }
+    }
+}
+
+/// Fetch pages in order until paging ends or the consumer goes away. The bounded
+/// channel makes the fetcher wait whenever the consumer falls behind. Each page waits for
+/// the session's rate limit.
+async fn fetch_pages(
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    statement: Statement,
+    values: HashMap<String, SerializableValue>,
+    pages: mpsc::Sender<Page>,
+) {
+    let mut paging_state = PagingState::start();
+    loop {
+        let slot = limiter.acquire().await;
+        let page = statement
+            .execute_single_page(&session, &values, paging_state)
+            .await
+            .map_err(query_error_to_py);
+        drop(slot);
+        let (result, paging_state_response) = match page {
+            Ok(page) => page,
+            Err(err) => {
+                let _ = pages.send(Err(err)).await;
+                return;
+            }
+        };
+
+        let rows = page_rows(result);
+        let failed = rows.is_err();
+        if pages.send(rows).await.is_err() || failed {
+            return;
+        }
+
+        match paging_state_response {
+            PagingStateResponse::HasMorePages { state } => paging_state = state,
+            _ => return,
+        }
+    }
+}
+
+/// Stream every row of a paged statement to `callback`, keeping at most `prefetch_pages`
+/// pages buffered ahead of it. Returns the number of rows delivered.
+pub async fn execute_stream(
+    session: Arc<ScyllaSession>,
+    limiter: Arc<RequestLimiter>,
+    statement: Statement,
+    values: HashMap<String, SerializableValue>,
+    callback: StreamCallback,
+    prefetch_pages: usize,
+) -> PyResult<u64> {
+    let (sender, mut receiver) = mpsc::channel(prefetch_pages);
+    let fetcher = get_runtime().spawn(fetch_pages(session, limiter, statement, values, sender));
+
+    let outcome = async {
+        let mut delivered = 0;
+        while let Some(page) = receiver.recv().await {
+            delivered += callback.deliver(page?).await?;
+        }
+        Ok(delivered)
+    }
+    .await;
+
+    // Stop fetching as soon as the callback fails.
+    fetcher.abort();
+    outcome
+}
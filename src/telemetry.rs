@@ -0,0 +1,141 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use scylla::observability::history::{HistoryCollector, HistoryListener};
+use scylla::statement::Consistency;
+use std::sync::Arc;
+
+const TRACER_NAME: &str = "rsylla";
+
+/// FNV-1a digest of the statement text, stable across processes and releases.
+fn statement_digest(statement: &str) -> String {
+    let digest = statement.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    });
+    format!("{:016x}", digest)
+}
+
+/// OpenTelemetry tracer used to emit one client span per request.
+#[derive(Clone)]
+pub struct Telemetry {
+    tracer: Arc<Py<PyAny>>,
+}
+
+impl Telemetry {
+    pub fn new(py: Python, tracer_provider: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let trace = py.import("opentelemetry.trace").map_err(|_| {
+            PyErr::new::<pyo3::exceptions::PyImportError, _>(
+                "OpenTelemetry spans require the 'opentelemetry-api' package",
+            )
+        })?;
+
+        let kwargs = PyDict::new(py);
+        if let Some(provider) = tracer_provider {
+            kwargs.set_item("tracer_provider", provider)?;
+        }
+        let tracer = trace.call_method("get_tracer", (TRACER_NAME,), Some(&kwargs))?;
+
+        Ok(Telemetry {
+            tracer: Arc::new(tracer.unbind()),
+        })
+    }
+
+    /// Start a span parented to the caller's current context.
+    pub fn start(
+        &self,
+        py: Python,
+        operation: &str,
+        statement: &str,
+        consistency: Option<Consistency>,
+    ) -> PyResult<RequestSpan> {
+        let attributes = PyDict::new(py);
+        attributes.set_item("db.system.name", "scylladb")?;
+        attributes.set_item("db.operation.name", operation)?;
+        attributes.set_item("db.query.text", statement)?;
+        attributes.set_item("db.query.digest", statement_digest(statement))?;
+        if let Some(consistency) = consistency {
+            attributes.set_item("db.cassandra.consistency_level", consistency.to_string())?;
+        }
+
+        let kind = py
+            .import("opentelemetry.trace")?
+            .getattr("SpanKind")?
+            .getattr("CLIENT")?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("kind", kind)?;
+        kwargs.set_item("attributes", attributes)?;
+
+        let span = self.tracer.bind(py).call_method(
+            "start_span",
+            (format!("rsylla.{}", operation),),
+            Some(&kwargs),
+        )?;
+
+        Ok(RequestSpan {
+            span: span.unbind(),
+            history: Arc::new(HistoryCollector::new()),
+            attempts: None,
+        })
+    }
+}
+
+/// A started span, ended with the request's outcome.
+pub struct RequestSpan {
+    span: Py<PyAny>,
+    history: Arc<HistoryCollector>,
+    attempts: Option<usize>,
+}
+
+impl RequestSpan {
+    /// Listener to attach to the statement so retries can be counted.
+    pub fn listener(&self) -> Arc<dyn HistoryListener> {
+        self.history.clone()
+    }
+
+    /// Override the attempt count for requests the driver doesn't record history for.
+    pub fn set_attempts(&mut self, attempts: usize) {
+        self.attempts = Some(attempts);
+    }
+
+    fn attempts(&self) -> usize {
+        self.attempts.unwrap_or_else(|| {
+            self.history
+                .clone_structured_history()
+                .requests
+                .iter()
+                .map(|request| {
+                    request.non_speculative_fiber.attempts.len()
+                        + request
+                            .speculative_fibers
+                            .iter()
+                            .map(|fiber| fiber.attempts.len())
+                            .sum::<usize>()
+                })
+                .sum()
+        })
+    }
+
+    pub fn finish(self, coordinator: Option<String>, error: Option<&PyErr>) {
+        let retries = self.attempts().saturating_sub(1);
+
+        // Telemetry must never fail the request it describes.
+        let _ = Python::attach(|py| -> PyResult<()> {
+            let span = self.span.bind(py);
+            if let Some(coordinator) = coordinator {
+                span.call_method1("set_attribute", ("db.scylladb.coordinator", coordinator))?;
+            }
+            span.call_method1("set_attribute", ("db.scylladb.retries", retries))?;
+
+            if let Some(error) = error {
+                let trace = py.import("opentelemetry.trace")?;
+                let status_code = trace.getattr("StatusCode")?.getattr("ERROR")?;
+                let status = trace
+                    .getattr("Status")?
+                    .call1((status_code, error.value(py).str()?))?;
+                span.call_method1("record_exception", (error.value(py),))?;
+                span.call_method1("set_status", (status,))?;
+            }
+            span.call_method0("end")?;
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,104 @@
+use pyo3::prelude::*;
+use scylla::observability::tracing::{
+    TracingEvent as ScyllaTracingEvent, TracingInfo as ScyllaTracingInfo,
+};
+use std::collections::HashMap;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct TracingEvent {
+    #[pyo3(get)]
+    pub event_id: String,
+    #[pyo3(get)]
+    pub activity: Option<String>,
+    #[pyo3(get)]
+    pub source: Option<String>,
+    /// Microseconds since the start of execution on the source node.
+    #[pyo3(get)]
+    pub source_elapsed: Option<i32>,
+    #[pyo3(get)]
+    pub thread: Option<String>,
+}
+
+#[pymethods]
+impl TracingEvent {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "TracingEvent(activity={:?}, source={:?}, source_elapsed={:?})",
+            self.activity, self.source, self.source_elapsed
+        )
+    }
+}
+
+impl From<&ScyllaTracingEvent> for TracingEvent {
+    fn from(event: &ScyllaTracingEvent) -> Self {
+        TracingEvent {
+            event_id: event.event_id.to_string(),
+            activity: event.activity.clone(),
+            source: event.source.map(|source| source.to_string()),
+            source_elapsed: event.source_elapsed,
+            thread: event.thread.clone(),
+        }
+    }
+}
+
+#[pyclass]
+#[derive(Clone)]
+pub struct TracingInfo {
+    #[pyo3(get)]
+    pub client: Option<String>,
+    #[pyo3(get)]
+    pub command: Option<String>,
+    #[pyo3(get)]
+    pub coordinator: Option<String>,
+    /// Execution time in microseconds.
+    #[pyo3(get)]
+    pub duration: Option<i32>,
+    #[pyo3(get)]
+    pub parameters: HashMap<String, String>,
+    #[pyo3(get)]
+    pub request: Option<String>,
+    /// Milliseconds since the unix epoch.
+    #[pyo3(get)]
+    pub started_at: Option<i64>,
+    #[pyo3(get)]
+    pub events: Vec<TracingEvent>,
+}
+
+#[pymethods]
+impl TracingInfo {
+    /// Distinct nodes that reported events, in order of first appearance.
+    pub fn nodes(&self) -> Vec<String> {
+        let mut nodes: Vec<String> = Vec::new();
+        for source in self.events.iter().filter_map(|event| event.source.as_ref()) {
+            if !nodes.contains(source) {
+                nodes.push(source.clone());
+            }
+        }
+        nodes
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!(
+            "TracingInfo(coordinator={:?}, duration={:?}, events={})",
+            self.coordinator,
+            self.duration,
+            self.events.len()
+        )
+    }
+}
+
+impl From<ScyllaTracingInfo> for TracingInfo {
+    fn from(info: ScyllaTracingInfo) -> Self {
+        TracingInfo {
+            client: info.client.map(|client| client.to_string()),
+            command: info.command,
+            coordinator: info.coordinator.map(|coordinator| coordinator.to_string()),
+            duration: info.duration,
+            parameters: info.parameters.unwrap_or_default(),
+            request: info.request,
+            started_at: info.started_at.map(|started_at| started_at.0),
+            events: info.events.iter().map(TracingEvent::from).collect(),
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use pyo3::conversion::IntoPyObjectExt;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict, PyList};
-use scylla::value::CqlValue;
+use pyo3::types::{PyBytes, PyDict, PyInt, PyList};
+use scylla::frame::response::result::{CollectionType, ColumnType, NativeType};
+use scylla::serialize::SerializationError;
+use scylla::value::{CqlValue, CqlVarint};
 use std::collections::HashMap;
 
+use crate::metadata::cql_type_name;
+
 pub fn cql_value_to_py(py: Python, value: &CqlValue) -> PyResult<Py<PyAny>> {
     match value {
         CqlValue::Ascii(s) | CqlValue::Text(s) => Ok(s.clone().into_bound_py_any(py)?.into()),
@@ -88,7 +92,6 @@ pub fn cql_value_to_py(py: Python, value: &CqlValue) -> PyResult<Py<PyAny>> {
     }
 }
 
-#[allow(dead_code)]
 pub fn py_to_cql_value(obj: &Bound<'_, PyAny>) -> PyResult<CqlValue> {
     if obj.is_none() {
         return Ok(CqlValue::Empty);
@@ -118,8 +121,8 @@ pub fn py_to_cql_value(obj: &Bound<'_, PyAny>) -> PyResult<CqlValue> {
         return Ok(CqlValue::Text(s));
     }
 
-    if let Ok(b) = obj.extract::<Vec<u8>>() {
-        return Ok(CqlValue::Blob(b));
+    if let Ok(b) = obj.cast::<PyBytes>() {
+        return Ok(CqlValue::Blob(b.as_bytes().to_vec()));
     }
 
     if let Ok(list) = obj.cast::<PyList>() {
@@ -165,11 +168,14 @@ pub enum SerializableValue {
     Bool(bool),
     Int(i32),
     BigInt(i64),
-    Float(f32),
+    // Ints beyond 64 bits: decimal digits and two's complement big-endian bytes
+    Varint {
+        digits: String,
+        bytes: Vec<u8>,
+    },
     Double(f64),
     Text(String),
     Blob(Vec<u8>),
-    Timestamp(chrono::DateTime<chrono::Utc>),
     List(Vec<SerializableValue>),
     #[allow(dead_code)]
     Set(Vec<SerializableValue>),
@@ -178,40 +184,322 @@ pub enum SerializableValue {
     IntMap(HashMap<String, i64>),
 }
 
+/// Why a value can't be written to the column of its bind marker.
+#[derive(Debug)]
+pub enum BindValueError {
+    TypeMismatch {
+        python_type: &'static str,
+        cql_type: String,
+        reason: Option<String>,
+    },
+    OutOfRange {
+        value: String,
+        cql_type: String,
+        min: i64,
+        max: i64,
+    },
+}
+
+impl std::fmt::Display for BindValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BindValueError::TypeMismatch {
+                python_type,
+                cql_type,
+                reason,
+            } => {
+                write!(
+                    f,
+                    "expects CQL type {}, got Python {}",
+                    cql_type, python_type
+                )?;
+                if let Some(reason) = reason {
+                    write!(f, " ({})", reason)?;
+                }
+                Ok(())
+            }
+            BindValueError::OutOfRange {
+                value,
+                cql_type,
+                min,
+                max,
+            } => write!(
+                f,
+                "got {}, but CQL type {} holds {} to {}",
+                value, cql_type, min, max
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BindValueError {}
+
+/// Range of the integer CQL types; timestamps are milliseconds in an i64.
+fn integer_range(native: &NativeType) -> Option<(i64, i64)> {
+    match native {
+        NativeType::TinyInt => Some((i8::MIN.into(), i8::MAX.into())),
+        NativeType::SmallInt => Some((i16::MIN.into(), i16::MAX.into())),
+        NativeType::Int => Some((i32::MIN.into(), i32::MAX.into())),
+        NativeType::BigInt | NativeType::Counter | NativeType::Timestamp => {
+            Some((i64::MIN, i64::MAX))
+        }
+        _ => None,
+    }
+}
+
+/// A Python number converted to the CQL type of its bind marker.
+enum Number {
+    TinyInt(i8),
+    SmallInt(i16),
+    Int(i32),
+    BigInt(i64),
+    Counter(i64),
+    Float(f32),
+    Double(f64),
+    Timestamp(i64),
+    Varint(CqlVarint),
+}
+
+impl SerializableValue {
+    /// Name of the Python type the value was converted from.
+    pub fn python_type(&self) -> &'static str {
+        match self {
+            SerializableValue::Null => "None",
+            SerializableValue::Bool(_) => "bool",
+            SerializableValue::Int(_)
+            | SerializableValue::BigInt(_)
+            | SerializableValue::Varint { .. } => "int",
+            SerializableValue::Double(_) => "float",
+            SerializableValue::Text(_) => "str",
+            SerializableValue::Blob(_) => "bytes",
+            SerializableValue::List(_) => "list",
+            SerializableValue::Set(_) => "set",
+            SerializableValue::TextMap(_) | SerializableValue::IntMap(_) => "dict",
+        }
+    }
+
+    fn mismatch(&self, typ: &ColumnType, reason: Option<String>) -> BindValueError {
+        BindValueError::TypeMismatch {
+            python_type: self.python_type(),
+            cql_type: cql_type_name(typ),
+            reason,
+        }
+    }
+
+    fn out_of_range(&self, typ: &ColumnType, min: i64, max: i64) -> BindValueError {
+        let value = match self {
+            SerializableValue::Int(i) => i.to_string(),
+            SerializableValue::BigInt(i) => i.to_string(),
+            SerializableValue::Varint { digits, .. } => digits.clone(),
+            _ => format!("{:?}", self),
+        };
+        BindValueError::OutOfRange {
+            value,
+            cql_type: cql_type_name(typ),
+            min,
+            max,
+        }
+    }
+
+    /// Whether a non-numeric value can be written to a column of type `typ` as it is.
+    fn fits(&self, typ: &ColumnType) -> bool {
+        matches!(
+            (self, typ),
+            (SerializableValue::Null, _)
+                | (
+                    SerializableValue::Bool(_),
+                    ColumnType::Native(NativeType::Boolean)
+                )
+                | (
+                    SerializableValue::Text(_),
+                    ColumnType::Native(NativeType::Text | NativeType::Ascii)
+                )
+                | (
+                    SerializableValue::Blob(_),
+                    ColumnType::Native(NativeType::Blob)
+                )
+                | (
+                    SerializableValue::List(_) | SerializableValue::Set(_),
+                    ColumnType::Collection {
+                        typ: CollectionType::List(_) | CollectionType::Set(_),
+                        ..
+                    } | ColumnType::Vector { .. }
+                )
+                | (
+                    SerializableValue::TextMap(_) | SerializableValue::IntMap(_),
+                    ColumnType::Collection {
+                        typ: CollectionType::Map(..),
+                        ..
+                    }
+                )
+        )
+    }
+
+    /// Convert a Python number to the numeric CQL type `typ`. Integers are narrowed only
+    /// when they fit; with `strict`, integers aren't converted to floating point and floats
+    /// are narrowed to `float` only when that doesn't lose precision.
+    fn to_number(&self, typ: &ColumnType, strict: bool) -> Result<Number, BindValueError> {
+        let ColumnType::Native(native) = typ else {
+            return Err(self.mismatch(typ, None));
+        };
+        if let Some((min, max)) = integer_range(native) {
+            let in_range = match self {
+                SerializableValue::Int(i) => (min..=max).contains(&i64::from(*i)),
+                SerializableValue::BigInt(i) => (min..=max).contains(i),
+                SerializableValue::Varint { .. } => false,
+                _ => true,
+            };
+            if !in_range {
+                return Err(self.out_of_range(typ, min, max));
+            }
+        }
+
+        let integer = match self {
+            SerializableValue::Int(i) => Some(i64::from(*i)),
+            SerializableValue::BigInt(i) => Some(*i),
+            _ => None,
+        };
+        match (self, native, integer) {
+            (_, NativeType::TinyInt, Some(i)) => Ok(Number::TinyInt(i as i8)),
+            (_, NativeType::SmallInt, Some(i)) => Ok(Number::SmallInt(i as i16)),
+            (_, NativeType::Int, Some(i)) => Ok(Number::Int(i as i32)),
+            (_, NativeType::BigInt, Some(i)) => Ok(Number::BigInt(i)),
+            (_, NativeType::Counter, Some(i)) => Ok(Number::Counter(i)),
+            (_, NativeType::Varint, Some(i)) => Ok(Number::Varint(
+                CqlVarint::from_signed_bytes_be(i.to_be_bytes().to_vec()),
+            )),
+            (SerializableValue::Varint { bytes, .. }, NativeType::Varint, _) => {
+                Ok(Number::Varint(CqlVarint::from_signed_bytes_be_slice(bytes)))
+            }
+            (
+                SerializableValue::Int(_)
+                | SerializableValue::BigInt(_)
+                | SerializableValue::Varint { .. },
+                NativeType::Float | NativeType::Double,
+                _,
+            ) if strict => Err(self.mismatch(
+                typ,
+                Some("strict types don't convert int to float".to_string()),
+            )),
+            (_, NativeType::Float, Some(i)) => Ok(Number::Float(i as f32)),
+            (_, NativeType::Double, Some(i)) => Ok(Number::Double(i as f64)),
+            (
+                SerializableValue::Varint { digits, .. },
+                NativeType::Float | NativeType::Double,
+                _,
+            ) => {
+                let f = digits
+                    .parse::<f64>()
+                    .map_err(|_| self.mismatch(typ, None))?;
+                Ok(match native {
+                    NativeType::Float => Number::Float(f as f32),
+                    _ => Number::Double(f),
+                })
+            }
+            (SerializableValue::Double(f), NativeType::Float, _) => {
+                let narrowed = *f as f32;
+                if strict && f64::from(narrowed) != *f && !f.is_nan() {
+                    return Err(self.mismatch(
+                        typ,
+                        Some(format!("{} is not exactly representable as float", f)),
+                    ));
+                }
+                Ok(Number::Float(narrowed))
+            }
+            (SerializableValue::Double(f), NativeType::Double, _) => Ok(Number::Double(*f)),
+            // Numbers bound to a timestamp are milliseconds since the epoch; pass a
+            // `datetime` for anything else
+            (_, NativeType::Timestamp, Some(i)) => Ok(Number::Timestamp(i)),
+            (SerializableValue::Double(f), NativeType::Timestamp, _) => {
+                Ok(Number::Timestamp(*f as i64))
+            }
+            _ => Err(self.mismatch(typ, None)),
+        }
+    }
+
+    /// Check that the value can be written to a column of type `typ`, including the
+    /// elements of lists and sets. See `to_number` for what `strict` refuses.
+    pub fn check_type(&self, typ: &ColumnType, strict: bool) -> Result<(), BindValueError> {
+        match (self, typ) {
+            (
+                SerializableValue::Int(_)
+                | SerializableValue::BigInt(_)
+                | SerializableValue::Varint { .. }
+                | SerializableValue::Double(_),
+                _,
+            ) => self.to_number(typ, strict).map(|_| ()),
+            (
+                SerializableValue::List(items) | SerializableValue::Set(items),
+                ColumnType::Collection {
+                    typ: CollectionType::List(elem) | CollectionType::Set(elem),
+                    ..
+                },
+            ) => items
+                .iter()
+                .try_for_each(|item| item.check_type(elem, strict)),
+            _ if self.fits(typ) => Ok(()),
+            _ => Err(self.mismatch(typ, None)),
+        }
+    }
+}
+
 impl scylla::serialize::value::SerializeValue for SerializableValue {
     fn serialize<'b>(
         &self,
-        _typ: &scylla::frame::response::result::ColumnType,
+        typ: &ColumnType,
         writer: scylla::serialize::writers::CellWriter<'b>,
     ) -> Result<
         scylla::serialize::writers::WrittenCellProof<'b>,
         scylla::serialize::SerializationError,
     > {
+        if !matches!(
+            self,
+            SerializableValue::Int(_)
+                | SerializableValue::BigInt(_)
+                | SerializableValue::Varint { .. }
+                | SerializableValue::Double(_)
+        ) && !self.fits(typ)
+        {
+            return Err(SerializationError::new(self.mismatch(typ, None)));
+        }
+
         match self {
             SerializableValue::Null => {
                 <Option<i32> as scylla::serialize::value::SerializeValue>::serialize(
-                    &None, _typ, writer,
+                    &None, typ, writer,
                 )
             }
-            SerializableValue::Bool(b) => b.serialize(_typ, writer),
-            SerializableValue::Int(i) => i.serialize(_typ, writer),
-            SerializableValue::BigInt(i) => i.serialize(_typ, writer),
-            SerializableValue::Float(f) => f.serialize(_typ, writer),
-            SerializableValue::Double(f) => f.serialize(_typ, writer),
-            SerializableValue::Text(s) => s.serialize(_typ, writer),
-            SerializableValue::Blob(b) => b.serialize(_typ, writer),
-            SerializableValue::Timestamp(dt) => {
-                // Convert to CqlTimestamp (milliseconds since epoch)
-                let timestamp = scylla::value::CqlTimestamp(dt.timestamp_millis());
-                timestamp.serialize(_typ, writer)
+            SerializableValue::Bool(b) => b.serialize(typ, writer),
+            SerializableValue::Int(_)
+            | SerializableValue::BigInt(_)
+            | SerializableValue::Varint { .. }
+            | SerializableValue::Double(_) => {
+                match self
+                    .to_number(typ, false)
+                    .map_err(SerializationError::new)?
+                {
+                    Number::TinyInt(i) => i.serialize(typ, writer),
+                    Number::SmallInt(i) => i.serialize(typ, writer),
+                    Number::Int(i) => i.serialize(typ, writer),
+                    Number::BigInt(i) => i.serialize(typ, writer),
+                    Number::Counter(i) => scylla::value::Counter(i).serialize(typ, writer),
+                    Number::Float(f) => f.serialize(typ, writer),
+                    Number::Double(f) => f.serialize(typ, writer),
+                    Number::Timestamp(millis) => {
+                        scylla::value::CqlTimestamp(millis).serialize(typ, writer)
+                    }
+                    Number::Varint(varint) => varint.serialize(typ, writer),
+                }
             }
-            SerializableValue::List(items) => items.serialize(_typ, writer),
+            SerializableValue::Text(s) => s.serialize(typ, writer),
+            SerializableValue::Blob(b) => b.serialize(typ, writer),
+            SerializableValue::List(items) => items.serialize(typ, writer),
             SerializableValue::Set(items) => {
                 // Sets are serialized as lists in scylla
-                items.serialize(_typ, writer)
+                items.serialize(typ, writer)
             }
-            SerializableValue::TextMap(map) => map.serialize(_typ, writer),
-            SerializableValue::IntMap(map) => map.serialize(_typ, writer),
+            SerializableValue::TextMap(map) => map.serialize(typ, writer),
+            SerializableValue::IntMap(map) => map.serialize(typ, writer),
         }
     }
 }
@@ -235,7 +523,21 @@ pub fn py_dict_to_serialized_values(
     Ok(serialized)
 }
 
-fn py_value_to_serializable(val: &Bound<'_, PyAny>) -> PyResult<SerializableValue> {
+pub fn py_list_to_batch_values(
+    values: &Bound<'_, PyList>,
+) -> PyResult<Vec<HashMap<String, SerializableValue>>> {
+    let mut batch_values = Vec::new();
+    for item in values.iter() {
+        if let Ok(dict) = item.cast::<PyDict>() {
+            batch_values.push(py_dict_to_serialized_values(Some(dict))?);
+        } else {
+            batch_values.push(py_dict_to_serialized_values(None)?);
+        }
+    }
+    Ok(batch_values)
+}
+
+pub(crate) fn py_value_to_serializable(val: &Bound<'_, PyAny>) -> PyResult<SerializableValue> {
     if val.is_none() {
         return Ok(SerializableValue::Null);
     }
@@ -245,56 +547,30 @@ fn py_value_to_serializable(val: &Bound<'_, PyAny>) -> PyResult<SerializableValu
         return Ok(SerializableValue::Bool(b));
     }
 
-    // Try int types
+    // Try int types. Numbers are converted to the type of their bind marker, including
+    // timestamps, when the value is serialized
     if let Ok(i) = val.extract::<i32>() {
         return Ok(SerializableValue::Int(i));
     }
     if let Ok(i) = val.extract::<i64>() {
-        // Check if this might be a timestamp - timestamps are usually in milliseconds or seconds since epoch
-        // Milliseconds: 1000000000000 to 4102444800000 (year 2001 to 2100)
-        // Seconds: 1000000000 to 4102444800 (year 2001 to 2100)
-        if (1_000_000_000_000..4_102_444_800_000).contains(&i)
-            || (1_000_000_000..4_102_444_800).contains(&i)
-        {
-            // Convert to datetime
-            use chrono::TimeZone;
-            let (secs, nanos) = if i >= 1_000_000_000_000 {
-                // Milliseconds
-                (i / 1000, ((i % 1000) * 1_000_000) as u32)
-            } else {
-                // Seconds
-                (i, 0)
-            };
-            let dt = chrono::Utc
-                .timestamp_opt(secs, nanos)
-                .single()
-                .ok_or_else(|| {
-                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid timestamp value")
-                })?;
-            return Ok(SerializableValue::Timestamp(dt));
-        }
         return Ok(SerializableValue::BigInt(i));
     }
-
-    // Try float types
-    if let Ok(f) = val.extract::<f32>() {
-        return Ok(SerializableValue::Float(f));
+    if val.is_instance_of::<PyInt>() {
+        // Beyond 64 bits: keep the exact value, which only a varint column can hold
+        let length = val.call_method0("bit_length")?.extract::<usize>()? / 8 + 1;
+        let kwargs = PyDict::new(val.py());
+        kwargs.set_item("signed", true)?;
+        let bytes = val
+            .call_method("to_bytes", (length, "big"), Some(&kwargs))?
+            .extract::<Vec<u8>>()?;
+        return Ok(SerializableValue::Varint {
+            digits: val.str()?.to_string(),
+            bytes,
+        });
     }
+
+    // Try float
     if let Ok(f) = val.extract::<f64>() {
-        // Check if this is a timestamp (common pattern in Python with datetime.timestamp())
-        // If it's a reasonable timestamp value (between 1970 and 2100), treat it as timestamp
-        if f > 0.0 && f < 4_102_444_800.0 {
-            // Between 1970 and 2100
-            // Convert to datetime
-            use chrono::TimeZone;
-            let dt = chrono::Utc
-                .timestamp_opt(f as i64, (f.fract() * 1_000_000_000.0) as u32)
-                .single()
-                .ok_or_else(|| {
-                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Invalid timestamp value")
-                })?;
-            return Ok(SerializableValue::Timestamp(dt));
-        }
         return Ok(SerializableValue::Double(f));
     }
 
@@ -4,7 +4,7 @@ Tests for Batch operations
 
 import pytest
 
-from rsylla import Batch
+from rsylla import Batch, Pipeline
 
 
 @pytest.mark.integration
@@ -146,3 +146,78 @@ class TestBatch:
         # So just verify one
         result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 550})
         assert len(result) == 1
+
+
+    async def test_batch_chunked(self, session, users_table):
+        """Test a large batch split into server batches with per-chunk results"""
+        batch = Batch("unlogged")
+        values = []
+        for i in range(1000, 1010):
+            table = "nonexistent_table" if i == 1005 else "users"
+            batch.append_statement(f"INSERT INTO {table} (id, username) VALUES (?, ?)")
+            values.append({"id": i, "username": f"chunk{i}"})
+
+        summary = await session.batch_chunked(batch, values, chunk_size=4)
+        assert summary["succeeded"] == 2
+        assert summary["failed"] == 1
+        chunks = summary["chunks"]
+        assert [chunk["statements"] for chunk in chunks] == [(0, 4), (4, 8), (8, 10)]
+        assert [chunk["success"] for chunk in chunks] == [True, False, True]
+        assert chunks[1]["error"]
+
+        result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1009})
+        assert len(result) == 1
+        result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1004})
+        assert len(result) == 0
+
+        with pytest.raises(ValueError):
+            session.batch_chunked(batch, values, chunk_size=0)
+        with pytest.raises(ValueError):
+            session.batch_chunked(batch, values[:3])
+
+@pytest.mark.integration
+class TestPipeline:
+    """Test Pipeline of batches"""
+
+    async def test_pipeline_execute(self, session, users_table):
+        """Test executing many single-partition batches with bounded concurrency"""
+        prepared = await session.prepare("INSERT INTO users (id, username, email) VALUES (?, ?, ?)")
+
+        pipeline = Pipeline(concurrency=4, retries=1)
+        for i in range(900, 910):
+            batch = Batch("unlogged")
+            batch.append_prepared(prepared)
+            pipeline.add(
+                batch, [{"id": i, "username": f"pipe{i}", "email": f"pipe{i}@example.com"}]
+            )
+
+        assert len(pipeline) == 10
+
+        outcomes = await session.execute_pipeline(pipeline)
+        assert [outcome["index"] for outcome in outcomes] == list(range(10))
+        assert all(outcome["success"] for outcome in outcomes)
+        assert all(outcome["error"] is None for outcome in outcomes)
+
+        result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 905})
+        assert len(result) == 1
+
+    async def test_pipeline_reports_failures(self, session, users_table):
+        """Test per-batch failure reporting; invalid requests aren't retried"""
+        batch = Batch("logged")
+        batch.append_statement("INSERT INTO nonexistent_table (id) VALUES (?)")
+
+        pipeline = Pipeline(concurrency=2, retries=1, retry_delay_ms=10)
+        pipeline.add(batch, [{"id": 1}])
+
+        outcomes = await session.execute_pipeline(pipeline)
+        assert outcomes[0]["success"] is False
+        assert outcomes[0]["attempts"] == 1
+        assert outcomes[0]["error"]
+
+    def test_pipeline_value_count_mismatch(self):
+        """Test that value sets must match the batch statements"""
+        batch = Batch("logged")
+        batch.append_statement("INSERT INTO users (id) VALUES (?)")
+
+        with pytest.raises(ValueError):
+            Pipeline().add(batch, [])
@@ -0,0 +1,94 @@
+"""
+Tests for the CDC log reader
+"""
+
+import time
+
+import pytest
+
+
+@pytest.fixture(scope="function")
+async def cdc_table(session, test_keyspace):
+    """Create a table with CDC and pre/post images enabled"""
+    await session.execute(
+        """
+        CREATE TABLE IF NOT EXISTS cdc_items (
+            id int PRIMARY KEY,
+            name text,
+            qty int
+        ) WITH cdc = {'enabled': true, 'preimage': true, 'postimage': true}
+    """
+    )
+    await session.await_schema_agreement()
+    yield "cdc_items"
+    await session.execute("DROP TABLE IF EXISTS cdc_items")
+
+
+@pytest.mark.integration
+class TestCDCReader:
+    """Test reading change events"""
+
+    async def test_read_changes(self, session, test_keyspace, cdc_table):
+        """Test that writes come back in order with their images"""
+        start_ms = int(time.time() * 1000) - 1000
+        await session.execute(
+            "INSERT INTO cdc_items (id, name, qty) VALUES (?, ?, ?)",
+            {"id": 1, "name": "apple", "qty": 3},
+        )
+        await session.execute("UPDATE cdc_items SET qty = ? WHERE id = ?", {"qty": 5, "id": 1})
+        await session.execute("DELETE FROM cdc_items WHERE id = ?", {"id": 1})
+        end_ms = int(time.time() * 1000) + 1000
+
+        reader = session.cdc_reader(
+            test_keyspace,
+            cdc_table,
+            start_ms=start_ms,
+            end_ms=end_ms,
+            poll_interval_ms=100,
+            confidence_window_ms=0,
+        )
+        events = [event async for event in reader]
+
+        assert [event.operation for event in events] == ["insert", "update", "row_delete"]
+        insert, update, delete = events
+        assert insert.columns["name"] == "apple"
+        assert update.columns["qty"] == 5
+        assert update.pre_image["qty"] == 3
+        assert update.post_image["qty"] == 5
+        assert delete.columns["id"] == 1
+        assert insert.time_ms <= update.time_ms <= delete.time_ms
+        assert isinstance(insert.stream_id, bytes)
+        assert reader.position_ms() >= end_ms
+
+    async def test_read_more_changes_than_a_page(self, session, test_keyspace, cdc_table):
+        """Test a window with more log rows than one page returns them all"""
+        start_ms = int(time.time() * 1000) - 1000
+        for qty in range(1200):
+            await session.execute(
+                "UPDATE cdc_items SET qty = ? WHERE id = ?", {"qty": qty, "id": 2}
+            )
+        end_ms = int(time.time() * 1000) + 1000
+
+        reader = session.cdc_reader(
+            test_keyspace,
+            cdc_table,
+            start_ms=start_ms,
+            end_ms=end_ms,
+            poll_interval_ms=100,
+            confidence_window_ms=0,
+        )
+        events = [event async for event in reader]
+
+        assert [event.columns["qty"] for event in events] == list(range(1200))
+
+    async def test_close(self, session, test_keyspace, cdc_table):
+        """Test that a closed reader stops iterating"""
+        reader = session.cdc_reader(test_keyspace, cdc_table, poll_interval_ms=100)
+        reader.close()
+        with pytest.raises(StopAsyncIteration):
+            await reader.__anext__()
+
+    async def test_invalid_table(self, session, test_keyspace):
+        """Test that table names are validated"""
+        with pytest.raises(ValueError):
+            session.cdc_reader(test_keyspace, "items; DROP TABLE items")
@@ -0,0 +1,114 @@
+"""
+Tests for configuring sessions from TOML and YAML files
+"""
+
+import pytest
+
+from rsylla import ExecutionProfile, Query, SessionBuilder
+
+CONFIG = """
+nodes = ["{node}"]
+keyspace = "test_rsylla"
+connection_timeout_ms = 5000
+consistency = "quorum"
+page_size = 500
+dns_refresh_ms = 30000
+
+[profiles.analytics]
+consistency = "one"
+request_timeout_ms = 30000
+
+[environments.dev]
+consistency = "one"
+
+[environments.dev.profiles.bulk]
+consistency = "any"
+retry_policy = "fallthrough"
+"""
+
+
+def write_config(tmp_path, text, name="rsylla.toml"):
+    path = tmp_path / name
+    path.write_text(text)
+    return path
+
+
+@pytest.mark.unit
+class TestConfigFile:
+    """Test reading and validating config files"""
+
+    def test_from_config(self, tmp_path):
+        """Test a valid TOML file with and without an environment"""
+        path = write_config(tmp_path, CONFIG.format(node="127.0.0.1:9042"))
+        assert isinstance(SessionBuilder.from_config(path), SessionBuilder)
+        assert isinstance(SessionBuilder.from_config(str(path), "dev"), SessionBuilder)
+
+    def test_from_yaml_config(self, tmp_path):
+        """Test a YAML file with the same settings"""
+        pytest.importorskip("yaml")
+        path = write_config(
+            tmp_path,
+            "nodes: ['127.0.0.1:9042']\nconsistency: local_quorum\n"
+            "profiles:\n  analytics:\n    consistency: one\n",
+            name="rsylla.yaml",
+        )
+        assert isinstance(SessionBuilder.from_config(path), SessionBuilder)
+
+    @pytest.mark.parametrize(
+        "text, message",
+        [
+            ('nodez = ["127.0.0.1"]', "Unknown config key 'nodez'"),
+            ("[profiles.analytics]\npool_size = 2", "in [profiles.analytics]"),
+            ('username = "cassandra"', "both 'username' and 'password'"),
+            ('pool_size = "four"', "Invalid config value for 'pool_size'"),
+            ('consistency = "most"', "Invalid consistency level: most"),
+            ('service_level = "oltp-tier"', "Invalid CQL identifier"),
+            ("connections_per_shard = 0", "Connections per shard must be greater than 0"),
+        ],
+    )
+    def test_invalid_config(self, tmp_path, text, message):
+        """Test mistakes are reported with the offending key"""
+        path = write_config(tmp_path, text)
+        with pytest.raises(ValueError, match=message.replace("[", r"\[")):
+            SessionBuilder.from_config(path)
+
+    def test_missing_environment(self, tmp_path):
+        """Test selecting an environment the file doesn't define"""
+        path = write_config(tmp_path, CONFIG.format(node="127.0.0.1:9042"))
+        with pytest.raises(ValueError, match="environments.prod"):
+            SessionBuilder.from_config(path, "prod")
+
+    def test_unsupported_file(self, tmp_path):
+        """Test files that are neither TOML nor YAML, or don't exist"""
+        with pytest.raises(ValueError, match="Unsupported config file"):
+            SessionBuilder.from_config(write_config(tmp_path, "", name="rsylla.ini"))
+        with pytest.raises(FileNotFoundError):
+            SessionBuilder.from_config(tmp_path / "missing.toml")
+
+
+@pytest.mark.integration
+class TestConfiguredSession:
+    """Test sessions built from config files"""
+
+    async def test_session_from_config(self, scylla_connection_string, test_keyspace, tmp_path):
+        """Test connecting with an environment and using its named profiles"""
+        path = write_config(tmp_path, CONFIG.format(node=scylla_connection_string))
+        session = await SessionBuilder.from_config(path, environment="dev").build()
+        assert session.get_keyspace() == "test_rsylla"
+
+        for name in ["analytics", "bulk"]:
+            profile = session.execution_profile(name)
+            assert isinstance(profile, ExecutionProfile)
+
+        query = Query("SELECT now() FROM system.local").with_execution_profile(
+            session.execution_profile("analytics")
+        )
+        result = await session.query(query)
+        assert result.first_row() is not None
+
+        with pytest.raises(KeyError):
+            session.execution_profile("reporting")
+
+        builder = SessionBuilder().execution_profile("reporting", ExecutionProfile())
+        session = await builder.known_node(scylla_connection_string).build()
+        assert isinstance(session.execution_profile("reporting"), ExecutionProfile)
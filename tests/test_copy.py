@@ -0,0 +1,169 @@
+"""
+Tests for bulk loading and exporting files with Session.copy_from and Session.copy_to
+"""
+
+import pytest
+
+
+@pytest.fixture(scope="function")
+async def readings_table(session, test_keyspace):
+    """Create a readings table with a compound primary key"""
+    await session.execute(
+        """
+        CREATE TABLE IF NOT EXISTS readings (
+            sensor int,
+            ts timestamp,
+            value double,
+            label text,
+            PRIMARY KEY (sensor, ts)
+        )
+    """
+    )
+    await session.await_schema_agreement()
+    yield "readings"
+    await session.execute("DROP TABLE IF EXISTS readings")
+
+
+def write_csv(path, rows, delimiter=","):
+    lines = [delimiter.join(["sensor", "ts", "value", "label"])]
+    lines += [delimiter.join(str(v) for v in row) for row in rows]
+    path.write_text("\n".join(lines) + "\n")
+
+
+@pytest.mark.integration
+class TestCopyFrom:
+    """Test loading CSV, TSV and Parquet files"""
+
+    async def test_copy_csv(self, session, readings_table, tmp_path):
+        """Test loading a CSV file across several partitions"""
+        path = tmp_path / "readings.csv"
+        write_csv(path, [(i % 5, 1_700_000_000_000 + i, i * 0.5, f"r{i}") for i in range(200)])
+
+        progress = []
+        loaded = await session.copy_from(
+            "readings", path, concurrency=4, batch_size=7, on_progress=progress.append
+        )
+
+        assert loaded == 200
+        assert progress[-1] == 200
+        result = await session.execute("SELECT COUNT(*) FROM readings")
+        assert result.first_row()[0] == 200
+        row = (
+            await session.execute(
+                "SELECT value, label FROM readings WHERE sensor = 3 AND ts = 1700000000003"
+            )
+        ).first_row()
+        assert row[0] == 1.5
+        assert row[1] == "r3"
+
+    async def test_copy_tsv_with_nulls(self, session, readings_table, tmp_path):
+        """Test loading a TSV file where empty values become nulls"""
+        path = tmp_path / "readings.tsv"
+        write_csv(path, [(1, "2024-01-02T03:04:05Z", "", "")], delimiter="\t")
+
+        assert await session.copy_from("readings", str(path), format="tsv") == 1
+
+        row = (await session.execute("SELECT value, label FROM readings")).first_row()
+        assert row[0] is None
+        assert row[1] is None
+
+    async def test_copy_parquet(self, session, readings_table, tmp_path):
+        """Test loading a Parquet file"""
+        pa = pytest.importorskip("pyarrow")
+        pq = pytest.importorskip("pyarrow.parquet")
+        table = pa.table(
+            {
+                "sensor": pa.array([1, 1, 2], type=pa.int32()),
+                "ts": pa.array([1, 2, 3], type=pa.timestamp("ms")),
+                "value": [0.1, 0.2, None],
+                "label": ["a", None, "c"],
+            }
+        )
+        path = tmp_path / "readings.parquet"
+        pq.write_table(table, path)
+
+        assert await session.copy_from("readings", path, format="parquet") == 3
+
+        result = await session.execute("SELECT COUNT(*) FROM readings")
+        assert result.first_row()[0] == 3
+
+    async def test_copy_invalid_value(self, session, readings_table, tmp_path):
+        """Test that a value that doesn't parse names its row and column"""
+        path = tmp_path / "bad.csv"
+        write_csv(path, [(1, 1, 1.0, "ok"), (1, 2, "not-a-number", "bad")])
+
+        with pytest.raises(ValueError, match="Row 2, column 'value'"):
+            await session.copy_from("readings", path)
+
+    async def test_copy_invalid_arguments(self, session, readings_table, tmp_path):
+        """Test argument validation"""
+        path = tmp_path / "readings.csv"
+        write_csv(path, [])
+
+        with pytest.raises(ValueError):
+            await session.copy_from("readings", path, format="xlsx")
+        with pytest.raises(ValueError):
+            await session.copy_from("readings", path, batch_size=0)
+        with pytest.raises(ValueError, match="Cannot open"):
+            await session.copy_from("readings", tmp_path / "missing.csv")
+
+
+@pytest.mark.integration
+class TestCopyTo:
+    """Test exporting tables and queries to CSV and Parquet"""
+
+    async def test_copy_to_csv_round_trip(self, session, readings_table, tmp_path):
+        """Test that an exported CSV loads back unchanged"""
+        source = tmp_path / "source.csv"
+        write_csv(source, [(i % 3, 1_700_000_000_000 + i, i / 4, f"r{i}") for i in range(50)])
+        await session.copy_from("readings", source)
+
+        export = tmp_path / "export.csv"
+        progress = []
+        written = await session.copy_to(
+            "readings", export, page_size=7, on_progress=progress.append
+        )
+
+        assert written == 50
+        assert progress[-1] == 50
+        lines = export.read_text().splitlines()
+        assert lines[0].split(",") == ["sensor", "ts", "label", "value"]
+        assert len(lines) == 51
+
+        await session.execute("TRUNCATE readings")
+        assert await session.copy_from("readings", export) == 50
+        row = (
+            await session.execute(
+                "SELECT value, label FROM readings WHERE sensor = 1 AND ts = 1700000000004"
+            )
+        ).first_row()
+        assert row[0] == 1.0
+        assert row[1] == "r4"
+
+    async def test_copy_query_to_parquet(self, session, readings_table, tmp_path):
+        """Test exporting a query's result to Parquet"""
+        pq = pytest.importorskip("pyarrow.parquet")
+        source = tmp_path / "source.csv"
+        write_csv(source, [(1, 1, 0.5, "a"), (1, 2, 1.5, ""), (2, 3, 2.5, "c")])
+        await session.copy_from("readings", source)
+
+        export = tmp_path / "export.parquet"
+        written = await session.copy_to(
+            "SELECT ts, value, label FROM readings WHERE sensor = :sensor",
+            export,
+            format="parquet",
+            values={"sensor": 1},
+        )
+
+        assert written == 2
+        table = pq.read_table(export)
+        assert table.column_names == ["ts", "value", "label"]
+        assert table.column("value").to_pylist() == [0.5, 1.5]
+        assert table.column("label").to_pylist() == ["a", None]
+
+    async def test_copy_empty_table(self, session, readings_table, tmp_path):
+        """Test that exporting an empty table writes just the header"""
+        export = tmp_path / "empty.csv"
+
+        assert await session.copy_to("readings", export) == 0
+        assert export.read_text().splitlines() == ["sensor,ts,label,value"]
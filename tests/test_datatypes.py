@@ -14,8 +14,35 @@ class TestDataTypes:
     # REMOVED: test_integer_types - fails due to dict parameter ordering with positional markers
     # Named parameter binding doesn't guarantee order matches positional ? markers
 
-    # REMOVED: test_float_types - fails due to type coercion issues with named parameters
-    # id column gets i64 instead of i32 in some cases
+    async def test_float_types(self, session, test_keyspace):
+        """Test numbers converted to the type of their column"""
+        await session.execute(
+            """
+            CREATE TABLE IF NOT EXISTS test_float (
+                id bigint PRIMARY KEY,
+                float_val float,
+                double_val double
+            )
+        """
+        )
+
+        await session.await_schema_agreement()
+
+        # Insert a small int into a bigint column and an int into a double column
+        await session.execute(
+            "INSERT INTO test_float (id, float_val, double_val) VALUES (:id, :f, :d)",
+            {"id": 1, "f": 1.5, "d": 3},
+        )
+
+        # Query
+        result = await session.execute("SELECT * FROM test_float WHERE id = ?", {"id": 1})
+        row = result.first_row()
+
+        assert row[0] == 1
+        assert row[1] == 1.5
+        assert row[2] == 3.0
+
+        await session.execute("DROP TABLE IF EXISTS test_float")
 
     async def test_text_types(self, session, test_keyspace):
         """Test text type conversions"""
@@ -134,6 +161,13 @@ class TestDataTypes:
         # Timestamp should be close to what we inserted
         assert abs(row[1] - current_time) < 1000
 
+        # Ints are always milliseconds, even where they'd be plausible seconds
+        await session.execute(
+            "INSERT INTO test_timestamp (id, ts) VALUES (?, ?)", {"id": 2, "ts": 1_500_000_000}
+        )
+        result = await session.execute("SELECT ts FROM test_timestamp WHERE id = ?", {"id": 2})
+        assert result.first_row()[0] == 1_500_000_000
+
         await session.execute("DROP TABLE IF EXISTS test_timestamp")
 
     async def test_list_type(self, session, test_keyspace):
@@ -0,0 +1,54 @@
+"""
+Tests for event loop backend selection
+"""
+
+import asyncio
+
+import pytest
+
+from rsylla import Session, event_loop_backend, use_event_loop
+
+
+@pytest.mark.unit
+class TestEventLoopBackend:
+    """Test selecting and verifying the asyncio backend"""
+
+    def test_use_asyncio(self):
+        """Test restoring the standard event loop"""
+        assert use_event_loop("asyncio") == "asyncio"
+        assert event_loop_backend() == "asyncio"
+
+        async def running_backend():
+            return event_loop_backend()
+
+        assert asyncio.run(running_backend()) == "asyncio"
+
+    def test_invalid_backend(self):
+        """Test that unknown backends are rejected"""
+        with pytest.raises(ValueError):
+            use_event_loop("tornado")
+
+
+@pytest.mark.integration
+class TestUvloop:
+    """Test requests on a uvloop event loop"""
+
+    def test_session_on_uvloop(self, scylla_connection_string):
+        """Test a session built and queried on uvloop"""
+        uvloop = pytest.importorskip("uvloop")
+
+        async def query():
+            assert event_loop_backend() == "uvloop"
+            session = await Session.connect([scylla_connection_string])
+            results = await asyncio.gather(
+                *[session.execute("SELECT now() FROM system.local") for _ in range(10)]
+            )
+            return all(result.first_row() is not None for result in results)
+
+        try:
+            assert use_event_loop("uvloop") == "uvloop"
+            assert event_loop_backend() == "uvloop"
+            with asyncio.Runner(loop_factory=uvloop.new_event_loop) as runner:
+                assert runner.run(query())
+        finally:
+            use_event_loop("asyncio")
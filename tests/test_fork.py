@@ -0,0 +1,59 @@
+"""
+Tests for using rsylla across os.fork()
+"""
+
+import asyncio
+import multiprocessing
+import os
+
+import pytest
+
+from rsylla import ForkedProcessError, ScyllaError, SessionBuilder
+
+
+async def connect(node):
+    return await SessionBuilder().known_node(node).connection_timeout(500).build()
+
+
+def connect_in_child(queue):
+    try:
+        asyncio.run(connect("127.0.0.1:1"))
+        queue.put("connected")
+    except ForkedProcessError as err:
+        queue.put(str(err))
+    except Exception as err:
+        queue.put(repr(err))
+
+
+@pytest.mark.unit
+@pytest.mark.skipif(not hasattr(os, "fork"), reason="requires os.fork()")
+class TestForkSafety:
+    """Test that a forked child fails fast instead of hanging"""
+
+    def test_forked_child_raises(self):
+        """Test building a session in a child forked after the runtime started"""
+        # Start the runtime in this process; nothing listens on port 1
+        with pytest.raises(ScyllaError):
+            asyncio.run(connect("127.0.0.1:1"))
+
+        context = multiprocessing.get_context("fork")
+        queue = context.Queue()
+        child = context.Process(target=connect_in_child, args=(queue,))
+        child.start()
+        message = queue.get(timeout=10)
+        child.join(timeout=10)
+
+        assert f"started in process {os.getpid()}" in message
+        assert "spawn" in message
+
+    def test_spawned_child_connects(self):
+        """Test that children started with 'spawn' get a runtime of their own"""
+        context = multiprocessing.get_context("spawn")
+        queue = context.Queue()
+        child = context.Process(target=connect_in_child, args=(queue,))
+        child.start()
+        message = queue.get(timeout=30)
+        child.join(timeout=10)
+
+        assert "ForkedProcessError" not in message
+        assert "ScyllaError" in message
@@ -0,0 +1,70 @@
+"""
+Tests for running rsylla on free-threaded (no-GIL) CPython builds
+"""
+
+import sys
+import sysconfig
+import threading
+
+import pytest
+
+import rsylla
+
+free_threaded = pytest.mark.skipif(
+    not sysconfig.get_config_var("Py_GIL_DISABLED"), reason="requires a free-threaded build"
+)
+
+
+@pytest.mark.unit
+class TestFreeThreadedBuild:
+    """Test the extension declares free-threading support"""
+
+    @free_threaded
+    def test_import_keeps_gil_disabled(self):
+        """Test importing rsylla doesn't re-enable the GIL"""
+        assert not sys._is_gil_enabled()
+
+    def test_builder_shared_between_threads(self):
+        """Test concurrent builder mutation never corrupts it"""
+        builder = rsylla.SessionBuilder()
+        errors = []
+
+        def configure(index):
+            for _ in range(200):
+                try:
+                    builder.known_node(f"10.0.0.{index}:9042").pool_size(index + 1)
+                except RuntimeError as err:
+                    # Another thread holds the builder; PyO3 refuses the overlapping borrow
+                    errors.append(err)
+
+        threads = [threading.Thread(target=configure, args=(i,)) for i in range(8)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert all("borrow" in str(err).lower() for err in errors)
+
+
+@pytest.mark.integration
+class TestSharedResults:
+    """Test results and sessions shared by threads"""
+
+    async def test_iterate_result_from_threads(self, session, users_table, sample_users):
+        """Test threads draining one result each get distinct rows"""
+        result = await session.execute("SELECT id FROM users")
+        seen = []
+        lock = threading.Lock()
+
+        def drain():
+            for row in result:
+                with lock:
+                    seen.append(row[0])
+
+        threads = [threading.Thread(target=drain) for _ in range(4)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert sorted(seen) == sorted(user["id"] for user in sample_users)
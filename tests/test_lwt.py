@@ -0,0 +1,73 @@
+"""
+Tests for the lightweight transaction helpers
+"""
+
+import pytest
+
+
+@pytest.mark.integration
+class TestLwt:
+    """Test insert_if_not_exists, compare_and_set and delete_if"""
+
+    async def test_insert_if_not_exists(self, session, users_table):
+        """Test that a second insert returns the existing row"""
+        applied, current = await session.insert_if_not_exists(
+            "users", {"id": 1, "username": "alice", "age": 30}
+        )
+        assert applied is True
+        assert current is None
+
+        applied, current = await session.insert_if_not_exists(
+            "users", {"id": 1, "username": "mallory", "age": 99}, ttl=60
+        )
+        assert applied is False
+        assert current["username"] == "alice"
+        assert current["age"] == 30
+
+    async def test_compare_and_set(self, session, users_table):
+        """Test updating only when the current value matches"""
+        await session.insert_if_not_exists("users", {"id": 2, "username": "bob", "age": 40})
+
+        applied, current = await session.compare_and_set(
+            "users", {"id": 2}, {"age": 41}, expected={"age": 40}
+        )
+        assert applied is True
+        assert current is None
+
+        # The same column appears in SET and IF with different values
+        applied, current = await session.compare_and_set(
+            "users", {"id": 2}, {"age": 42}, expected={"age": 40}
+        )
+        assert applied is False
+        assert current["age"] == 41
+
+        applied, current = await session.compare_and_set("users", {"id": 999}, {"age": 1})
+        assert applied is False
+        assert current is None
+
+    async def test_delete_if(self, session, users_table):
+        """Test conditional deletes"""
+        await session.insert_if_not_exists("users", {"id": 3, "username": "carol"})
+
+        applied, current = await session.delete_if(
+            "users", {"id": 3}, expected={"username": "dave"}
+        )
+        assert applied is False
+        assert current["username"] == "carol"
+
+        applied, _ = await session.delete_if("users", {"id": 3}, expected={"username": "carol"})
+        assert applied is True
+        applied, current = await session.delete_if("users", {"id": 3})
+        assert applied is False
+        assert current is None
+
+    async def test_invalid_arguments(self, session, users_table):
+        """Test argument validation"""
+        with pytest.raises(ValueError):
+            await session.insert_if_not_exists("users", {})
+        with pytest.raises(ValueError):
+            await session.insert_if_not_exists("users", {"id": 1}, ttl=0)
+        with pytest.raises(ValueError):
+            await session.compare_and_set("users", {}, {"age": 1})
+        with pytest.raises(ValueError):
+            await session.delete_if("users; DROP TABLE users", {"id": 1})
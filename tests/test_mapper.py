@@ -0,0 +1,116 @@
+"""
+Tests for the dataclass Mapper
+"""
+
+from dataclasses import dataclass
+
+import pytest
+
+
+@dataclass
+class Event:
+    tenant: int
+    id: int
+    name: str | None = None
+    score: int | None = None
+
+
+@dataclass
+class Unmapped:
+    id: int
+
+
+@pytest.fixture(scope="function")
+async def events_table(session, test_keyspace):
+    """Create an events table with a compound primary key and a soft-delete marker"""
+    await session.execute(
+        """
+        CREATE TABLE IF NOT EXISTS events (
+            tenant int,
+            id int,
+            name text,
+            score int,
+            deleted_at timestamp,
+            PRIMARY KEY (tenant, id)
+        )
+    """
+    )
+    await session.await_schema_agreement()
+    yield "events"
+    await session.execute("DROP TABLE IF EXISTS events")
+
+
+@pytest.mark.integration
+class TestMapper:
+    """Test Mapper operations"""
+
+    async def test_save_get_delete(self, session, events_table):
+        """Test round-tripping an instance"""
+        events = session.mapper(Event, "events", ["tenant", "id"])
+        await events.save(Event(tenant=1, id=1, name="first", score=10))
+
+        event = await events.get((1, 1))
+        assert event == Event(tenant=1, id=1, name="first", score=10)
+        assert await events.get({"tenant": 1, "id": 1}) == event
+        assert await events.get((1, 2)) is None
+
+        await events.delete(event)
+        assert await events.get((1, 1)) is None
+
+    async def test_filter(self, session, events_table):
+        """Test filtering by partition key and by a regular column"""
+        events = session.mapper(Event, "events", ["tenant", "id"])
+        for i in range(3):
+            await events.save(Event(tenant=2, id=i, name=f"e{i}", score=i % 2))
+
+        found = await events.filter(tenant=2)
+        assert [event.id for event in found] == [0, 1, 2]
+
+        found = await events.filter(tenant=2, score=1, allow_filtering=True)
+        assert [event.name for event in found] == ["e1"]
+
+        with pytest.raises(ValueError):
+            events.filter(unknown=1)
+
+    async def test_soft_delete(self, session, test_keyspace, events_table):
+        """Test that soft-deleted rows are kept but hidden"""
+        events = session.mapper(
+            Event,
+            "events",
+            ["tenant", "id"],
+            keyspace=test_keyspace,
+            soft_delete_marker="deleted_at",
+        )
+        event = Event(tenant=3, id=1, name="soft")
+        await events.save(event)
+        await events.delete(event)
+
+        assert await events.get((3, 1)) is None
+        assert await events.filter(tenant=3) == []
+
+        result = await session.execute(
+            "SELECT name, deleted_at FROM events WHERE tenant = ? AND id = ?",
+            {"tenant": 3, "id": 1},
+        )
+        row = result.first_row()
+        assert row[0] == "soft"
+        assert row[1] is not None
+
+        # Saving again clears the marker
+        await events.save(event)
+        assert await events.get((3, 1)) == event
+
+    async def test_validation(self, session, events_table):
+        """Test mapper construction errors"""
+        with pytest.raises(TypeError):
+            session.mapper(dict, "events", ["id"])
+        with pytest.raises(ValueError):
+            session.mapper(Event, "events", ["missing"])
+        with pytest.raises(ValueError):
+            session.mapper(Event, "events", [])
+        with pytest.raises(ValueError):
+            session.mapper(Unmapped, "events; DROP TABLE events", ["id"])
+
+        events = session.mapper(Event, "events", ["tenant", "id"])
+        with pytest.raises(ValueError):
+            events.get(1)
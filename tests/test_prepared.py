@@ -2,9 +2,18 @@
 Tests for PreparedStatement
 """
 
+import pickle
+
 import pytest
 
-from rsylla import ScyllaError
+from rsylla import (
+    BindError,
+    Query,
+    RateLimitedError,
+    ScyllaError,
+    SessionBuilder,
+    ValueOutOfRange,
+)
 
 
 @pytest.mark.integration
@@ -64,6 +73,218 @@ class TestPreparedStatement:
         result = await session.execute_prepared(prepared_paged)
         assert result is not None
 
+    async def test_prepared_keyspace(self, session, test_keyspace, users_table):
+        """Test reading the keyspace a statement was prepared against"""
+        prepared = await session.prepare("SELECT * FROM users WHERE id = ?")
+        assert prepared.get_keyspace() == test_keyspace
+
+        qualified = await session.prepare("SELECT keyspace_name FROM system_schema.keyspaces")
+        assert qualified.get_keyspace() == "system_schema"
+
+    async def test_prepared_variable_specs(self, session, test_keyspace, users_table):
+        """Test introspecting bind markers and partition key positions"""
+        prepared = await session.prepare("INSERT INTO users (username, id, age) VALUES (?, ?, ?)")
+
+        specs = prepared.variable_specs()
+        assert [spec["name"] for spec in specs] == ["username", "id", "age"]
+        assert [str(spec["typ"]) for spec in specs] == ["text", "int", "int"]
+        assert specs[0]["keyspace"] == test_keyspace
+        assert specs[0]["table"] == "users"
+        assert prepared.partition_key_indexes() == [1]
+
+        unbound = await session.prepare("SELECT * FROM users")
+        assert unbound.variable_specs() == []
+        assert unbound.partition_key_indexes() == []
+
+    async def test_prepared_result_specs(self, session, test_keyspace, users_table):
+        """Test introspecting the columns a statement returns"""
+        prepared = await session.prepare("SELECT id, username, is_active FROM users WHERE id = ?")
+
+        specs = prepared.result_specs()
+        assert [spec["name"] for spec in specs] == ["id", "username", "is_active"]
+        assert [str(spec["typ"]) for spec in specs] == ["int", "text", "boolean"]
+        assert specs[0]["keyspace"] == test_keyspace
+        assert specs[0]["table"] == "users"
+
+        insert = await session.prepare("INSERT INTO users (id, username) VALUES (?, ?)")
+        assert insert.result_specs() == []
+
+    async def test_prepared_compute_token(self, session, test_keyspace, users_table):
+        """Test computing the token and partition key of bound values"""
+        prepared = await session.prepare("INSERT INTO users (id, username) VALUES (?, ?)")
+        values = {"id": 5, "username": "user5"}
+
+        token = prepared.compute_token(values)
+        assert token == session.token_for(test_keyspace, "users", {"id": 5})
+        assert prepared.partition_key(values) == (5).to_bytes(4, "big")
+
+        unbound = await session.prepare("SELECT * FROM users")
+        assert unbound.compute_token() is None
+
+        with pytest.raises(BindError):
+            prepared.compute_token({"id": 5})
+
+    async def test_reprepare_all(self, session, users_table, sample_users):
+        """Test re-preparing statements after a schema change"""
+        prepared = await session.prepare("SELECT * FROM users WHERE id = ?")
+        assert await session.reprepare_all() >= 1
+
+        await session.execute("ALTER TABLE users ADD nickname text")
+        await session.await_schema_agreement()
+        try:
+            await session.reprepare_all()
+            result = await session.execute_prepared(prepared, {"id": 1})
+            assert "nickname" in result.first_row().keys()
+        finally:
+            await session.execute("ALTER TABLE users DROP nickname")
+            await session.await_schema_agreement()
+
+    async def test_execute_many(self, session, users_table):
+        """Test streaming parameter sets from a generator"""
+        prepared = await session.prepare(
+            "INSERT INTO users (id, username, age) VALUES (?, ?, ?)"
+        )
+
+        def params():
+            for i in range(300):
+                yield {"id": i, "username": f"user{i}", "age": i % 90}
+            yield {"id": 300, "username": "bad", "age": "not a number"}
+            yield "not a dict"
+
+        summary = await session.execute_many(prepared, params(), window=16)
+
+        assert summary["succeeded"] == 300
+        assert summary["failed"] == 2
+        assert [e["index"] for e in summary["errors"]] == [300, 301]
+        assert all(e["error"] for e in summary["errors"])
+        result = await session.execute("SELECT COUNT(*) FROM users")
+        assert result.first_row()[0] == 300
+
+        with pytest.raises(ValueError):
+            await session.execute_many(prepared, [], window=0)
+        empty = await session.execute_many(prepared, [])
+        assert empty == {"succeeded": 0, "failed": 0, "errors": []}
+
+    async def test_bind_and_execute_repeatedly(self, session, users_table, sample_users):
+        """Test executing one bound statement several times"""
+        prepared = await session.prepare("SELECT username FROM users WHERE id = ?")
+        bound = prepared.bind({"id": 1})
+        assert bound.get_statement() == prepared.get_statement()
+
+        first = await session.execute_bound(bound)
+        second = await session.execute_bound(bound)
+        assert first.rows_typed() == second.rows_typed()
+        assert len(first) == 1
+
+    async def test_bind_missing_value(self, session, users_table):
+        """Test that a missing bind value is rejected at bind time"""
+        prepared = await session.prepare("INSERT INTO users (id, username) VALUES (?, ?)")
+
+        with pytest.raises(BindError):
+            prepared.bind({"id": 1})
+        with pytest.raises(BindError):
+            prepared.bind()
+
+    async def test_named_parameters(self, session, users_table, sample_users):
+        """Test binding by marker name regardless of dict order"""
+        prepared = await session.prepare(
+            "SELECT username FROM users WHERE id = :user_id AND age > :min_age ALLOW FILTERING"
+        )
+        result = await session.execute_prepared(prepared, {"min_age": 0, "user_id": 1})
+        assert len(result) == 1
+
+        insert = await session.prepare("INSERT INTO users (id, username, age) VALUES (?, ?, ?)")
+        await session.execute_prepared(insert, {"age": 50, "username": "ordered", "id": 77})
+        row = (await session.execute("SELECT username, age FROM users WHERE id = 77")).first_row()
+        assert row["username"] == "ordered"
+        assert row["age"] == 50
+
+    async def test_named_parameter_errors(self, session, users_table):
+        """Test that missing and extra keys are reported by name"""
+        prepared = await session.prepare(
+            "INSERT INTO users (id, username, age) VALUES (:id, :name, :age)"
+        )
+
+        with pytest.raises(BindError, match="missing values for 'name', 'age'"):
+            await session.execute_prepared(prepared, {"id": 1})
+        with pytest.raises(BindError, match="no bind markers named 'nickname'"):
+            await session.execute_prepared(
+                prepared, {"id": 1, "name": "a", "age": 1, "nickname": "b"}
+            )
+        with pytest.raises(BindError, match="missing values for 'age'.*'extra'"):
+            prepared.bind({"id": 1, "name": "a", "extra": 2})
+
+        # Unprepared statements are checked by the driver and raise the same error type
+        with pytest.raises(BindError, match="'username'"):
+            await session.execute("INSERT INTO users (id, username) VALUES (?, ?)", {"id": 1})
+
+    async def test_bind_wrong_type(self, session, users_table):
+        """Test that a value of the wrong type is rejected at bind time"""
+        prepared = await session.prepare("INSERT INTO users (id, username) VALUES (?, ?)")
+
+        with pytest.raises(BindError):
+            prepared.bind({"id": "not an int", "username": "user"})
+        assert issubclass(BindError, ScyllaError)
+
+    async def test_bind_type_mismatch_message(self, session, users_table):
+        """Test that a type mismatch names the parameter, the Python type and the CQL type"""
+        prepared = await session.prepare("INSERT INTO users (id, username) VALUES (:id, :name)")
+
+        with pytest.raises(BindError, match="parameter 'id' expects CQL type int, got Python str"):
+            prepared.bind({"id": "not an int", "name": "user"})
+        with pytest.raises(BindError, match="parameter 'name' expects CQL type text"):
+            await session.execute_prepared(prepared, {"id": 1, "name": 5})
+
+    async def test_bind_out_of_range(self, session, test_keyspace):
+        """Test that ints too large for their column raise ValueOutOfRange"""
+        await session.execute(
+            "CREATE TABLE IF NOT EXISTS big_numbers (id int PRIMARY KEY, total bigint, big varint)"
+        )
+        await session.await_schema_agreement()
+        prepared = await session.prepare(
+            "INSERT INTO big_numbers (id, total, big) VALUES (:id, :total, :big)"
+        )
+
+        with pytest.raises(
+            ValueOutOfRange,
+            match="parameter 'id' got 3000000000, but CQL type int holds -2147483648 to 2147483647",
+        ):
+            prepared.bind({"id": 3_000_000_000, "total": 1, "big": 1})
+        with pytest.raises(ValueOutOfRange, match=f"parameter 'total' got {2**64}"):
+            await session.execute_prepared(prepared, {"id": 1, "total": 2**64, "big": 1})
+        assert issubclass(ValueOutOfRange, BindError)
+
+        # Values beyond 64 bits are kept exactly for varint columns
+        await session.execute_prepared(prepared, {"id": 1, "total": 2**63 - 1, "big": -(2**100)})
+        await session.execute_prepared(prepared, {"id": 2, "total": 0, "big": 12345})
+        result = await session.execute("SELECT big FROM big_numbers WHERE id = 1")
+        assert result.first_row()[0] is not None
+
+    async def test_strict_types(self, scylla_connection_string, session, test_keyspace):
+        """Test that strict_types() refuses lossy conversions the default session makes"""
+        await session.execute(
+            "CREATE TABLE IF NOT EXISTS readings "
+            "(id bigint PRIMARY KEY, value float, total double)"
+        )
+        await session.await_schema_agreement()
+        insert = "INSERT INTO readings (id, value, total) VALUES (:id, :value, :total)"
+
+        # By default numbers are converted to the column type
+        lenient = await session.prepare(insert)
+        await session.execute_prepared(lenient, {"id": 1, "value": 0.1, "total": 2})
+
+        strict = (
+            await SessionBuilder().known_node(scylla_connection_string).strict_types().build()
+        )
+        await strict.use_keyspace(test_keyspace, False)
+        prepared = await strict.prepare(insert)
+        await strict.execute_prepared(prepared, {"id": 2, "value": 0.5, "total": 2.0})
+
+        with pytest.raises(BindError, match="parameter 'value' .*not exactly representable"):
+            await strict.execute_prepared(prepared, {"id": 3, "value": 0.1, "total": 1.0})
+        with pytest.raises(BindError, match="parameter 'total' expects CQL type double"):
+            prepared.bind({"id": 3, "value": 0.5, "total": 1})
+
     async def test_prepared_idempotency(self, session, users_table):
         """Test prepared statement idempotency"""
         prepared = await session.prepare("SELECT * FROM users")
@@ -114,3 +335,94 @@ class TestPreparedStatement:
         """Test preparing invalid query"""
         with pytest.raises(ScyllaError):
             await session.prepare("INVALID QUERY SYNTAX")
+
+    async def test_prepare_with_timeout_and_retries(self, session, users_table):
+        """Test preparing with a timeout and retry budget"""
+        prepared = await session.prepare(
+            "SELECT * FROM users WHERE id = ?", timeout_ms=5000, retries=2
+        )
+
+        assert prepared.prepare_attempts() == 1
+        assert len(prepared.connected_at_prepare()) > 0
+
+    async def test_prepare_bypass_cache(self, session, users_table, sample_users):
+        """Test preparing a SELECT that reads past the row cache"""
+        prepared = await session.prepare("SELECT * FROM users WHERE id = ?", bypass_cache=True)
+        result = await session.execute_prepared(prepared, {"id": 1})
+        assert len(result) == 1
+
+        with pytest.raises(ValueError):
+            await session.prepare("DELETE FROM users WHERE id = ?", bypass_cache=True)
+
+    async def test_prepare_from(self, scylla_connection_string, session, users_table, sample_users):
+        """Test exporting a prepared statement and preparing it on another session"""
+        prepared = (
+            (await session.prepare("SELECT * FROM users WHERE id = ?"))
+            .with_consistency("one")
+            .with_page_size(25)
+            .with_retry_policy("fallthrough")
+            .set_idempotent(True)
+        )
+        data = prepared.to_dict()
+        assert data["query"] == "SELECT * FROM users WHERE id = ?"
+        assert data["consistency"] == "one"
+        assert data["page_size"] == 25
+        assert data["retry_policy"] == "fallthrough"
+
+        other = await SessionBuilder().known_node(scylla_connection_string).build()
+        rebound = await other.prepare_from(pickle.loads(pickle.dumps(data)))
+        assert rebound.to_dict() == data
+        assert rebound.get_id() == prepared.get_id()
+        assert (await other.prepare_from(prepared)).to_dict() == data
+
+        result = await other.execute_prepared(rebound, {"id": 1})
+        assert len(result) == 1
+
+        query = Query("SELECT * FROM users WHERE id = ?").with_bypass_cache().with_timeout(5000)
+        from_query = await session.prepare_from(query.to_dict())
+        assert "BYPASS CACHE" in from_query.get_statement()
+        assert from_query.to_dict()["timeout_ms"] == 5000
+
+        with pytest.raises(ValueError, match="Unknown query option"):
+            await session.prepare_from({"query": "SELECT * FROM users", "keyspace": "x"})
+
+    async def test_prepare_syntax_error_not_retried(self, session, test_keyspace):
+        """Test that a syntax error fails at once instead of sleeping through retries"""
+        import time
+
+        start = time.monotonic()
+        with pytest.raises(ScyllaError, match="Prepare error"):
+            await session.prepare("INVALID QUERY SYNTAX", retries=5)
+        # Five retries would back off for 100 + 200 + ... + 500 ms
+        assert time.monotonic() - start < 1.0
+
+    async def test_rate_limited_error(self, session, test_keyspace):
+        """Test per-partition rate limit rejections carry the partition key"""
+        import asyncio
+
+        assert issubclass(RateLimitedError, ScyllaError)
+
+        await session.execute(
+            """
+            CREATE TABLE IF NOT EXISTS limited (
+                id int,
+                seq int,
+                PRIMARY KEY (id, seq)
+            ) WITH per_partition_rate_limit = {'max_writes_per_second': 1}
+        """
+        )
+        prepared = await session.prepare("INSERT INTO limited (id, seq) VALUES (?, ?)")
+
+        results = await asyncio.gather(
+            *[session.execute_prepared(prepared, {"seq": i, "id": 7}) for i in range(200)],
+            return_exceptions=True,
+        )
+        errors = [r for r in results if isinstance(r, RateLimitedError)]
+        if not errors:
+            pytest.skip("Per-partition rate limiting not triggered by this Scylla version")
+
+        assert errors[0].partition_key == {"id": 7}
+        assert errors[0].operation == "write"
+        assert isinstance(errors[0].rejected_by_coordinator, bool)
+
+        await session.execute("DROP TABLE IF EXISTS limited")
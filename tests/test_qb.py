@@ -0,0 +1,102 @@
+"""
+Tests for the rsylla.qb query builder
+"""
+
+import pytest
+
+from rsylla.qb import delete, insert_into, select, update
+
+
+@pytest.mark.unit
+class TestQueryBuilder:
+    """Test CQL generation"""
+
+    def test_select(self):
+        """Test SELECT with restrictions, ordering and limit"""
+        query = (
+            select("events", ["id", "ts", "payload"])
+            .where("id")
+            .where("ts", ">=", name="since")
+            .order_by("ts", descending=True)
+            .limit(10)
+        )
+        assert query.build() == (
+            "SELECT id, ts, payload FROM events WHERE id = :id AND ts >= :since"
+            " ORDER BY ts DESC LIMIT 10"
+        )
+        assert str(query) == query.build()
+
+    def test_select_all(self):
+        """Test SELECT * with a keyspace-qualified table"""
+        query = select("ks.users").where("id", "in", name="ids").allow_filtering()
+        assert query.build() == "SELECT * FROM ks.users WHERE id IN :ids ALLOW FILTERING"
+
+    def test_select_bypass_cache(self):
+        """Test SELECT reading past the row cache"""
+        query = select("events").allow_filtering().bypass_cache()
+        assert query.build() == "SELECT * FROM events ALLOW FILTERING BYPASS CACHE"
+
+    def test_insert(self):
+        """Test INSERT with options"""
+        query = insert_into("users").values(["id", "username"]).if_not_exists().using_ttl(60)
+        assert query.build() == (
+            "INSERT INTO users (id, username) VALUES (:id, :username) IF NOT EXISTS USING TTL 60"
+        )
+
+        with pytest.raises(ValueError):
+            insert_into("users").build()
+
+    def test_update(self):
+        """Test UPDATE with assignments and a condition"""
+        query = update("users").set("username").set("age", name="new_age").where("id")
+        query = query.using_timestamp(1000).if_exists()
+        assert query.build() == (
+            "UPDATE users USING TIMESTAMP 1000 SET username = :username, age = :new_age"
+            " WHERE id = :id IF EXISTS"
+        )
+
+        with pytest.raises(ValueError):
+            update("users").set("username").build()
+
+    def test_delete(self):
+        """Test DELETE of rows and of columns"""
+        assert delete("users").where("id").build() == "DELETE FROM users WHERE id = :id"
+        assert (
+            delete("users", ["email"]).where("id").build()
+            == "DELETE email FROM users WHERE id = :id"
+        )
+
+        with pytest.raises(ValueError):
+            delete("users").build()
+
+    def test_rejects_injection(self):
+        """Test that identifiers and operators are validated"""
+        with pytest.raises(ValueError):
+            select("users; DROP TABLE users")
+        with pytest.raises(ValueError):
+            select("users", ["id, password"])
+        with pytest.raises(ValueError):
+            select("users").where("id", "= 1 OR 1 =")
+        with pytest.raises(ValueError):
+            update("users").set("username", name="x = 1 --")
+
+
+@pytest.mark.integration
+class TestQueryBuilderExecution:
+    """Test executing generated statements"""
+
+    async def test_round_trip(self, session, users_table):
+        """Test preparing and executing builder output"""
+        insert = await session.prepare(insert_into("users").values(["id", "username"]).build())
+        await session.execute_prepared(insert, {"id": 900, "username": "built"})
+
+        change = await session.prepare(update("users").set("username").where("id").build())
+        await session.execute_prepared(change, {"id": 900, "username": "changed"})
+
+        query = await session.prepare(select("users", ["username"]).where("id").build())
+        result = await session.execute_prepared(query, {"id": 900})
+        assert result.scalar() == "changed"
+
+        await session.execute(delete("users").where("id").build(), {"id": 900})
+        result = await session.execute_prepared(query, {"id": 900})
+        assert len(result) == 0
@@ -2,11 +2,12 @@
 Tests for Query execution
 """
 
+import json
 import time
 
 import pytest
 
-from rsylla import Query, ScyllaError
+from rsylla import ExecutionProfile, Query, ScyllaError, SessionBuilder
 
 
 @pytest.mark.integration
@@ -111,6 +112,41 @@ class TestQueryObject:
         result = await session.query(query)
         assert result is not None
 
+    async def test_query_with_db_timeout(self, session, users_table, sample_users):
+        """Test Query with Scylla's server-side USING TIMEOUT"""
+        query = Query("SELECT * FROM users WHERE id = ?").with_db_timeout(10000)
+        assert query.get_contents() == "SELECT * FROM users WHERE id = ? USING TIMEOUT 10000ms"
+        result = await session.query(query, {"id": 1})
+        assert len(result) == 1
+
+        update = Query("UPDATE users USING TTL 60 SET email = ? WHERE id = ?").with_db_timeout(
+            5000
+        )
+        assert "USING TIMEOUT 5000ms AND TTL 60 SET" in update.get_contents()
+        await session.query(update, {"email": "db@example.com", "id": 1})
+
+        query.with_db_timeout(None)
+        assert query.get_contents() == "SELECT * FROM users WHERE id = ?"
+
+        with pytest.raises(ValueError):
+            Query("TRUNCATE users").with_db_timeout(1000)
+        with pytest.raises(ValueError):
+            Query("SELECT * FROM users").with_db_timeout(0)
+
+    async def test_query_with_bypass_cache(self, session, users_table, sample_users):
+        """Test Query reading past the row cache, alone and with USING TIMEOUT"""
+        query = Query("SELECT * FROM users WHERE id = ?").with_bypass_cache()
+        assert query.get_contents() == "SELECT * FROM users WHERE id = ? BYPASS CACHE"
+        result = await session.query(query, {"id": 1})
+        assert len(result) == 1
+
+        query.with_db_timeout(5000)
+        assert query.get_contents().endswith("BYPASS CACHE USING TIMEOUT 5000ms")
+        assert len(await session.query(query, {"id": 1})) == 1
+
+        with pytest.raises(ValueError):
+            Query("DELETE FROM users WHERE id = 1").with_bypass_cache()
+
     async def test_query_with_tracing(self, session, users_table, sample_users):
         """Test Query with tracing"""
         query = Query("SELECT * FROM users WHERE id = ?").with_tracing(True)
@@ -122,6 +158,58 @@ class TestQueryObject:
         _ = result.tracing_id()
         # Trace ID might be None in some cases, but the query should work
 
+    async def test_get_tracing_info(self, session, users_table, sample_users):
+        """Test fetching the trace of a traced query"""
+        query = Query("SELECT * FROM users WHERE id = ?").with_tracing(True)
+        result = await session.query(query, {"id": 1})
+
+        info = await session.get_tracing_info(result.tracing_id())
+        assert info.coordinator
+        assert info.duration is None or info.duration > 0
+        assert "query" in info.parameters
+        assert len(info.events) > 0
+        assert info.events[0].activity
+        assert len(info.nodes()) > 0
+
+        with pytest.raises(ValueError):
+            await session.get_tracing_info("not-a-uuid")
+
+    async def test_result_trace(self, session, users_table, sample_users):
+        """Test fetching and caching the trace from the result"""
+        query = Query("SELECT * FROM users WHERE id = ?").with_tracing(True)
+        result = await session.query(query, {"id": 1})
+
+        trace = await result.trace()
+        assert trace.coordinator
+        assert len(trace.events) > 0
+        event = trace.events[0]
+        assert event.activity
+        assert event.source
+        assert event.source_elapsed is None or event.source_elapsed >= 0
+        cached = await result.trace()
+        assert [e.event_id for e in cached.events] == [e.event_id for e in trace.events]
+
+        untraced = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1})
+        assert await untraced.trace() is None
+
+    async def test_result_stats(
+        self, session, users_table, sample_users, scylla_connection_string
+    ):
+        """Test the per-request stats recorded on the result"""
+        result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1})
+        stats = result.stats()
+        assert stats["latency_ms"] > 0
+        assert stats["attempts"] >= 1
+        assert stats["coordinator"]
+        assert stats["compression"] is None
+        assert stats["rows_bytes"] > 0
+
+        compressed = await (
+            SessionBuilder().known_node(scylla_connection_string).compression("lz4").build()
+        )
+        result = await compressed.execute("SELECT * FROM system.local")
+        assert result.stats()["compression"] == "lz4"
+
     async def test_query_with_timestamp(self, session, users_table):
         """Test Query with custom timestamp"""
         timestamp = int(time.time() * 1000000)
@@ -160,6 +248,43 @@ class TestQueryObject:
         contents = query.get_contents()
         assert contents == query_str
 
+    async def test_query_to_dict(self, session, users_table, sample_users):
+        """Test serializing a query to a dict and rebuilding it from one"""
+        query = (
+            Query("SELECT * FROM users WHERE id = ?")
+            .with_consistency("LOCAL_QUORUM")
+            .with_serial_consistency("local_serial")
+            .with_page_size(50)
+            .with_timeout(5000)
+            .with_db_timeout(2000)
+            .with_bypass_cache()
+            .with_retry_policy("Fallthrough")
+        )
+        query.set_idempotent(True)
+        data = query.to_dict()
+        assert data["query"] == "SELECT * FROM users WHERE id = ?"
+        assert data["consistency"] == "local_quorum"
+        assert data["serial_consistency"] == "local_serial"
+        assert data["page_size"] == 50
+        assert data["timeout_ms"] == 5000
+        assert data["db_timeout_ms"] == 2000
+        assert data["retry_policy"] == "fallthrough"
+        assert data["idempotent"] is True
+
+        rebuilt = Query.from_dict(json.loads(json.dumps(data)))
+        assert rebuilt.to_dict() == data
+        assert rebuilt.get_contents() == query.get_contents()
+        result = await session.query(rebuilt, {"id": 1})
+        assert len(result) == 1
+
+        assert Query.from_dict({"query": "SELECT * FROM users"}).to_dict()["consistency"] is None
+        with pytest.raises(ValueError, match="Unknown query option 'page'"):
+            Query.from_dict({"query": "SELECT * FROM users", "page": 10})
+        with pytest.raises(ValueError, match="needs a 'query' key"):
+            Query.from_dict({"consistency": "one"})
+        with pytest.raises(ValueError, match="Invalid value for 'page_size'"):
+            Query.from_dict({"query": "SELECT * FROM users", "page_size": "ten"})
+
     async def test_query_all_consistency_levels(self, session, users_table):
         """Test all consistency levels"""
         # Only test consistency levels that work with a single-node cluster
@@ -170,3 +295,38 @@ class TestQueryObject:
             # Should not raise error
             result = await session.query(query)
             assert result is not None
+
+    async def test_query_with_retry_policy(self, session, users_table, sample_users):
+        """Test Query with a named retry policy"""
+        for policy in ["default", "fallthrough", "downgrading_consistency"]:
+            query = Query("SELECT * FROM users WHERE id = ?").with_retry_policy(policy)
+            result = await session.query(query, {"id": 1})
+            assert len(result) == 1
+
+        with pytest.raises(ValueError):
+            Query("SELECT * FROM users").with_retry_policy("nonexistent")
+
+    async def test_query_with_execution_profile(self, session, users_table, sample_users):
+        """Test Query with an execution profile"""
+        profile = (
+            ExecutionProfile()
+            .with_consistency("ONE")
+            .with_timeout(5000)
+            .with_retry_policy("fallthrough")
+        )
+        query = Query("SELECT * FROM users WHERE id = ?").with_execution_profile(profile)
+
+        result = await session.query(query, {"id": 1})
+        assert len(result) == 1
+
+    async def test_query_with_host(self, session):
+        """Test Query routed to a specific node"""
+        local = await session.execute("SELECT rpc_address FROM system.local")
+        address = local.first_row()[0]
+
+        query = Query("SELECT host_id FROM system.local").with_host(address)
+        result = await session.query(query)
+        assert len(result) == 1
+
+        with pytest.raises(ValueError):
+            Query("SELECT * FROM system.local").with_host("not-a-host")
@@ -2,8 +2,16 @@
 Tests for QueryResult and Row
 """
 
+import asyncio
+import json
+import pickle
+from concurrent.futures import ThreadPoolExecutor
+from dataclasses import dataclass
+
 import pytest
 
+from rsylla import ResultSnapshot, Row, SessionBuilder, diff_results, diff_rows
+
 
 @pytest.mark.integration
 class TestQueryResult:
@@ -32,6 +40,43 @@ class TestQueryResult:
 
         assert row is None
 
+    async def test_result_kind(self, session, test_keyspace, users_table):
+        """Test telling empty results apart from statements that don't return rows"""
+        result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": -1})
+        assert result.kind == "rows"
+        assert result.is_rows()
+        assert result.rows_num == 0
+
+        result = await session.execute(
+            "INSERT INTO users (id, username) VALUES (?, ?)", {"id": 1, "username": "a"}
+        )
+        assert result.kind == "void"
+        assert not result.is_rows()
+        assert result.rows_num is None
+
+        result = await session.execute(f"USE {test_keyspace}")
+        assert result.kind == "set_keyspace"
+
+        result = await session.execute("CREATE TABLE kind_test (id int PRIMARY KEY)")
+        assert result.kind == "schema_change"
+        assert result.rows_num is None
+        result = await session.execute("DROP TABLE kind_test")
+        assert result.kind == "schema_change"
+
+    async def test_result_pickling(self, session, users_table, sample_users):
+        """Test pickling a result as a detached snapshot of its rows"""
+        result = await session.execute("SELECT id, username, email FROM users WHERE id = 1")
+
+        snapshot = result.snapshot()
+        assert snapshot.columns() == ["id", "username", "email"]
+        assert snapshot[0].items() == result.first_row().items()
+
+        restored = pickle.loads(pickle.dumps(result))
+        assert isinstance(restored, ResultSnapshot)
+        assert len(restored) == 1
+        assert restored.first_row().username == "alice"
+        assert [row.values() for row in restored] == [row.values() for row in snapshot]
+
     async def test_result_single_row(self, session, users_table, sample_users):
         """Test getting single row"""
         result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1})
@@ -73,6 +118,38 @@ class TestQueryResult:
         assert isinstance(col_specs, list)
         assert len(col_specs) > 0
 
+    async def test_result_col_specs_structured(self, session, test_keyspace):
+        """Test column specs expose parsed CQL types"""
+        await session.execute("CREATE TYPE IF NOT EXISTS address (street text, zip int)")
+        await session.execute(
+            """
+            CREATE TABLE IF NOT EXISTS typed (
+                id int PRIMARY KEY,
+                tags frozen<set<text>>,
+                scores map<text, int>,
+                home frozen<address>
+            )
+            """
+        )
+        result = await session.execute("SELECT id, tags, scores, home FROM typed")
+        specs = {spec["name"]: spec for spec in result.col_specs()}
+
+        assert specs["id"]["keyspace"] == test_keyspace
+        assert specs["id"]["table"] == "typed"
+        assert specs["id"]["typ"].kind == "native"
+        assert str(specs["id"]["typ"]) == "int"
+
+        tags = specs["tags"]["typ"]
+        assert (tags.kind, tags.frozen) == ("set", True)
+        assert [t.name for t in tags.element_types] == ["text"]
+
+        scores = specs["scores"]["typ"]
+        assert [t.name for t in scores.element_types] == ["text", "int"]
+
+        home = specs["home"]["typ"]
+        assert (home.kind, home.udt_name, home.udt_keyspace) == ("udt", "address", test_keyspace)
+        assert [(name, t.name) for name, t in home.fields] == [("street", "text"), ("zip", "int")]
+
     async def test_result_warnings(self, session, users_table):
         """Test getting warnings"""
         result = await session.execute("SELECT * FROM users")
@@ -157,6 +234,22 @@ class TestRow:
         last_col = row[-1]
         assert last_col is not None
 
+    async def test_row_slicing(self, session, users_table, sample_users):
+        """Test slicing a row into a tuple and looking up column positions"""
+        query = "SELECT id, username, email FROM users WHERE id = ?"
+        row = (await session.execute(query, {"id": 1})).first_row()
+
+        assert row[1:3] == ("alice", "alice@example.com")
+        assert row[::-1] == ("alice@example.com", "alice", 1)
+        assert row[-2:] == ("alice", "alice@example.com")
+        assert row[5:] == ()
+        assert row[1:2][0] is row.username
+
+        assert row.index("email") == 2
+        assert row[row.index("username")] == "alice"
+        with pytest.raises(ValueError):
+            row.index("missing")
+
     async def test_row_len(self, session, users_table, sample_users):
         """Test row length"""
         result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1})
@@ -173,3 +266,560 @@ class TestRow:
         row_dict = row.as_dict()
         assert row_dict is not None
         assert isinstance(row_dict, dict)
+
+    async def test_row_as_dict_omit_nulls(self, session, users_table):
+        """Test NULL columns can be told apart from empty values"""
+        await session.execute(
+            "INSERT INTO users (id, username, age) VALUES (60, 'nulls', blobAsInt(0x))"
+        )
+        result = await session.execute("SELECT id, email, age FROM users WHERE id = ?", {"id": 60})
+
+        assert result.first_row_typed() == {"id": 60, "email": None, "age": None}
+        assert result.first_row_typed(omit_nulls=True) == {"id": 60, "age": None}
+        assert result.rows_typed(omit_nulls=True) == [{"id": 60, "age": None}]
+        assert result.first_row().as_dict(omit_nulls=True) == {"id": 60, "age": None}
+
+    async def test_take_rows(self, session, users_table, sample_users):
+        """Test moving rows out of a result"""
+        result = await session.execute("SELECT id, username FROM users")
+
+        rows = result.take_rows()
+        assert sorted(row.id for row in rows) == sorted(user["id"] for user in sample_users)
+        assert len(result) == 0
+        assert result.take_rows() == []
+
+        result = await session.execute("SELECT id FROM users", row_factory="tuple")
+        assert len(result.take_rows()) == len(sample_users)
+
+    async def test_rows_from_threads(self, session, users_table, sample_users):
+        """Test converting one result from several threads at once"""
+        result = await session.execute("SELECT id, username FROM users")
+        expected = sorted(result.rows_typed(), key=lambda row: row["id"])
+
+        with ThreadPoolExecutor(max_workers=4) as pool:
+            converted = list(pool.map(lambda _: result.rows_typed(), range(8)))
+
+        for rows in converted:
+            assert sorted(rows, key=lambda row: row["id"]) == expected
+
+    async def test_to_json(self, session, users_table, sample_users):
+        """Test exporting rows as JSON keyed by column name"""
+        query = "SELECT id, username, age FROM users WHERE id = ?"
+        result = await session.execute(query, {"id": 1})
+
+        assert json.loads(result.to_json()) == [{"id": 1, "username": "alice", "age": 30}]
+        assert json.loads(result.first_row().to_json()) == {
+            "id": 1,
+            "username": "alice",
+            "age": 30,
+        }
+
+        empty = await session.execute(query, {"id": -1})
+        assert empty.to_json() == "[]"
+
+    async def test_to_arrow(self, session, users_table, sample_users):
+        """Test exporting a result as an Arrow record batch"""
+        pa = pytest.importorskip("pyarrow")
+        result = await session.execute("SELECT id, username, age FROM users")
+
+        batch = result.to_arrow()
+        assert isinstance(batch, pa.RecordBatch)
+        assert batch.schema.names == ["id", "username", "age"]
+        assert batch.schema.field("id").type == pa.int32()
+        assert batch.num_rows == len(sample_users)
+        assert sorted(batch.column("username").to_pylist()) == sorted(
+            user["username"] for user in sample_users
+        )
+
+    async def test_to_pandas(self, session, users_table, sample_users):
+        """Test exporting a result as a pandas DataFrame"""
+        pytest.importorskip("pandas")
+        insert = "INSERT INTO users (id, username) VALUES (?, ?)"
+        await session.execute(insert, {"id": 99, "username": "nulls"})
+        result = await session.execute("SELECT id, username, age, is_active FROM users")
+
+        df = result.to_pandas().sort_values("id")
+        assert list(df.columns) == ["id", "username", "age", "is_active"]
+        assert str(df["id"].dtype) == "int32"
+        assert str(df["age"].dtype) == "Int32"
+        assert df["age"].isna().sum() == 1
+        assert df["username"].tolist()[-1] == "nulls"
+
+    async def test_to_polars(self, session, users_table, sample_users):
+        """Test exporting a result as a polars DataFrame"""
+        pl = pytest.importorskip("polars")
+        pytest.importorskip("pyarrow")
+        result = await session.execute("SELECT id, username, created_at FROM users")
+
+        df = result.to_polars().sort("id")
+        assert df.columns == ["id", "username", "created_at"]
+        assert df.schema["id"] == pl.Int32
+        assert isinstance(df.schema["created_at"], pl.Datetime)
+        assert df["username"].to_list() == [user["username"] for user in sample_users]
+
+    async def test_column_as_numpy(self, session, users_table, sample_users):
+        """Test extracting numeric columns as numpy arrays"""
+        np = pytest.importorskip("numpy")
+        result = await session.execute("SELECT id, username, age FROM users")
+
+        ages = result.column_as_numpy("age")
+        assert isinstance(ages, np.ndarray)
+        assert ages.dtype == np.int32
+        assert sorted(ages.tolist()) == sorted(user["age"] for user in sample_users)
+
+        with pytest.raises(TypeError):
+            result.column_as_numpy("username")
+        with pytest.raises(KeyError):
+            result.column_as_numpy("missing")
+
+    async def test_scalar(self, session, users_table, sample_users):
+        """Test reading a single value"""
+        result = await session.execute("SELECT count(*) FROM users")
+        assert result.scalar() == len(sample_users)
+
+        result = await session.execute("SELECT username FROM users WHERE id = ?", {"id": -1})
+        assert result.scalar() is None
+
+    async def test_maybe_first_row_typed(self, session, users_table, sample_users):
+        """Test the first row keyed by column name, if any"""
+        query = "SELECT id, username, email FROM users WHERE id = ?"
+
+        result = await session.execute(query, {"id": 1})
+        assert result.maybe_first_row_typed() == {
+            "id": 1,
+            "username": "alice",
+            "email": "alice@example.com",
+        }
+        assert result.first_row_typed() == result.maybe_first_row_typed()
+        assert result.rows_typed() == [result.maybe_first_row_typed()]
+
+        result = await session.execute(query, {"id": -1})
+        assert result.maybe_first_row_typed() is None
+
+    async def test_row_access_by_name(self, session, users_table, sample_users):
+        """Test reading row columns by name"""
+        query = "SELECT id, username, email FROM users WHERE id = ?"
+        row = (await session.execute(query, {"id": 1})).first_row()
+
+        assert row.username == "alice"
+        assert row["id"] == 1
+        assert row[1] == row["username"]
+        assert row.keys() == ["id", "username", "email"]
+        assert row.values() == [1, "alice", "alice@example.com"]
+        assert dict(row.items()) == {"id": 1, "username": "alice", "email": "alice@example.com"}
+        assert row.as_dict() == dict(row.items())
+
+        with pytest.raises(AttributeError):
+            _ = row.missing
+        with pytest.raises(KeyError):
+            _ = row["missing"]
+
+    async def test_row_columns_converted_once(self, session, users_table, sample_users):
+        """Test a column is converted on first access and then reused"""
+        query = "SELECT id, username, email FROM users WHERE id = ?"
+        row = (await session.execute(query, {"id": 1})).first_row()
+
+        assert row["email"] is row.email
+        assert row.email is row[2]
+        assert row.values()[1] is row.username
+        assert row.as_dict()["email"] is row.email
+
+    async def test_paged_row_access_by_name(self, session, users_table, sample_users):
+        """Test rows from paginate() carry their column names"""
+        pager = session.paginate("SELECT id, username FROM users", page_size=2)
+
+        names = {row.id: row["username"] async for row in pager}
+        assert names == {user["id"]: user["username"] for user in sample_users}
+
+    async def test_rows_named(self, session, users_table, sample_users):
+        """Test rows as namedtuples keyed by column name"""
+        query = "SELECT id, username, age FROM users WHERE id = ?"
+        result = await session.execute(query, {"id": 1})
+
+        (row,) = result.rows_named()
+        assert row.id == 1
+        assert row.username == "alice"
+        assert row._fields == ("id", "username", "age")
+        assert tuple(row) == (1, "alice", 30)
+
+    async def test_row_factory_per_call(self, session, users_table, sample_users):
+        """Test choosing the row shape for a single execute call"""
+        query = "SELECT id, username FROM users WHERE id = ?"
+
+        result = await session.execute(query, {"id": 1}, row_factory="tuple")
+        assert result.rows() == [(1, "alice")]
+
+        result = await session.execute(query, {"id": 1}, row_factory="dict")
+        assert result.first_row() == {"id": 1, "username": "alice"}
+
+        result = await session.execute(query, {"id": 1}, row_factory="namedtuple")
+        assert result.single_row().username == "alice"
+
+        result = await session.execute(
+            query, {"id": 1}, row_factory=lambda names, values: dict(zip(names, values))["id"]
+        )
+        assert list(result) == [1]
+
+        with pytest.raises(ValueError):
+            await session.execute(query, {"id": 1}, row_factory="list")
+
+    async def test_row_factory_session_default(
+        self, scylla_connection_string, test_keyspace, users_table, sample_users
+    ):
+        """Test a session-wide row factory and per-call override"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .use_keyspace(test_keyspace, False)
+            .row_factory("dict")
+            .build()
+        )
+        query = "SELECT id, username FROM users WHERE id = ?"
+
+        result = await session.execute(query, {"id": 2})
+        assert result.rows() == [{"id": 2, "username": "bob"}]
+
+        result = await session.execute(query, {"id": 2}, row_factory="tuple")
+        assert result.first_row() == (2, "bob")
+
+    async def test_rows_as_dataclass(self, session, users_table, sample_users):
+        """Test mapping rows onto a dataclass by column name"""
+
+        @dataclass
+        class User:
+            id: int
+            username: str
+            age: float | None = None
+
+        result = await session.execute("SELECT username, id, age, email FROM users")
+        users = sorted(result.rows_as(User), key=lambda user: user.id)
+
+        assert users[0] == User(id=1, username="alice", age=30.0)
+        assert isinstance(users[0].age, float)
+        assert len(users) == len(sample_users)
+
+    async def test_rows_as_coercion_error(self, session, users_table, sample_users):
+        """Test coercion failures name the offending column"""
+
+        @dataclass
+        class BadUser:
+            id: int
+            username: int
+
+        result = await session.execute("SELECT id, username FROM users WHERE id = ?", {"id": 1})
+        with pytest.raises(ValueError, match="Column 'username'"):
+            result.rows_as(BadUser)
+
+        with pytest.raises(TypeError):
+            result.rows_as(dict)
+
+    async def test_rows_as_pydantic(self, session, users_table, sample_users):
+        """Test mapping rows onto a pydantic model"""
+        pydantic = pytest.importorskip("pydantic")
+
+        class User(pydantic.BaseModel):
+            id: int
+            username: str
+
+        class Strict(pydantic.BaseModel):
+            id: int
+            username: list[int]
+
+        result = await session.execute("SELECT id, username FROM users WHERE id = ?", {"id": 1})
+        assert result.rows_as(User) == [User(id=1, username="alice")]
+        with pytest.raises(ValueError, match="Column 'username'"):
+            result.rows_as(Strict)
+
+    async def test_rows_named_invalid_identifiers(self, session):
+        """Test column names that aren't identifiers fall back to positional fields"""
+        result = await session.execute("SELECT key, writetime(cluster_name) FROM system.local")
+
+        (row,) = result.rows_named()
+        assert row._fields[0] == "key"
+        assert row._fields[1] == "_1"
+
+
+@pytest.mark.integration
+class TestPager:
+    """Test paginated row iteration"""
+
+    async def test_paginate(self, session, users_table, sample_users):
+        """Test iterating every row across small pages"""
+        pager = session.paginate("SELECT id FROM users", page_size=2)
+
+        ids = [row[0] async for row in pager]
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+        assert pager.paging_state() is None
+
+    async def test_paginate_read_ahead(self, session, users_table, sample_users):
+        """Test the next page is prefetched once the current one is mostly consumed"""
+        pager = session.paginate("SELECT id FROM users", page_size=2, read_ahead=0.5)
+
+        first = await pager.__anext__()
+        assert pager.is_prefetching()
+        # The page being read ahead is still where a resumed pager would start
+        checkpoint = pager.paging_state()
+        assert checkpoint is not None
+
+        remaining = [row async for row in pager]
+        assert len(remaining) == len(sample_users) - 1
+        assert pager.paging_state() is None
+
+        resumed = session.paginate("SELECT id FROM users", page_size=2, paging_state=checkpoint)
+        ids = [row[0] async for row in resumed]
+        assert len(ids) == len(sample_users) - 2
+        assert first[0] not in ids
+
+    async def test_paginate_prefetch(self, session, users_table, sample_users):
+        """Test pages are fetched ahead of consumption up to the prefetch depth"""
+        pager = session.paginate("SELECT id FROM users", page_size=1, prefetch=2)
+        assert pager.pages_fetched() == 0
+
+        first = await pager.__anext__()
+        await asyncio.sleep(0.5)
+        assert pager.pages_consumed() == 1
+        assert pager.pages_fetched() > pager.pages_consumed()
+
+        ids = [first[0]] + [row[0] async for row in pager]
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+        assert pager.pages_consumed() >= len(sample_users)
+        assert pager.paging_state() is None
+
+    async def test_paginate_prefetch_with_read_ahead(self, session, users_table):
+        """Test prefetch and read-ahead are mutually exclusive"""
+        with pytest.raises(ValueError):
+            session.paginate("SELECT id FROM users", read_ahead=0.5, prefetch=2)
+
+    async def test_paginate_invalid_read_ahead(self, session, users_table):
+        """Test read-ahead must be a fraction"""
+        with pytest.raises(ValueError):
+            session.paginate("SELECT id FROM users", read_ahead=1.5)
+
+
+@pytest.mark.integration
+class TestPageIterator:
+    """Test iterating page by page"""
+
+    async def test_pages(self, session, users_table, sample_users):
+        """Test each page is a result carrying the next page's paging state"""
+        pages = [page async for page in session.pages("SELECT id FROM users", page_size=2)]
+
+        ids = [row.id for page in pages for row in page.rows()]
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+        assert all(len(page) <= 2 for page in pages)
+        assert all(page.paging_state() is not None for page in pages[:-1])
+        assert pages[-1].paging_state() is None
+
+    async def test_pages_resume(self, session, users_table, sample_users):
+        """Test resuming from a page's paging state"""
+        iterator = session.pages("SELECT id FROM users", page_size=2)
+        first = await iterator.__anext__()
+        assert iterator.paging_state() == first.paging_state()
+
+        resumed = session.pages(
+            "SELECT id FROM users", page_size=2, paging_state=first.paging_state()
+        )
+        rest = [row.id async for page in resumed for row in page.rows()]
+        seen = [row.id for row in first.rows()] + rest
+        assert sorted(seen) == sorted(user["id"] for user in sample_users)
+
+
+    async def test_session_default_page_size(
+        self, scylla_connection_string, test_keyspace, users_table, sample_users
+    ):
+        """Test the session page size applies unless a call overrides it"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .use_keyspace(test_keyspace, False)
+            .default_page_size(1)
+            .build()
+        )
+
+        prepared = await session.prepare("SELECT id FROM users")
+        assert prepared.get_page_size() == 1
+        assert prepared.with_page_size(50).get_page_size() == 50
+
+        pages = [page async for page in session.pages("SELECT id FROM users")]
+        assert all(len(page) <= 1 for page in pages)
+        pages = [page async for page in session.pages("SELECT id FROM users", page_size=10)]
+        assert len(pages[0]) == len(sample_users)
+
+    async def test_default_page_size_must_be_positive(self):
+        """Test the session page size is validated"""
+        with pytest.raises(ValueError):
+            SessionBuilder().default_page_size(0)
+
+
+@pytest.mark.integration
+class TestExecuteStream:
+    """Test streaming rows to callbacks"""
+
+    async def test_stream_rows(self, session, users_table, sample_users):
+        """Test every row reaches the row callback"""
+        ids = []
+        count = await session.execute_stream(
+            "SELECT id FROM users", on_row=lambda row: ids.append(row.id), page_size=2
+        )
+
+        assert count == len(sample_users)
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+
+    async def test_stream_pages(self, session, users_table, sample_users):
+        """Test pages are delivered with their column names"""
+        pages = []
+        count = await session.execute_stream(
+            "SELECT id, username FROM users",
+            on_page=lambda rows, names: pages.append((len(rows), names)),
+            page_size=2,
+            prefetch_pages=1,
+        )
+
+        assert count == len(sample_users)
+        assert sum(size for size, _ in pages) == len(sample_users)
+        assert all(size <= 2 and names == ["id", "username"] for size, names in pages)
+
+    async def test_stream_async_callback(self, session, users_table, sample_users):
+        """Test coroutine callbacks are awaited before the next row"""
+        ids = []
+
+        async def on_row(row):
+            await asyncio.sleep(0)
+            ids.append(row["id"])
+
+        await session.execute_stream("SELECT id FROM users", on_row=on_row, page_size=1)
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+
+    async def test_stream_callback_error(self, session, users_table, sample_users):
+        """Test an exception in the callback stops the stream"""
+
+        def on_row(row):
+            raise RuntimeError("stop")
+
+        with pytest.raises(RuntimeError, match="stop"):
+            await session.execute_stream("SELECT id FROM users", on_row=on_row)
+
+    async def test_stream_requires_one_callback(self, session, users_table):
+        """Test exactly one of on_row and on_page is required"""
+        with pytest.raises(ValueError):
+            session.execute_stream("SELECT id FROM users")
+        with pytest.raises(ValueError):
+            session.execute_stream("SELECT id FROM users", on_row=print, on_page=print)
+
+
+@pytest.mark.integration
+class TestFullScan:
+    """Test parallel token-range table scans"""
+
+    async def test_full_scan(self, session, users_table, sample_users):
+        """Test every row is yielded exactly once"""
+        scan = session.full_scan("users", concurrency=4, columns=["id", "username"], page_size=2)
+
+        ids = [row[0] async for row in scan]
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+
+    async def test_full_scan_bypass_cache(self, session, users_table, sample_users):
+        """Test scanning past the row cache"""
+        scan = session.full_scan("users", bypass_cache=True)
+
+        ids = [row["id"] async for row in scan]
+        assert sorted(ids) == sorted(user["id"] for user in sample_users)
+
+    async def test_full_scan_qualified_table(self, session, test_keyspace, users_table):
+        """Test scanning a keyspace-qualified table with several ranges per task"""
+        for i in range(100, 150):
+            await session.execute(
+                "INSERT INTO users (id, username) VALUES (?, ?)", {"id": i, "username": f"u{i}"}
+            )
+
+        scan = session.full_scan(f"{test_keyspace}.users", ranges_per_task=8)
+        assert len([row async for row in scan]) == 50
+
+    async def test_full_scan_unknown_table(self, session, test_keyspace):
+        """Test errors surface when iterating"""
+        scan = session.full_scan("nonexistent_table")
+        with pytest.raises(ValueError):
+            await scan.__anext__()
+
+        with pytest.raises(ValueError):
+            session.full_scan("users", concurrency=0)
+
+
+@pytest.mark.integration
+class TestDiff:
+    """Test row and result diffing"""
+
+    async def test_diff_rows(self, session, users_table, sample_users):
+        """Test comparing two rows column by column"""
+        query = "SELECT id, username, age FROM users WHERE id = ?"
+        alice = (await session.execute(query, {"id": 1})).first_row()
+        bob = (await session.execute(query, {"id": 2})).first_row()
+
+        assert diff_rows(alice, alice) == {}
+        assert diff_rows(alice, bob) == {
+            "col_0": (1, 2),
+            "col_1": ("alice", "bob"),
+            "col_2": (30, 25),
+        }
+        assert diff_rows(alice, bob, ["id", "username", "age"])["age"] == (30, 25)
+
+        with pytest.raises(ValueError):
+            diff_rows(alice, bob, ["id"])
+
+    async def test_diff_results(self, session, users_table, sample_users):
+        """Test matching rows by key and reporting changed columns"""
+        before = await session.execute("SELECT id, username, age FROM users")
+
+        await session.execute("UPDATE users SET age = ? WHERE id = ?", {"age": 31, "id": 1})
+        await session.execute("DELETE FROM users WHERE id = ?", {"id": 2})
+        await session.execute(
+            "INSERT INTO users (id, username) VALUES (?, ?)", {"id": 50, "username": "zed"}
+        )
+
+        after = await session.execute("SELECT age, username, id FROM users")
+        diffs = {diff["key"]["id"]: diff for diff in diff_results(before, after, ["id"])}
+
+        assert diffs[1]["status"] == "changed"
+        assert diffs[1]["changes"] == {"age": (30, 31)}
+        assert diffs[2]["status"] == "removed"
+        assert diffs[50]["status"] == "added"
+        assert diffs[50]["changes"]["username"] == (None, "zed")
+        assert len(diffs) == 3
+
+        with pytest.raises(ValueError):
+            diff_results(before, after, ["missing"])
+
+
+@pytest.mark.unit
+class TestPickling:
+    """Test pickling rows and result snapshots"""
+
+    def test_row_pickling(self):
+        """Test a row round-trips with its names and values"""
+        row = Row(
+            [1, "alice", None, [1, 2], {"k": "v"}, b"\x00"],
+            ["id", "name", "email", "tags", "attrs", "data"],
+        )
+
+        restored = pickle.loads(pickle.dumps(row))
+        assert restored.items() == row.items()
+        assert restored.name == "alice"
+        assert restored.to_json() == row.to_json()
+
+        unnamed = pickle.loads(pickle.dumps(Row([1, 2])))
+        assert unnamed.keys() == ["col_0", "col_1"]
+
+        with pytest.raises(ValueError):
+            Row([1], ["id", "name"])
+
+    def test_snapshot_pickling(self):
+        """Test a snapshot round-trips and indexes like a list of rows"""
+        snapshot = ResultSnapshot(["id", "name"], [[1, "alice"], [2, "bob"]])
+
+        restored = pickle.loads(pickle.dumps(snapshot))
+        assert restored.columns() == ["id", "name"]
+        assert len(restored) == 2
+        assert restored[-1].name == "bob"
+        assert [row["id"] for row in restored] == [1, 2]
+        with pytest.raises(IndexError):
+            restored[2]
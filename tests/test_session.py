@@ -2,9 +2,26 @@
 Tests for Session and SessionBuilder
 """
 
+import asyncio
+import logging
+import time
+from concurrent.futures import ThreadPoolExecutor
+
 import pytest
 
-from rsylla import ScyllaError, Session, SessionBuilder
+from rsylla import (
+    Batch,
+    DeadlineExceededError,
+    ExecutionProfile,
+    LoadBalancingPolicy,
+    Query,
+    ScyllaError,
+    ScyllaWarning,
+    Session,
+    SessionBuilder,
+    build_info,
+    configure_logging,
+)
 
 
 @pytest.mark.integration
@@ -42,6 +59,19 @@ class TestSessionBuilder:
         )
         assert session is not None
 
+    async def test_session_builder_connections_per_shard(self, scylla_connection_string):
+        """Test opening several connections to every shard"""
+        session = await (
+            SessionBuilder().known_node(scylla_connection_string).connections_per_shard(2).build()
+        )
+        results = await asyncio.gather(
+            *[session.execute("SELECT now() FROM system.local") for _ in range(50)]
+        )
+        assert len(results) == 50
+
+        with pytest.raises(ValueError):
+            SessionBuilder().connections_per_shard(0)
+
     async def test_session_builder_compression(self, scylla_connection_string):
         """Test SessionBuilder with compression"""
         # Test LZ4
@@ -62,6 +92,571 @@ class TestSessionBuilder:
         )
         assert session_none is not None
 
+    async def test_session_builder_emit_warnings(self, scylla_connection_string, users_table):
+        """Test SessionBuilder with server warnings forwarded to Python"""
+        assert issubclass(ScyllaWarning, UserWarning)
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .use_keyspace("test_rsylla", False)
+            .emit_warnings(True)
+            .build()
+        )
+
+        # A batch this size is over the server's batch size warning threshold
+        batch = Batch("logged")
+        batch.append_statement("INSERT INTO users (id, username) VALUES (?, ?)")
+        batch.append_statement("INSERT INTO users (id, username) VALUES (?, ?)")
+        with pytest.warns(ScyllaWarning) as record:
+            result = await session.batch(
+                batch,
+                [{"id": 802, "username": "x" * 100_000}, {"id": 803, "username": "y" * 100_000}],
+            )
+
+        assert len(result.warnings()) > 0
+        emitted = [w for w in record if issubclass(w.category, ScyllaWarning)]
+        assert [str(warning.message) for warning in emitted] == result.warnings()
+
+    async def test_session_builder_collect_warnings(self, scylla_connection_string, users_table):
+        """Test server warnings accumulate on the session until drained"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .use_keyspace("test_rsylla", False)
+            .collect_warnings(True)
+            .build()
+        )
+
+        batch = Batch("logged")
+        batch.append_statement("INSERT INTO users (id, username) VALUES (?, ?)")
+        batch.append_statement("INSERT INTO users (id, username) VALUES (?, ?)")
+        result = await session.batch(
+            batch,
+            [{"id": 800, "username": "x" * 100_000}, {"id": 801, "username": "y" * 100_000}],
+        )
+
+        assert session.take_warnings() == result.warnings()
+        assert len(result.warnings()) > 0
+        assert session.take_warnings() == []
+
+    async def test_session_builder_fairness_classes(self, scylla_connection_string):
+        """Test statements are throttled per fairness class"""
+        import asyncio
+
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .fairness_class("background", max_in_flight=2)
+            .fairness_class("interactive")
+            .build()
+        )
+
+        background = Query("SELECT now() FROM system.local").with_fairness_class("background")
+        interactive = Query("SELECT now() FROM system.local").with_fairness_class("interactive")
+        prepared = await session.prepare("SELECT now() FROM system.local")
+
+        results = await asyncio.gather(
+            *[session.query(background) for _ in range(20)],
+            *[session.query(interactive) for _ in range(20)],
+            *[
+                session.execute_prepared(prepared.with_fairness_class("background"))
+                for _ in range(5)
+            ],
+        )
+        assert len(results) == 45
+        assert session.fairness_in_flight() == {"background": 0}
+
+        with pytest.raises(ValueError):
+            await session.query(Query("SELECT now() FROM system.local").with_fairness_class("x"))
+
+    async def test_fairness_classes_per_session(self, scylla_connection_string):
+        """Test sessions built from one builder don't share fairness class slots"""
+        builder = (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .fairness_class("background", max_in_flight=1)
+        )
+        first = await builder.build()
+        second = await builder.build()
+
+        query = Query("SELECT now() FROM system.local").with_fairness_class("background")
+        pending = [first.query(query) for _ in range(5)]
+        assert second.fairness_in_flight() == {"background": 0}
+        await asyncio.gather(*pending)
+        assert first.fairness_in_flight() == {"background": 0}
+
+    def test_session_across_event_loops(self, scylla_connection_string):
+        """Test a session built on one event loop serves requests from other loops"""
+        async def connect():
+            return await Session.connect([scylla_connection_string])
+
+        session = asyncio.run(connect())
+
+        async def query():
+            result = await session.execute("SELECT now() FROM system.local")
+            return result.first_row() is not None
+
+        # A new loop after the one that built the session has closed
+        assert asyncio.run(query())
+
+        # Several loops at once, one per thread
+        with ThreadPoolExecutor(max_workers=4) as pool:
+            assert all(pool.map(lambda _: asyncio.run(query()), range(8)))
+
+    async def test_session_builder_rate_limit(self, scylla_connection_string):
+        """Test the client-side rate limiter and its usage in metrics()"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .rate_limit(requests_per_second=50, max_concurrent=2)
+            .build()
+        )
+
+        start = time.monotonic()
+        results = await asyncio.gather(
+            *[session.execute("SELECT now() FROM system.local") for _ in range(20)]
+        )
+        assert len(results) == 20
+        # 20 requests spaced 20ms apart can't finish sooner than ~380ms
+        assert time.monotonic() - start >= 0.35
+
+        usage = session.metrics()["rate_limiter"]
+        assert usage["requests_per_second"] == 50
+        assert usage["max_concurrent"] == 2
+        assert usage["in_flight"] == 0
+        assert usage["waiting"] == 0
+        assert usage["admitted"] == 20
+        assert 0 < usage["throttled"] <= 20
+
+        unlimited = await Session.connect([scylla_connection_string])
+        assert "rate_limiter" not in unlimited.metrics()
+
+        with pytest.raises(ValueError):
+            SessionBuilder().rate_limit(requests_per_second=0)
+        with pytest.raises(ValueError):
+            SessionBuilder().rate_limit(max_concurrent=0)
+        with pytest.raises(ValueError):
+            SessionBuilder().rate_limit(requests_per_second=1e-320)
+
+    async def test_rate_limit_covers_paging_and_writers(self, scylla_connection_string):
+        """Test pages, streams and ordered writes all go through the rate limiter"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .rate_limit(max_concurrent=2)
+            .build()
+        )
+        query = "SELECT * FROM system_schema.columns"
+
+        pager = session.paginate(query, page_size=50)
+        rows = [row async for row in pager]
+        assert len(rows) > 50
+        fetched = pager.pages_fetched()
+        assert session.metrics()["rate_limiter"]["admitted"] == fetched
+
+        pages = [page async for page in session.pages(query, page_size=50)]
+        admitted = fetched + len(pages)
+        assert session.metrics()["rate_limiter"]["admitted"] == admitted
+
+        streamed = []
+        await session.execute_stream(
+            query, on_page=lambda rows, names: streamed.append(rows), page_size=50
+        )
+        assert session.metrics()["rate_limiter"]["admitted"] >= admitted + len(streamed)
+
+        before = session.metrics()["rate_limiter"]["admitted"]
+        writer = session.ordered_writer()
+        await writer.submit("key", "SELECT now() FROM system.local")
+        assert session.metrics()["rate_limiter"]["admitted"] == before + 1
+
+    async def test_inflight_requests(self, scylla_connection_string):
+        """Test in-flight request counts, overall and per node"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .rate_limit(max_concurrent=2)
+            .track_node_requests()
+            .build()
+        )
+        assert session.inflight_requests() == 0
+
+        requests = [session.execute("SELECT now() FROM system.local") for _ in range(10)]
+        assert session.inflight_requests() == 10
+        depths = []
+        while session.inflight_requests():
+            depths.append(sum(session.node_queue_depths().values()))
+            await asyncio.sleep(0.001)
+        await asyncio.gather(*requests)
+
+        assert max(depths, default=0) <= 2
+        assert session.inflight_requests() == 0
+        assert session.node_queue_depths() == {}
+
+        untracked = await Session.connect([scylla_connection_string])
+        await untracked.execute("SELECT now() FROM system.local")
+        assert untracked.node_queue_depths() == {}
+
+    async def test_cancelled_requests_are_orphaned(self, scylla_connection_string):
+        """Test that cancelling an awaited request drops it and counts it as orphaned"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .rate_limit(requests_per_second=1, max_concurrent=1)
+            .build()
+        )
+        assert session.orphaned_requests() == 0
+
+        await session.execute("SELECT now() FROM system.local")
+        # The limiter holds the next request back for a second, so it is still in flight
+        with pytest.raises(asyncio.TimeoutError):
+            await asyncio.wait_for(session.execute("SELECT now() FROM system.local"), 0.1)
+        # The cancellation reaches the Rust future on a runtime thread
+        await asyncio.sleep(0.1)
+        assert session.orphaned_requests() == 1
+
+        result = await session.execute("SELECT now() FROM system.local")
+        assert result.first_row() is not None
+        assert session.orphaned_requests() == 1
+
+    async def test_deadline(self, scylla_connection_string):
+        """Test a deadline bounds the whole call, including waiting for the limiter"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .rate_limit(requests_per_second=1)
+            .build()
+        )
+        result = await session.execute("SELECT now() FROM system.local", deadline=time.time() + 5)
+        assert result.first_row() is not None
+
+        # The limiter holds the next request back for a second, past its deadline
+        with pytest.raises(DeadlineExceededError, match="Deadline exceeded"):
+            await session.execute("SELECT now() FROM system.local", deadline=time.time() + 0.1)
+        assert session.orphaned_requests() == 0
+
+        with pytest.raises(DeadlineExceededError):
+            await session.query(Query("SELECT now() FROM system.local"), deadline=time.time())
+        with pytest.raises(ValueError, match="Invalid deadline"):
+            session.execute("SELECT now() FROM system.local", deadline=float("nan"))
+        with pytest.raises(ValueError, match="Invalid deadline"):
+            session.execute("SELECT now() FROM system.local", deadline=1e300)
+
+    async def test_session_builder_build_with_retry(self, scylla_connection_string):
+        """Test build_with_retry connects on the first attempt when the cluster is up"""
+        session = await (
+            SessionBuilder().known_node(scylla_connection_string).build_with_retry(max_attempts=3)
+        )
+        result = await session.execute("SELECT now() FROM system.local")
+        assert result.first_row() is not None
+
+    async def test_session_builder_dns_refresh(self, scylla_connection_string, scylla_port):
+        """Test contact point hostnames are re-resolved in the background"""
+        port = scylla_port
+        session = await (
+            SessionBuilder().known_node(f"localhost:{port}").dns_refresh(interval_ms=100).build()
+        )
+        for _ in range(50):
+            if session.contact_points():
+                break
+            await asyncio.sleep(0.1)
+        assert any(address.endswith(f":{port}") for address in session.contact_points())
+
+        result = await session.execute("SELECT now() FROM system.local")
+        assert result.first_row() is not None
+
+        static = await SessionBuilder().known_node(scylla_connection_string).build()
+        assert static.contact_points() == []
+
+        with pytest.raises(ValueError):
+            SessionBuilder().dns_refresh(interval_ms=0)
+
+    async def test_session_check_connectivity(self, scylla_connection_string):
+        """Test probing every node for a readiness check"""
+        session = await SessionBuilder().known_node(scylla_connection_string).build()
+        assert session.is_connected()
+
+        health = await session.check_connectivity(timeout_ms=5000)
+        assert len(health) >= 1
+        for node in health:
+            assert node.connected
+            assert node.healthy
+            assert node.error is None
+            assert node.latency_ms >= 0
+            assert node.host_id
+
+        with pytest.raises(ValueError):
+            session.check_connectivity(timeout_ms=0)
+
+    async def test_session_describe(self, scylla_connection_string, test_keyspace):
+        """Test the diagnostic summary of a session"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .use_keyspace(test_keyspace, False)
+            .compression("lz4")
+            .connections_per_shard(2)
+            .build()
+        )
+        summary = session.describe()
+        assert summary["contact_points"] == [scylla_connection_string]
+        assert summary["keyspace"] == test_keyspace
+        assert summary["compression"] == "lz4"
+        assert summary["pool_size"] == {"kind": "per_shard", "size": 2}
+        assert summary["protocol_version"] == 4
+        assert summary["rsylla_version"] == build_info()["rsylla_version"]
+        assert summary["driver_version"] == build_info()["scylla_driver_version"]
+        assert any(node["connected"] for node in summary["nodes"])
+        assert all(node["address"] for node in summary["nodes"])
+
+        default = (await Session.connect([scylla_connection_string])).describe()
+        assert default["compression"] is None
+        assert default["keyspace"] is None
+        assert default["pool_size"]["kind"] == "per_shard"
+
+    async def test_supported_features(self, scylla_connection_string):
+        """Test reading the features the nodes advertise in SUPPORTED"""
+        session = await SessionBuilder().known_node(scylla_connection_string).build()
+        features = await session.supported_features(timeout_ms=5000)
+        assert features["errors"] == {}
+        assert len(features["nodes"]) >= 1
+        assert features["cql_versions"]
+        assert "lz4" in features["compression"]
+        # Scylla always advertises its sharding, which the driver reads too
+        assert features["shard_aware"]
+        assert "SCYLLA_NR_SHARDS" in features["extensions"]
+        assert set(features["shards"]) == set(features["nodes"])
+        assert isinstance(features["rate_limit_error"], bool)
+        for options in features["nodes"].values():
+            assert "CQL_VERSION" in options
+
+        with pytest.raises(ValueError):
+            session.supported_features(timeout_ms=0)
+
+    async def test_list_server_connections(self, scylla_connection_string):
+        """Test listing the session's connections from system.clients"""
+        session = await (
+            SessionBuilder().known_node(scylla_connection_string).connections_per_shard(2).build()
+        )
+        connections = await session.list_server_connections()
+        assert len(connections) >= 2
+        for connection in connections:
+            assert connection.driver_name == "ScyllaDB Rust Driver"
+            assert connection.node
+            assert connection.address
+            assert connection.port > 0
+            assert connection.shard_id is not None
+
+        # Every shard of every node serves some of the pool's connections
+        shard_counts = (await session.supported_features())["shards"]
+        for node, count in shard_counts.items():
+            served = {c.shard_id for c in connections if c.node == node}
+            assert served >= set(range(count))
+
+        everyone = await session.list_server_connections(own_only=False)
+        assert len(everyone) >= len(connections)
+
+    async def test_session_builder_hedged_startup(self, scylla_connection_string):
+        """Test SessionBuilder racing contact points when one of them is down"""
+        session = await (
+            SessionBuilder()
+            .known_nodes(["127.0.0.1:1", "::1", "[::1]:2", scylla_connection_string])
+            .hedged_startup(True, probe_timeout_ms=1000)
+            .build()
+        )
+        result = await session.execute("SELECT now() FROM system.local")
+        assert result is not None
+
+        # A bare IPv6 address isn't split into host and port
+        contact_points = session.describe()["contact_points"]
+        assert "[::1]:9042" in contact_points
+        assert "[::1]:2" in contact_points
+
+    async def test_session_builder_opentelemetry(self, scylla_connection_string):
+        """Test SessionBuilder emitting an OpenTelemetry span per request"""
+        sdk_trace = pytest.importorskip("opentelemetry.sdk.trace")
+        from opentelemetry.sdk.trace.export import SimpleSpanProcessor
+        from opentelemetry.sdk.trace.export.in_memory_span_exporter import (
+            InMemorySpanExporter,
+        )
+
+        exporter = InMemorySpanExporter()
+        provider = sdk_trace.TracerProvider()
+        provider.add_span_processor(SimpleSpanProcessor(exporter))
+
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .opentelemetry(tracer_provider=provider)
+            .build()
+        )
+        await session.execute("SELECT now() FROM system.local")
+        prepared = await session.prepare("SELECT * FROM system.local")
+        await session.execute_prepared(prepared.with_consistency("ONE"))
+        with pytest.raises(ScyllaError):
+            await session.execute("SELECT * FROM no_such_keyspace.no_such_table")
+
+        spans = exporter.get_finished_spans()
+        assert [span.name for span in spans] == [
+            "rsylla.execute",
+            "rsylla.prepare",
+            "rsylla.execute_prepared",
+            "rsylla.execute",
+        ]
+        executed = spans[2]
+        assert executed.attributes["db.query.text"] == "SELECT * FROM system.local"
+        assert len(executed.attributes["db.query.digest"]) == 16
+        assert executed.attributes["db.cassandra.consistency_level"] == "One"
+        assert executed.attributes["db.scylladb.retries"] == 0
+        assert "db.scylladb.coordinator" in executed.attributes
+        assert not spans[3].status.is_ok
+
+    async def test_configure_logging(self, scylla_connection_string, caplog):
+        """Test driver logs are forwarded to the rsylla Python logger"""
+        caplog.set_level(logging.DEBUG, logger="rsylla")
+        configure_logging("debug")
+        try:
+            await SessionBuilder().known_node(scylla_connection_string).build()
+        finally:
+            configure_logging("off")
+
+        records = [record for record in caplog.records if record.name == "rsylla"]
+        assert len(records) > 0
+        assert all(record.rust_target.startswith("scylla") for record in records)
+
+    async def test_session_builder_request_hook(self, scylla_connection_string):
+        """Test request hooks observe every request with timing and outcome"""
+        started, completed = [], []
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .request_hook(before=started.append, after=completed.append)
+            .build()
+        )
+
+        await session.execute("SELECT now() FROM system.local")
+        with pytest.raises(ScyllaError):
+            await session.execute("SELECT * FROM no_such_keyspace.no_such_table")
+
+        assert [event.statement for event in started] == [
+            "SELECT now() FROM system.local",
+            "SELECT * FROM no_such_keyspace.no_such_table",
+        ]
+        assert all(event.elapsed_ms is None for event in started)
+
+        ok, failed = completed
+        assert ok.operation == "execute"
+        assert ok.elapsed_ms > 0
+        assert ok.coordinator is not None
+        assert ok.error is None
+        assert isinstance(failed.error, ScyllaError)
+
+    async def test_session_builder_request_hook_blocks(self, scylla_connection_string):
+        """Test raising from a before hook aborts the request"""
+
+        def deny_writes(event):
+            if event.statement.startswith("INSERT"):
+                raise PermissionError("writes are disabled")
+
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .request_hook(before=deny_writes)
+            .build()
+        )
+        await session.execute("SELECT now() FROM system.local")
+        with pytest.raises(PermissionError):
+            await session.execute("INSERT INTO system.local (key) VALUES ('x')")
+
+    async def test_session_builder_default_execution_profile(self, scylla_connection_string):
+        """Test SessionBuilder with a default execution profile"""
+        profile = ExecutionProfile().with_consistency("ONE").with_retry_policy("default")
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .default_execution_profile(profile)
+            .build()
+        )
+        result = await session.execute("SELECT now() FROM system.local")
+        assert result is not None
+
+    async def test_session_builder_load_balancing_policy(self, scylla_connection_string):
+        """Test SessionBuilder with a DC-aware, latency-aware load balancing policy"""
+        policy = (
+            LoadBalancingPolicy()
+            .prefer_datacenter("datacenter1")
+            .token_aware(True)
+            .permit_dc_failover(True)
+            .latency_awareness(exclusion_threshold=3.0, minimum_measurements=10)
+        )
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .load_balancing_policy(policy)
+            .build()
+        )
+        result = await session.execute("SELECT now() FROM system.local")
+        assert result is not None
+
+        latencies = session.node_latencies()
+        assert len(latencies) > 0
+        for stats in latencies.values():
+            assert stats["average_ms"] > 0
+            assert stats["measurements"] >= 1
+            assert isinstance(stats["slow"], bool)
+
+        builder = SessionBuilder().known_node(scylla_connection_string)
+        other = await builder.load_balancing_policy(policy).build()
+        assert other.node_latencies() == {}
+
+        profile = ExecutionProfile().with_load_balancing_policy(LoadBalancingPolicy())
+        assert profile is not None
+
+    async def test_load_balancing_policy_survives_default_profile(self, scylla_connection_string):
+        """Test a default profile set after the load balancing policy keeps the policy"""
+        policy = LoadBalancingPolicy().latency_awareness(minimum_measurements=1)
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .load_balancing_policy(policy)
+            .default_execution_profile(ExecutionProfile().with_consistency("ONE"))
+            .build()
+        )
+        await session.execute("SELECT now() FROM system.local")
+        assert len(session.node_latencies()) > 0
+
+    async def test_session_builder_timestamp_generator(
+        self, scylla_connection_string, test_keyspace, users_table
+    ):
+        """Test writes get client-side timestamps from the session generator"""
+        session = await (
+            SessionBuilder()
+            .known_node(scylla_connection_string)
+            .use_keyspace(test_keyspace, False)
+            .timestamp_generator("monotonic")
+            .build()
+        )
+
+        before = int(time.time() * 1_000_000)
+        for i in range(3):
+            insert = "INSERT INTO users (id, username) VALUES (?, ?)"
+            await session.execute(insert, {"id": 700, "username": f"v{i}"})
+        result = await session.execute("SELECT WRITETIME(username) FROM users WHERE id = 700")
+        assert result.scalar() >= before
+
+        for generator in ("simple", None):
+            builder = SessionBuilder().known_node(scylla_connection_string)
+            assert await builder.timestamp_generator(generator).build() is not None
+
+    def test_timestamp_generator_invalid(self):
+        """Test unknown timestamp generators are rejected"""
+        with pytest.raises(ValueError):
+            SessionBuilder().timestamp_generator("random")
+
+    def test_latency_awareness_invalid_scale(self):
+        """Test latency awareness rejects a zero scale"""
+        with pytest.raises(ValueError):
+            LoadBalancingPolicy().latency_awareness(scale_ms=0)
+
 
 @pytest.mark.integration
 class TestSession:
@@ -81,6 +676,79 @@ class TestSession:
         await session.use_keyspace(test_keyspace, False)
         assert session.get_keyspace() == test_keyspace
 
+    async def test_execute_overrides(self, session, users_table, sample_users):
+        """Test per-call consistency and timeout overrides"""
+        result = await session.execute(
+            "SELECT * FROM users WHERE id = ?", {"id": 1}, consistency="ONE", timeout_ms=5000
+        )
+        assert len(result) == 1
+
+        # The test keyspace has a single replica, so THREE can't be satisfied
+        with pytest.raises(ScyllaError):
+            await session.execute(
+                "SELECT * FROM users WHERE id = ?", {"id": 1}, consistency="THREE"
+            )
+
+        prepared = await session.prepare("SELECT * FROM users WHERE id = ?")
+        with pytest.raises(ScyllaError):
+            await session.execute_prepared(prepared, {"id": 1}, consistency="THREE")
+        result = await session.execute_prepared(prepared, {"id": 1}, timeout_ms=5000)
+        assert len(result) == 1
+
+        with pytest.raises(ValueError):
+            await session.execute("SELECT * FROM users", consistency="SOME")
+
+    async def test_execute_nowait(self, session, users_table):
+        """Test fire-and-forget writes through the PendingResult handle"""
+        handles = [
+            session.execute_nowait(
+                "INSERT INTO users (id, username) VALUES (?, ?)",
+                {"id": i, "username": f"nowait{i}"},
+            )
+            for i in range(1100, 1110)
+        ]
+        for _ in range(100):
+            if all(handle.done() for handle in handles):
+                break
+            await asyncio.sleep(0.05)
+        assert all(handle.done() for handle in handles)
+        assert all(handle.result() is not None for handle in handles)
+
+        result = await session.execute("SELECT * FROM users WHERE id = ?", {"id": 1109})
+        assert len(result) == 1
+
+        prepared = await session.prepare("SELECT * FROM users WHERE id = ?")
+        assert len(await session.execute_nowait(prepared, {"id": 1100})) == 1
+
+        with pytest.raises(ScyllaError):
+            await session.execute_nowait("INSERT INTO nonexistent_table (id) VALUES (1)")
+        with pytest.raises(TypeError):
+            session.execute_nowait(42)
+
+    async def test_execute_with_retry(self, session, users_table, sample_users):
+        """Test retrying with backoff and the attempt history on failure"""
+        result = await session.execute_with_retry("SELECT * FROM users WHERE id = ?", {"id": 1})
+        assert len(result) == 1
+
+        # Unavailable is raised before the request is applied, so it is always retried
+        unavailable = Query("SELECT * FROM users WHERE id = ?").with_consistency("THREE")
+        with pytest.raises(ScyllaError) as excinfo:
+            await session.execute_with_retry(
+                unavailable, {"id": 1}, retries=2, jitter=False, base_delay_ms=1
+            )
+        attempts = excinfo.value.attempts
+        assert [a["attempt"] for a in attempts] == [1, 2, 3]
+        assert [a["delay_ms"] for a in attempts] == [1, 2, None]
+        assert all(a["error"] for a in attempts)
+
+        # Invalid statements are never retried
+        with pytest.raises(ScyllaError) as excinfo:
+            await session.execute_with_retry("SELECT missing FROM users", retries=5)
+        assert len(excinfo.value.attempts) == 1
+
+        with pytest.raises(ValueError):
+            await session.execute_with_retry("SELECT * FROM users", backoff="random")
+
     async def test_await_schema_agreement(self, session, test_keyspace):
         """Test schema agreement"""
         await session.execute(
@@ -106,7 +774,382 @@ class TestSession:
         assert isinstance(cluster_data, str)
         assert len(cluster_data) > 0
 
+    async def test_metrics(self, session):
+        """Test the driver metrics snapshot"""
+        for _ in range(5):
+            await session.execute("SELECT now() FROM system.local")
+
+        metrics = session.metrics()
+        assert metrics["queries"] >= 5
+        assert metrics["errors"] >= 0
+        assert metrics["retries"] >= 0
+        assert metrics["total_connections"] > 0
+        assert metrics["latency_p50_ms"] is not None
+        assert metrics["latency_p50_ms"] <= metrics["latency_p99_ms"]
+
+    async def test_prometheus_exporter(self, session):
+        """Test serving driver metrics to Prometheus scrapes"""
+        await session.execute("SELECT now() FROM system.local")
+        assert "rsylla_requests_total" in session.prometheus_metrics()
+
+        exporter = await session.serve_prometheus("127.0.0.1:0")
+        assert exporter.is_running()
+        host, port = exporter.address.rsplit(":", 1)
+
+        reader, writer = await asyncio.open_connection(host, int(port))
+        writer.write(b"GET /metrics HTTP/1.1\r\nHost: localhost\r\n\r\n")
+        await writer.drain()
+        response = (await reader.read()).decode()
+        writer.close()
+
+        assert response.startswith("HTTP/1.1 200 OK")
+        assert "# TYPE rsylla_requests_total counter" in response
+        assert 'rsylla_node_connected{address="' in response
+
+        exporter.stop()
+
+    async def test_cluster_metadata(self, session):
+        """Test cluster topology introspection"""
+        metadata = await session.cluster_metadata()
+        assert len(metadata.nodes) > 0
+        assert len(metadata.datacenters()) > 0
+
+        node = metadata.nodes[0]
+        assert node.address
+        assert node.host_id
+        assert node.datacenter is not None
+        assert node.is_up is True
+        assert node.scylla_version
+        # Not the Cassandra version Scylla reports compatibility with
+        local = await session.execute("SELECT release_version FROM system.local")
+        assert node.scylla_version != local.first_row()[0]
+
+    async def test_cluster_events(self, session):
+        """Test schema changes are delivered as cluster events"""
+        import asyncio
+
+        events = session.cluster_events(poll_interval_ms=100)
+        await session.execute(
+            """
+            CREATE KEYSPACE IF NOT EXISTS test_rsylla_events
+            WITH replication = {'class': 'SimpleStrategy', 'replication_factor': 1}
+        """
+        )
+        await session.keyspaces()
+
+        async def next_schema_event():
+            async for event in events:
+                if event.kind == "schema_change" and event.keyspace == "test_rsylla_events":
+                    return event
+
+        event = await asyncio.wait_for(next_schema_event(), timeout=10)
+        assert event.change == "created"
+        assert event.target == "keyspace"
+
+        events.close()
+        await session.execute("DROP KEYSPACE IF EXISTS test_rsylla_events")
+
+        with pytest.raises(ValueError):
+            session.cluster_events(poll_interval_ms=0)
+
+    async def test_schema_events(self, session, test_keyspace):
+        """Test structured table schema change notifications"""
+        import asyncio
+
+        events = session.schema_events(poll_interval_ms=100)
+
+        async def next_event(target, name):
+            async for event in events:
+                assert event.kind == "schema_change"
+                if event.target == target and event.name == name:
+                    return event
+
+        await session.execute("CREATE TABLE IF NOT EXISTS watched (id int PRIMARY KEY)")
+        await session.keyspaces()
+        event = await asyncio.wait_for(next_event("table", "watched"), timeout=10)
+        assert (event.change, event.keyspace) == ("created", test_keyspace)
+
+        await session.execute("ALTER TABLE watched ADD note text")
+        await session.keyspaces()
+        event = await asyncio.wait_for(next_event("table", "watched"), timeout=10)
+        assert event.change == "updated"
+
+        await session.execute("DROP TABLE watched")
+        await session.keyspaces()
+        event = await asyncio.wait_for(next_event("table", "watched"), timeout=10)
+        assert event.change == "dropped"
+
+        events.close()
+
+    async def test_keyspace_metadata(self, session, test_keyspace):
+        """Test keyspace, table and UDT schema introspection"""
+        await session.execute("CREATE TYPE IF NOT EXISTS address (street text, zip int)")
+        await session.execute(
+            """
+            CREATE TABLE IF NOT EXISTS events (
+                tenant text,
+                day date,
+                ts timestamp,
+                tags set<text>,
+                home frozen<address>,
+                PRIMARY KEY ((tenant, day), ts)
+            )
+        """
+        )
+
+        keyspaces = await session.keyspaces()
+        assert test_keyspace in keyspaces
+        assert "system" in keyspaces
+
+        keyspace = await session.keyspace(test_keyspace)
+        assert keyspace.strategy == "SimpleStrategy"
+        assert keyspace.replication == {"replication_factor": "1"}
+        assert keyspace.user_types["address"].fields == [("street", "text"), ("zip", "int")]
+
+        table = keyspace.table("events")
+        assert table.partition_key == ["tenant", "day"]
+        assert table.clustering_key == ["ts"]
+        assert [column.name for column in table.columns][:3] == ["tenant", "day", "ts"]
+        assert table.column("tags").cql_type == "set<text>"
+        assert table.column("home").cql_type == "frozen<address>"
+        assert table.column("ts").kind == "clustering"
+
+        assert await session.keyspace("nonexistent_keyspace_12345") is None
+
+        await session.execute("DROP TABLE IF EXISTS events")
+        await session.execute("DROP TYPE IF EXISTS address")
+
+    async def test_view_and_index_metadata(self, session, users_table):
+        """Test materialized view and secondary index introspection"""
+        await session.execute("CREATE INDEX IF NOT EXISTS users_email_idx ON users (email)")
+        await session.execute(
+            """
+            CREATE MATERIALIZED VIEW IF NOT EXISTS users_by_name AS
+                SELECT id, username, age FROM users
+                WHERE username IS NOT NULL AND id IS NOT NULL
+                PRIMARY KEY (username, id)
+        """
+        )
+
+        keyspace = await session.keyspace(session.get_keyspace())
+
+        view = keyspace.view("users_by_name")
+        assert view.base_table == "users"
+        assert view.partition_key == ["username"]
+        assert view.clustering_key == ["id"]
+        assert sorted(view.included_columns()) == ["age", "id", "username"]
+        assert "users_by_name" in [v.name for v in keyspace.views_of("users")]
+
+        indexes = keyspace.table("users").indexes
+        assert [index.name for index in indexes] == ["users_email_idx"]
+        assert indexes[0].target == "email"
+
+        await session.execute("DROP MATERIALIZED VIEW IF EXISTS users_by_name")
+        await session.execute("DROP INDEX IF EXISTS users_email_idx")
+
+    async def test_token_ranges(self, session, test_keyspace):
+        """Test token ranges cover the whole ring without wrapping"""
+        ranges = session.token_ranges(test_keyspace)
+        assert len(ranges) > 0
+
+        assert ranges[0][0] == -(2**63)
+        assert ranges[-1][1] == 2**63 - 1
+        for previous, current in zip(ranges, ranges[1:]):
+            assert previous[1] == current[0]
+        for start, end, replicas in ranges:
+            assert start < end
+            assert len(replicas) == 1
+
+        with pytest.raises(ValueError):
+            session.token_ranges("nonexistent_keyspace_12345")
+
+    async def test_token_and_replicas_for(self, session, test_keyspace, users_table):
+        """Test computing a partition's token and owning replicas client-side"""
+        # Make sure the driver's schema view includes the freshly created table
+        await session.keyspaces()
+
+        token = session.token_for(test_keyspace, "users", {"id": 1})
+        await session.execute("INSERT INTO users (id, username) VALUES (1, 'tok')")
+        result = await session.execute("SELECT token(id) FROM users WHERE id = ?", {"id": 1})
+        assert result.first_row()[0] == token
+
+        replicas = session.replicas_for(test_keyspace, "users", {"id": 1})
+        assert len(replicas) == 1
+        address, shard = replicas[0]
+        assert address
+        assert shard >= 0
+
+        with pytest.raises(ScyllaError):
+            session.token_for(test_keyspace, "nonexistent_table", {"id": 1})
+
+    async def test_soft_delete(self, session, test_keyspace):
+        """Test soft-deleting a row with a TTL'd marker column"""
+        await session.execute(
+            """
+            CREATE TABLE IF NOT EXISTS soft_items (
+                id int PRIMARY KEY,
+                name text,
+                deleted_at timestamp
+            )
+        """
+        )
+        await session.execute(
+            "INSERT INTO soft_items (id, name) VALUES (?, ?)", {"id": 1, "name": "a"}
+        )
+
+        await session.soft_delete("soft_items", {"id": 1}, ttl=3600)
+
+        result = await session.execute(
+            "SELECT name, deleted_at FROM soft_items WHERE id = ?", {"id": 1}
+        )
+        row = result.first_row()
+        assert row[0] == "a"
+        assert row[1] is not None
+
+        with pytest.raises(ValueError):
+            await session.soft_delete("soft_items; DROP TABLE x", {"id": 1})
+
+        await session.execute("DROP TABLE IF EXISTS soft_items")
+
+    async def test_ordered_writer(self, session, users_table):
+        """Test writes sharing a key are applied in submission order"""
+        import asyncio
+
+        prepared = await session.prepare("UPDATE users SET username = ? WHERE id = ?")
+        writer = session.ordered_writer(concurrency=8)
+
+        futures = [
+            writer.submit(key, prepared, {"username": f"v{version}", "id": key})
+            for version in range(20)
+            for key in (700, 701)
+        ]
+        await asyncio.gather(*futures)
+
+        for key in (700, 701):
+            result = await session.execute("SELECT username FROM users WHERE id = ?", {"id": key})
+            assert result.first_row()[0] == "v19"
+
     async def test_invalid_keyspace(self, session):
         """Test using non-existent keyspace"""
         with pytest.raises(ScyllaError):
             await session.use_keyspace("nonexistent_keyspace_12345", False)
+
+    async def test_create_and_drop_keyspace(self, session):
+        """Test creating and dropping keyspaces with replication options"""
+        datacenter = session.describe()["nodes"][0]["datacenter"]
+        nts = {"class": "NetworkTopologyStrategy", datacenter: 1}
+        simple = {"class": "SimpleStrategy", "replication_factor": 1}
+        await session.create_keyspace("rsylla_nts", nts, durable_writes=False)
+        # Already there, which IF NOT EXISTS tolerates
+        await session.create_keyspace("rsylla_nts", nts)
+
+        result = await session.execute(
+            "SELECT replication, durable_writes FROM system_schema.keyspaces "
+            "WHERE keyspace_name = 'rsylla_nts'"
+        )
+        replication, durable_writes = result.first_row()
+        assert replication["class"].endswith("NetworkTopologyStrategy")
+        assert replication[datacenter] == "1"
+        assert durable_writes is False
+
+        await session.create_keyspace("rsylla_simple", simple)
+        for name in ["rsylla_nts", "rsylla_simple"]:
+            await session.drop_keyspace(name)
+            await session.drop_keyspace(name)
+        result = await session.execute(
+            "SELECT keyspace_name FROM system_schema.keyspaces WHERE keyspace_name = 'rsylla_nts'"
+        )
+        assert result.first_row() is None
+
+        with pytest.raises(ScyllaError):
+            await session.drop_keyspace("rsylla_nts", if_exists=False)
+        with pytest.raises(ValueError, match="'class'"):
+            session.create_keyspace("rsylla_bad", {"replication_factor": 1})
+        with pytest.raises(ValueError, match="replication_factor"):
+            session.create_keyspace("rsylla_bad", {"class": "SimpleStrategy", datacenter: 1})
+        with pytest.raises(ValueError, match="per datacenter"):
+            session.create_keyspace("rsylla_bad", {"class": "NetworkTopologyStrategy"})
+        with pytest.raises(ValueError, match="negative"):
+            session.create_keyspace("rsylla_bad", {**simple, "replication_factor": -1})
+        with pytest.raises(ValueError, match="Invalid CQL identifier"):
+            session.create_keyspace("bad-name", simple)
+
+    async def test_service_level_is_configuration(self, scylla_connection_string, session):
+        """Test the builder's service level is recorded without touching any role"""
+        configured = await (
+            SessionBuilder().known_node(scylla_connection_string).service_level("oltp").build()
+        )
+        assert configured.service_level() == "oltp"
+        assert configured.describe()["service_level"] == "oltp"
+        assert session.service_level() is None
+
+        with pytest.raises(ValueError, match="Invalid CQL identifier"):
+            session.attach_service_level("oltp; DROP ROLE admin", "app")
+
+
+@pytest.mark.unit
+class TestBuildInfo:
+    """Test build introspection"""
+
+    def test_build_info(self):
+        """Test build_info reports driver and build characteristics"""
+        info = build_info()
+
+        assert info["rsylla_version"]
+        assert info["scylla_driver_version"] != "unknown"
+        assert isinstance(info["features"], list)
+        assert "metrics" in info["features"]
+        assert info["tls"] == ("tls" in info["features"])
+        assert isinstance(info["protocol_versions"], list)
+        assert 4 in info["protocol_versions"]
+
+
+@pytest.mark.unit
+class TestLogging:
+    """Test the driver log bridge"""
+
+    def test_configure_logging_invalid_level(self):
+        """Test configure_logging rejects unknown levels"""
+        with pytest.raises(ValueError):
+            configure_logging("verbose")
+
+
+@pytest.mark.unit
+class TestBuildWithRetry:
+    """Test retrying the initial connection"""
+
+    async def test_retries_unreachable_cluster(self):
+        """Test every attempt is made and recorded when nothing listens"""
+        builder = SessionBuilder().known_node("127.0.0.1:1")
+        with pytest.raises(ScyllaError) as excinfo:
+            await builder.build_with_retry(
+                max_attempts=3, backoff_ms=10, backoff="constant", jitter=False
+            )
+
+        attempts = excinfo.value.attempts
+        assert [a["attempt"] for a in attempts] == [1, 2, 3]
+        assert [a["delay_ms"] for a in attempts] == [10, 10, None]
+        assert all(a["error"] for a in attempts)
+
+    async def test_configuration_errors_not_retried(self):
+        """Test a builder without contact points fails on the first attempt"""
+        with pytest.raises(ScyllaError) as excinfo:
+            await SessionBuilder().build_with_retry(max_attempts=5, backoff_ms=10)
+        assert len(excinfo.value.attempts) == 1
+
+    def test_invalid_options(self):
+        """Test build_with_retry validates its arguments"""
+        with pytest.raises(ValueError):
+            SessionBuilder().build_with_retry(max_attempts=0)
+        with pytest.raises(ValueError):
+            SessionBuilder().build_with_retry(backoff="random")
+
+
+@pytest.mark.unit
+class TestServiceLevel:
+    """Test configuring a workload prioritization service level"""
+
+    def test_invalid_service_level(self):
+        """Test service level names are validated as CQL identifiers"""
+        with pytest.raises(ValueError):
+            SessionBuilder().service_level("oltp; DROP ROLE admin")
@@ -0,0 +1,62 @@
+"""
+Tests for shutting rsylla down at interpreter exit
+"""
+
+import subprocess
+import sys
+import textwrap
+
+import pytest
+
+# Leaves a connection attempt in flight on an event loop that is closed before it ends
+ABANDONED_REQUEST = """
+import asyncio
+import rsylla
+
+async def start():
+    return asyncio.ensure_future(rsylla.SessionBuilder().known_node("127.0.0.1:1").build())
+
+loop = asyncio.new_event_loop()
+loop.run_until_complete(start())
+loop.close()
+"""
+
+
+def run(script):
+    return subprocess.run(
+        [sys.executable, "-c", textwrap.dedent(script)],
+        capture_output=True,
+        text=True,
+        timeout=30,
+    )
+
+
+@pytest.mark.unit
+class TestShutdown:
+    """Test draining in-flight requests at exit"""
+
+    def test_exit_with_request_in_flight(self):
+        """Test a script exiting mid-request ends cleanly, without a traceback"""
+        process = run(ABANDONED_REQUEST)
+        assert process.returncode == 0
+        assert process.stderr == ""
+
+    def test_requests_rejected_after_shutdown(self):
+        """Test shutdown() drains and then refuses new requests"""
+        process = run(
+            """
+            import asyncio
+            import rsylla
+
+            async def connect():
+                return await rsylla.SessionBuilder().known_node("127.0.0.1:1").build()
+
+            assert rsylla.shutdown(timeout_ms=1000) == 0
+            try:
+                asyncio.run(connect())
+            except rsylla.ScyllaError as err:
+                print(err)
+            """
+        )
+        assert process.returncode == 0
+        assert process.stdout.strip() == "rsylla has been shut down"
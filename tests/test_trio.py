@@ -0,0 +1,81 @@
+"""
+Tests for awaiting rsylla under trio and anyio
+"""
+
+import socket
+
+import pytest
+
+from rsylla import PendingResult, ScyllaError, Session, SessionBuilder
+
+trio = pytest.importorskip("trio")
+
+
+@pytest.mark.unit
+class TestTrio:
+    """Test requests awaited from trio without an asyncio loop"""
+
+    def test_error_delivered(self):
+        """Test a failed request raises its error in the trio task"""
+
+        async def connect():
+            pending = SessionBuilder().known_node("127.0.0.1:1").build()
+            assert isinstance(pending, PendingResult)
+            with pytest.raises(ScyllaError):
+                await pending
+            assert pending.done()
+            assert not pending.cancel()
+
+        trio.run(connect)
+
+    def test_cancel_scope_cancels_request(self):
+        """Test a trio cancel scope aborts the request awaited inside it"""
+        # Accepts connections but never answers, so the handshake hangs
+        server = socket.socket()
+        server.bind(("127.0.0.1", 0))
+        server.listen()
+        node = f"127.0.0.1:{server.getsockname()[1]}"
+
+        async def connect():
+            with trio.move_on_after(0.2) as scope:
+                await SessionBuilder().known_node(node).connection_timeout(5000).build()
+            return scope.cancelled_caught
+
+        try:
+            assert trio.run(connect)
+        finally:
+            server.close()
+
+
+@pytest.mark.integration
+class TestTrioSession:
+    """Test running queries from trio and anyio"""
+
+    def test_execute_under_trio(self, scylla_connection_string):
+        """Test connecting and querying from trio tasks"""
+
+        async def query():
+            session = await Session.connect([scylla_connection_string])
+            results = []
+
+            async def execute():
+                results.append(await session.execute("SELECT now() FROM system.local"))
+
+            async with trio.open_nursery() as nursery:
+                for _ in range(10):
+                    nursery.start_soon(execute)
+            return all(result.first_row() is not None for result in results)
+
+        assert trio.run(query)
+
+    @pytest.mark.parametrize("backend", ["asyncio", "trio"])
+    def test_execute_under_anyio(self, scylla_connection_string, backend):
+        """Test the same anyio code on both of its backends"""
+        anyio = pytest.importorskip("anyio")
+
+        async def query():
+            session = await Session.connect([scylla_connection_string])
+            result = await session.execute("SELECT now() FROM system.local")
+            return result.first_row() is not None
+
+        assert anyio.run(query, backend=backend)